@@ -0,0 +1,62 @@
+//! Compares the throughput of stepping cart pole environments sequentially and in parallel.
+//!
+//! Cart pole environments are not `Send` while the `sdl2` feature is enabled, so the parallel
+//! half only runs with `cargo bench --no-default-features --features parallel`.
+#![allow(unused_crate_dependencies)]
+
+use std::time::{Duration, Instant};
+
+#[cfg(not(feature = "sdl2"))]
+use gym_rs::envs::parallel_vec_env::ParallelVecEnv;
+use gym_rs::{
+    envs::{
+        classical_control::cartpole::{CartPoleAction, CartPoleEnv},
+        vec_env::SyncVectorEnv,
+    },
+    utils::renderer::RenderMode,
+};
+
+const NUM_ENVS: usize = 16;
+const NUM_STEPS: usize = 10_000;
+
+fn actions(step: usize) -> Vec<CartPoleAction> {
+    (0..NUM_ENVS)
+        .map(|i| {
+            if (i + step).is_multiple_of(2) {
+                CartPoleAction::Left
+            } else {
+                CartPoleAction::Right
+            }
+        })
+        .collect()
+}
+
+fn report(name: &str, elapsed: Duration) {
+    let steps_per_second = (NUM_ENVS * NUM_STEPS) as f64 / elapsed.as_secs_f64();
+    println!("{name}: {elapsed:?} ({steps_per_second:.0} steps/s)");
+}
+
+fn main() {
+    let mut sync_env = SyncVectorEnv::from_fn(NUM_ENVS, |_| CartPoleEnv::new(RenderMode::None));
+    sync_env.reset_all(Some(0));
+    let start = Instant::now();
+    for step in 0..NUM_STEPS {
+        sync_env.step_all(actions(step));
+    }
+    report("SyncVectorEnv", start.elapsed());
+
+    #[cfg(feature = "sdl2")]
+    println!("ParallelVecEnv: skipped, as cart pole is not Send with the sdl2 feature");
+
+    #[cfg(not(feature = "sdl2"))]
+    {
+        let mut parallel_env =
+            ParallelVecEnv::from_fn(NUM_ENVS, |_| CartPoleEnv::new(RenderMode::None));
+        parallel_env.reset_all(Some(0));
+        let start = Instant::now();
+        for step in 0..NUM_STEPS {
+            parallel_env.step_all(actions(step));
+        }
+        report("ParallelVecEnv", start.elapsed());
+    }
+}
@@ -1,33 +1,21 @@
+//! Runs a handful of cart pole episodes using a random policy.
+#![allow(unused_crate_dependencies)]
+
 use gym_rs::{
-    core::Env, envs::classical_control::cartpole::CartPoleEnv, utils::renderer::RenderMode,
+    envs::classical_control::cartpole::{CartPoleAction, CartPoleEnv},
+    utils::{renderer::RenderMode, rollout::run_episodes},
 };
-use ordered_float::OrderedFloat;
 use rand::{thread_rng, Rng};
 
 fn main() {
     let mut env = CartPoleEnv::new(RenderMode::Human);
-    env.reset(None, false, None);
-
-    const N: usize = 15;
-    let mut rewards = Vec::with_capacity(N);
 
     let mut rng = thread_rng();
-    for _ in 0..N {
-        let mut current_reward = OrderedFloat(0.);
-
-        for _ in 0..475 {
-            let action = rng.gen_range(0..=1);
-            let state_reward = env.step(action);
-            current_reward += state_reward.reward;
-
-            if state_reward.done {
-                break;
-            }
-        }
-
-        env.reset(None, false, None);
-        rewards.push(current_reward);
-    }
+    let mut policy = |_: &_| {
+        CartPoleAction::try_from(rng.gen_range(0..=1)).expect("Both values index an action.")
+    };
+    let stats = run_episodes(&mut env, &mut policy, 15);
 
+    let rewards: Vec<_> = stats.iter().map(|episode| episode.total_reward).collect();
     println!("{:?}", rewards);
 }
@@ -1,34 +1,25 @@
+//! Drives the mountain car environment using a random policy.
+#![allow(unused_crate_dependencies)]
+
 use gym_rs::{
-    core::{ActionReward, Env},
-    envs::classical_control::mountain_car::MountainCarEnv,
-    utils::renderer::RenderMode,
+    core::Env,
+    envs::classical_control::mountain_car::{MountainCarAction, MountainCarEnv},
+    utils::{renderer::RenderMode, rollout::run_episode},
 };
 use rand::{thread_rng, Rng};
 
 fn main() {
     let mut mc = MountainCarEnv::new(RenderMode::Human);
-    let _state = mc.reset(None, false, None);
 
     let mut rng = thread_rng();
-    let mut end: bool = false;
-    let mut episode_length = 0;
-    while !end {
-        if episode_length > 200 {
-            break;
-        }
-        let action = rng.gen_range(0..3);
-        let ActionReward { done, .. } = mc.step(action);
-        episode_length += 1;
-        end = done;
-        println!("episode_length: {}", episode_length);
-    }
+    let mut policy = |_: &_| {
+        MountainCarAction::try_from(rng.gen_range(0..3)).expect("Every value indexes an action.")
+    };
+    let stats = run_episode(&mut mc, &mut policy);
+    println!(
+        "episode_length: {}, total_reward: {}, truncated: {}",
+        stats.length, stats.total_reward, stats.truncated
+    );
 
     mc.close();
-
-    for _ in 0..200 {
-        let action = rng.gen_range(0..3);
-        mc.step(action);
-        episode_length += 1;
-        println!("episode_length: {}", episode_length);
-    }
 }
@@ -1,11 +1,14 @@
-use std::fmt::Debug;
+use std::{
+    error::Error,
+    fmt::{Debug, Display},
+};
 
 use ordered_float::OrderedFloat;
 use rand_pcg::Pcg64;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    spaces::BoxR,
+    spaces::{BoxR, Space},
     utils::{
         custom::{structs::Metadata, traits::Sample, types::O64},
         renderer::{RenderMode, Renders},
@@ -22,16 +25,10 @@ const DEFAULT_REWARD_RANGE: &RewardRange = &(RewardRange {
 const DEFAULT_RENDER_MODE: &RenderMode = &RenderMode::None;
 
 /// Defines a common set of operations available to different environments.
-pub trait Env: Clone + Debug + Serialize + EnvProperties
-where
-    Self::Observation: Sample + Into<Vec<f64>>,
-{
+pub trait Env: Clone + Debug + Serialize + EnvProperties {
     /// The type of action supported.
     type Action;
 
-    /// The type of the observation produced after an action has been applied.
-    type Observation;
-
     /// The type of the metadata object produced by acting on the environment.
     type Info;
 
@@ -49,11 +46,60 @@ where
         options: Option<BoxR<Self::Observation>>,
     ) -> (Self::Observation, Option<Self::ResetInfo>);
 
+    /// Resets the environment to the given state rather than sampling one, returning it unchanged.
+    ///
+    /// Any episode bookkeeping, such as step counters, is reset as it would be by
+    /// [`Env::reset`].
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation;
+
     /// Produces the renders, if any, associated with the given mode.
     fn render(&mut self, mode: RenderMode) -> Renders;
 
     /// Closes any open resources associated with the internal rendering service.
     fn close(&mut self);
+
+    /// Checks whether the given action lies within the action space of the environment.
+    fn action_space_contains(&self, action: &Self::Action) -> bool
+    where
+        Self::Action: PartialOrd + Clone,
+        Self::ActionSpace: Space<Self::Action>,
+    {
+        self.action_space().contains(action.clone())
+    }
+
+    /// Checks whether the given observation lies within the observation space of the environment.
+    fn observation_space_contains(&self, observation: &Self::Observation) -> bool
+    where
+        Self::Observation: PartialOrd + Clone,
+        Self::ObservationSpace: Space<Self::Observation>,
+    {
+        self.observation_space().contains(observation.clone())
+    }
+
+    /// Clones the environment without any of its active rendering resources, such as an open
+    /// window, which the clone loads anew once it is rendered.
+    ///
+    /// Intended for building several environments from a template, which should not share the
+    /// window of the template.
+    fn clone_detached(&self) -> Self {
+        self.clone()
+    }
+
+    /// Encodes everything which changes while acting on the environment, including its random
+    /// number generator, such that [`Env::load_state`] can later restore it.
+    ///
+    /// Environments which do not support checkpointing, which requires the `checkpoint` feature,
+    /// return an error.
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn Error>> {
+        Err(format!("{} does not support checkpointing.", Self::spec().id).into())
+    }
+
+    /// Restores the state encoded by [`Env::save_state`].
+    ///
+    /// Environments which do not support checkpointing return an error.
+    fn load_state(&mut self, _bytes: &[u8]) -> Result<(), Box<dyn Error>> {
+        Err(format!("{} does not support checkpointing.", Self::spec().id).into())
+    }
 }
 
 /// Defines a set of properties that should be accessible in all environments.
@@ -61,11 +107,18 @@ pub trait EnvProperties
 where
     Self: Sized,
 {
+    /// The type of the observation produced after an action has been applied.
+    type Observation: Sample + Into<Vec<f64>>;
     /// The type of values that can be observed in the action space.
     type ActionSpace;
     /// The type of observations produced
     type ObservationSpace;
 
+    /// Provides the static description of this environment, as registered in OpenAI's gym.
+    fn spec() -> EnvSpec
+    where
+        Self: Sized;
+
     /// Provides an object describing additional details about this environment.
     fn metadata(&self) -> &Metadata<Self>;
 
@@ -82,7 +135,33 @@ where
         DEFAULT_REWARD_RANGE
     }
 
+    /// Provides the number of steps after which an episode is truncated, if the environment
+    /// truncates episodes at all.
+    fn episode_length(&self) -> Option<usize> {
+        None
+    }
+
+    /// Provides the number of steps taken since the last reset.
+    fn step_count(&self) -> usize;
+
+    /// Checks whether the episode ended on the last step because a terminal state was reached.
+    ///
+    /// Environments which do not track why their episodes end always return `false`.
+    fn is_terminated(&self) -> bool {
+        false
+    }
+
+    /// Checks whether the episode ended on the last step because it reached its step limit.
+    ///
+    /// Environments which do not track why their episodes end always return `false`.
+    fn is_truncated(&self) -> bool {
+        false
+    }
+
     /// Provides the object describing the actions that can be observed.
+    ///
+    /// Environments conventionally expose a `sample_action` method which draws an action from
+    /// this space using their own random number generator, e.g. for epsilon-greedy exploration.
     fn action_space(&self) -> &Self::ActionSpace;
 
     /// Provides the object describing the states that can be observed in this environment.
@@ -91,7 +170,7 @@ where
 
 /// Encapsulates and describes the state update experienced by an environment after acting on an
 /// action.
-#[derive(Clone, Debug, Copy, PartialEq, Eq, Ord, PartialOrd)]
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Ord, PartialOrd, Serialize, Deserialize)]
 pub struct ActionReward<T, E> {
     /// The current observable state.
     pub observation: T,
@@ -102,9 +181,103 @@ pub struct ActionReward<T, E> {
     /// Indicates whether the episode has termianted early or  not.
     pub truncated: bool,
     /// Additional info implementations may provide for purposes beyond classical RL.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub info: Option<E>,
 }
 
+impl<T, E> ActionReward<T, E> {
+    /// Applies the given function to the reward, leaving all other fields unchanged.
+    pub fn map_reward<F: Fn(O64) -> O64>(self, f: F) -> ActionReward<T, E> {
+        ActionReward {
+            reward: f(self.reward),
+            ..self
+        }
+    }
+
+    /// Applies the given function to the observation, leaving all other fields unchanged.
+    pub fn map_observation<U, F: Fn(T) -> U>(self, f: F) -> ActionReward<U, E> {
+        ActionReward {
+            observation: f(self.observation),
+            reward: self.reward,
+            done: self.done,
+            truncated: self.truncated,
+            info: self.info,
+        }
+    }
+
+    /// Applies the given function to the info, leaving all other fields unchanged.
+    pub fn map_info<G, F: Fn(Option<E>) -> Option<G>>(self, f: F) -> ActionReward<T, G> {
+        ActionReward {
+            observation: self.observation,
+            reward: self.reward,
+            done: self.done,
+            truncated: self.truncated,
+            info: f(self.info),
+        }
+    }
+}
+
+impl<T: Display, E> Display for ActionReward<T, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "obs={}, reward={:.2}, done={}, truncated={}",
+            self.observation, self.reward, self.done, self.truncated
+        )
+    }
+}
+
+/// Provides a stable identifier for an environment, as registered in OpenAI's gym, for purposes
+/// such as logging and experiment tracking.
+pub trait EnvironmentId {
+    /// The identifier of the environment, e.g. `"CartPole-v1"`.
+    const ID: &'static str;
+}
+
+/// Provides the identifier of the given environment type.
+pub fn env_id<E: EnvironmentId>() -> &'static str {
+    E::ID
+}
+
+/// Describes why the episode of an environment ended, if it has ended since the last reset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TerminationStatus {
+    /// The episode has not ended.
+    #[default]
+    Running,
+    /// The episode ended because a terminal state was reached.
+    Terminated,
+    /// The episode ended because it reached its step limit.
+    Truncated,
+}
+
+impl TerminationStatus {
+    /// Derives the status from the conditions checked after a step, where reaching a terminal
+    /// state takes precedence over reaching the step limit.
+    pub fn from_conditions(terminated: bool, truncated: bool) -> Self {
+        if terminated {
+            TerminationStatus::Terminated
+        } else if truncated {
+            TerminationStatus::Truncated
+        } else {
+            TerminationStatus::Running
+        }
+    }
+}
+
+/// Describes an environment in the same manner as the specs registered in OpenAI's gym.
+#[derive(Clone, Copy, Debug, Serialize, PartialEq)]
+pub struct EnvSpec {
+    /// The unique, versioned name of the environment.
+    pub id: &'static str,
+    /// The number of steps after which an episode is truncated, if any.
+    pub max_episode_steps: Option<usize>,
+    /// The average total reward over which the environment is considered solved, if any.
+    pub reward_threshold: Option<f64>,
+    /// Indicates whether the environment is non-deterministic even after seeding.
+    pub nondeterministic: bool,
+}
+
 /// Defines the bounds for the reward value that can be observed.
 #[derive(Clone, Debug, Serialize, PartialEq, Ord, PartialOrd, Eq)]
 pub struct RewardRange {
@@ -114,9 +287,231 @@ pub struct RewardRange {
     upper_bound: O64,
 }
 
+impl RewardRange {
+    /// Generates a range of rewards between the given inclusive bounds.
+    pub fn new(lower_bound: O64, upper_bound: O64) -> Self {
+        Self {
+            lower_bound,
+            upper_bound,
+        }
+    }
+
+    /// The smallest possible reward that can be observed.
+    pub fn lower_bound(&self) -> O64 {
+        self.lower_bound
+    }
+
+    /// The largest possible reward that can be observed.
+    pub fn upper_bound(&self) -> O64 {
+        self.upper_bound
+    }
+}
+
 /// Implement a default reward range.
 impl Default for RewardRange {
     fn default() -> Self {
         DEFAULT_REWARD_RANGE.clone()
     }
 }
+
+/// An object-safe view of an environment, exchanging actions and observations as vectors.
+///
+/// Implemented for every environment whose actions can be built from a vector, allowing
+/// environments chosen at runtime to be held behind a [`DynEnv`].
+pub trait ErasedEnv: Debug {
+    /// Acts on the environment using the given action, producing the observation, the reward and
+    /// whether the episode is done or truncated.
+    fn step_raw(&mut self, action: Vec<f64>) -> (Vec<f64>, f64, bool, bool);
+
+    /// Resets the environment to a initial random state, producing the initial observation.
+    fn reset_raw(&mut self, seed: Option<u64>) -> Vec<f64>;
+}
+
+impl<E: Env> ErasedEnv for E
+where
+    E::Action: From<Vec<f64>>,
+{
+    fn step_raw(&mut self, action: Vec<f64>) -> (Vec<f64>, f64, bool, bool) {
+        let ActionReward {
+            observation,
+            reward,
+            done,
+            truncated,
+            ..
+        } = self.step(action.into());
+
+        (observation.into(), reward.into_inner(), done, truncated)
+    }
+
+    fn reset_raw(&mut self, seed: Option<u64>) -> Vec<f64> {
+        self.reset(seed, false, None).0.into()
+    }
+}
+
+/// A type-erased environment, whose concrete type can be chosen at runtime.
+#[derive(Debug)]
+pub struct DynEnv {
+    inner: Box<dyn ErasedEnv>,
+}
+
+impl DynEnv {
+    /// Erases the type of the given environment.
+    pub fn new(env: impl ErasedEnv + 'static) -> Self {
+        Self {
+            inner: Box::new(env),
+        }
+    }
+
+    /// Acts on the environment using the given action, producing the observation, the reward and
+    /// whether the episode is done or truncated.
+    pub fn step(&mut self, action: Vec<f64>) -> (Vec<f64>, f64, bool, bool) {
+        self.inner.step_raw(action)
+    }
+
+    /// Resets the environment to a initial random state, producing the initial observation.
+    pub fn reset(&mut self, seed: Option<u64>) -> Vec<f64> {
+        self.inner.reset_raw(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::{env_id, ActionReward, DynEnv, Env, EnvProperties};
+    use crate::{
+        envs::{
+            classical_control::{
+                acrobot::AcrobotEnv,
+                cartpole::{CartPoleAction, CartPoleEnv, CartPoleObservation},
+                mountain_car::{MountainCarEnv, MountainCarObservation},
+            },
+            wrappers::time_limit::TimeLimit,
+        },
+        utils::renderer::RenderMode,
+    };
+
+    /// Flattens an observation knowing only the properties of its environment.
+    fn flatten<E: EnvProperties>(_env: &E, observation: E::Observation) -> Vec<f64> {
+        observation.into()
+    }
+
+    #[test]
+    fn given_wrapped_and_unwrapped_environments_when_observing_then_properties_name_the_type() {
+        let mut env = TimeLimit::new(CartPoleEnv::new(RenderMode::None), 10);
+        let (observation, _) = env.reset(Some(0), false, None);
+        assert_eq!(flatten(&env, observation).len(), 4);
+
+        let mut env = AcrobotEnv::new(RenderMode::None);
+        let (observation, _) = env.reset(Some(0), false, None);
+        assert_eq!(flatten(&env, observation).len(), 6);
+    }
+
+    #[test]
+    fn given_environments_of_different_types_when_erased_then_both_can_be_stepped() {
+        let mut envs = [
+            DynEnv::new(CartPoleEnv::new(RenderMode::None)),
+            DynEnv::new(MountainCarEnv::new(RenderMode::None)),
+        ];
+
+        let observations: Vec<_> = envs.iter_mut().map(|env| env.reset(Some(0))).collect();
+        let steps: Vec<_> = envs.iter_mut().map(|env| env.step(vec![1.])).collect();
+
+        assert_eq!((observations[0].len(), observations[1].len()), (4, 2));
+        assert_eq!((steps[0].1, steps[1].1), (1., -1.));
+        assert_ne!(steps[0].0, observations[0]);
+    }
+
+    #[test]
+    fn given_action_reward_when_mapped_in_sequence_then_untouched_fields_are_preserved() {
+        let action_reward = ActionReward {
+            observation: 2,
+            reward: OrderedFloat(1.),
+            done: true,
+            truncated: false,
+            info: Some("info"),
+        };
+
+        let mapped = action_reward
+            .map_reward(|reward| reward * 3.)
+            .map_observation(|observation| vec![observation; 2])
+            .map_info(|info| info.map(str::len));
+
+        assert_eq!(
+            mapped,
+            ActionReward {
+                observation: vec![2, 2],
+                reward: OrderedFloat(3.),
+                done: true,
+                truncated: false,
+                info: Some(4),
+            }
+        );
+    }
+
+    #[test]
+    fn given_cart_pole_step_when_serialized_to_json_then_deserialized_result_is_identical() {
+        let mut env = CartPoleEnv::new(RenderMode::None);
+        env.reset(Some(0), false, None);
+        let action_reward = env.step(CartPoleAction::Right);
+
+        let json = serde_json::to_string(&action_reward).unwrap();
+        assert!(!json.contains("info"));
+        let deserialized: ActionReward<CartPoleObservation, ()> =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, action_reward);
+    }
+
+    #[test]
+    fn given_wrapped_env_when_stepping_and_resetting_then_step_count_follows_episode() {
+        let mut env = TimeLimit::new(AcrobotEnv::new(RenderMode::None), 10);
+
+        env.reset(Some(0), false, None);
+        assert_eq!(env.step_count(), 0);
+
+        env.step(1);
+        assert_eq!(env.step_count(), 1);
+        env.step(1);
+        assert_eq!(env.step_count(), 2);
+
+        env.reset(Some(0), false, None);
+        assert_eq!(env.step_count(), 0);
+    }
+
+    #[test]
+    fn given_env_types_when_ids_requested_then_ids_match_gym_registration() {
+        assert_eq!(env_id::<CartPoleEnv>(), "CartPole-v1");
+        assert_eq!(env_id::<MountainCarEnv>(), "MountainCar-v0");
+        assert_eq!(env_id::<CartPoleEnv>(), CartPoleEnv::spec().id);
+    }
+
+    #[test]
+    fn given_action_reward_when_displayed_then_observation_and_rounded_reward_are_shown() {
+        let action_reward = ActionReward::<_, ()> {
+            observation: MountainCarObservation::new(OrderedFloat(-0.52349), OrderedFloat(0.00123)),
+            reward: OrderedFloat(-1.005),
+            done: false,
+            truncated: true,
+            info: None,
+        };
+
+        assert_eq!(
+            action_reward.to_string(),
+            "obs=pos=-0.523, vel=0.0012, reward=-1.00, done=false, truncated=true"
+        );
+    }
+
+    #[test]
+    fn given_values_inside_and_outside_spaces_when_checked_then_only_inside_values_are_contained() {
+        let acrobot = AcrobotEnv::new(RenderMode::None);
+        assert!(acrobot.action_space_contains(&2));
+        assert!(!acrobot.action_space_contains(&3));
+
+        let mut cartpole = CartPoleEnv::new(RenderMode::None);
+        let (observation, _) = cartpole.reset(Some(0), false, None);
+        assert!(cartpole.action_space_contains(&CartPoleAction::Right));
+        assert!(cartpole.observation_space_contains(&observation));
+        assert!(!cartpole.observation_space_contains(&observation.with_x(10.)));
+    }
+}
@@ -0,0 +1,2 @@
+/// Holds all structures related to the n-armed bandit environment.
+pub mod n_armed_bandit;
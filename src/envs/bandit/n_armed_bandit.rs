@@ -0,0 +1,277 @@
+use derivative::Derivative;
+use ordered_float::OrderedFloat;
+use rand::Rng;
+use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId},
+    spaces::{BoxR, Discrete, Space},
+    utils::{
+        custom::{structs::Metadata, traits::Sample, util_fns::sample_standard_normal},
+        renderer::{RenderMode, Renders},
+        seeding::rand_random,
+    },
+};
+
+const N_ARMED_BANDIT_RENDER_MODES: &[RenderMode] = &[RenderMode::None];
+
+impl Default for Metadata<NArmedBanditEnv> {
+    fn default() -> Self {
+        Metadata::new(N_ARMED_BANDIT_RENDER_MODES, 0)
+    }
+}
+
+/// The observation of a bandit, which holds no state and therefore carries no values.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
+pub struct BanditObservation;
+
+impl Sample for BanditObservation {
+    fn sample_between<R: Rng>(_rng: &mut R, _bounds: Option<BoxR<Self>>) -> Self {
+        BanditObservation
+    }
+}
+
+impl Space<BanditObservation> for Discrete {
+    fn contains(&self, _value: BanditObservation) -> bool {
+        self.contains(0)
+    }
+}
+
+impl From<BanditObservation> for Vec<f64> {
+    fn from(_: BanditObservation) -> Self {
+        vec![]
+    }
+}
+
+/// An implementation of the multi-armed bandit, as described in Sutton and Barto's book.
+///
+/// Pulling an arm, identified by its index, is rewarded with a value drawn from a normal
+/// distribution associated with the arm. The bandit holds no state, so the observation carries no
+/// values. When `resample_means` is set, the means of the arms are drawn from the standard normal
+/// distribution on every reset.
+///
+/// Episodes never end, so the environment should be wrapped by a
+/// [`TimeLimit`](crate::envs::wrappers::time_limit::TimeLimit).
+#[derive(Serialize, Derivative, Clone)]
+#[derivative(Debug)]
+pub struct NArmedBanditEnv {
+    /// The number of arms which can be pulled.
+    pub n_arms: usize,
+    /// The mean reward of each arm.
+    pub means: Vec<f64>,
+    /// The standard deviation of the reward of each arm.
+    pub stds: Vec<f64>,
+    /// Whether the means are drawn anew on every reset.
+    pub resample_means: bool,
+
+    /// The set of arms which can be pulled.
+    pub action_space: Discrete,
+    /// The single value which can be observed.
+    pub observation_space: Discrete,
+
+    /// Additional information provided by the environment.
+    pub metadata: Metadata<Self>,
+
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    rand_random: Pcg64,
+}
+
+impl NArmedBanditEnv {
+    /// Generates a bandit whose arms have means drawn from the standard normal distribution and
+    /// a standard deviation of `1`, as in the 10-armed testbed.
+    pub fn new(n_arms: usize, resample_means: bool) -> Self {
+        let (mut rng, _) = rand_random(None);
+        let means = (0..n_arms)
+            .map(|_| sample_standard_normal(&mut rng))
+            .collect();
+
+        let mut env = Self::from_parameters(means, vec![1.; n_arms]);
+        env.resample_means = resample_means;
+        env.rand_random = rng;
+        env
+    }
+
+    /// Generates a stationary bandit with the given mean and standard deviation for each arm.
+    ///
+    /// Panics if no arms are given, or if the number of means and standard deviations differ.
+    pub fn from_parameters(means: Vec<f64>, stds: Vec<f64>) -> Self {
+        assert!(!means.is_empty(), "A bandit requires at least one arm.");
+        assert_eq!(
+            means.len(),
+            stds.len(),
+            "Each arm requires both a mean and a standard deviation."
+        );
+        let (rand_random, _) = rand_random(None);
+        let n_arms = means.len();
+
+        Self {
+            n_arms,
+            means,
+            stds,
+            resample_means: false,
+            action_space: Discrete(n_arms),
+            observation_space: Discrete(1),
+            metadata: Metadata::default(),
+            step_count: 0,
+            rand_random,
+        }
+    }
+
+    /// Provides the index of the arm with the highest mean reward.
+    pub fn optimal_action(&self) -> usize {
+        self.means
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index)
+            .expect("A bandit holds at least one arm.")
+    }
+
+    /// Draws a random arm from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> usize {
+        self.action_space.sample(&mut self.rand_random)
+    }
+}
+
+impl Env for NArmedBanditEnv {
+    type Action = usize;
+    type Info = ();
+    type ResetInfo = ();
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        assert!(
+            self.action_space.contains(action),
+            "{} does not index an arm of the bandit.",
+            action
+        );
+
+        let noise = sample_standard_normal(&mut self.rand_random);
+        let reward = self.means[action] + self.stds[action] * noise;
+
+        self.step_count += 1;
+
+        ActionReward {
+            observation: BanditObservation,
+            reward: OrderedFloat(reward),
+            done: false,
+            truncated: false,
+            info: None,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        _options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (rand_random, _) = rand_random(seed);
+        self.rand_random = rand_random;
+        self.step_count = 0;
+
+        if self.resample_means {
+            for mean in self.means.iter_mut() {
+                *mean = sample_standard_normal(&mut self.rand_random);
+            }
+        }
+
+        if return_info {
+            (BanditObservation, Some(()))
+        } else {
+            (BanditObservation, None)
+        }
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.step_count = 0;
+        state
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        assert!(self.metadata.render_modes.contains(&mode));
+
+        Renders::None
+    }
+
+    fn close(&mut self) {}
+}
+
+impl EnvironmentId for NArmedBanditEnv {
+    const ID: &'static str = "NArmedBandit-v0";
+}
+
+impl EnvProperties for NArmedBanditEnv {
+    type Observation = BanditObservation;
+    type ActionSpace = Discrete;
+    type ObservationSpace = Discrete;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: None,
+            reward_threshold: None,
+            nondeterministic: false,
+        }
+    }
+
+    fn metadata(&self) -> &Metadata<Self> {
+        &self.metadata
+    }
+
+    fn rand_random(&self) -> &Pcg64 {
+        &self.rand_random
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        &self.action_space
+    }
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NArmedBanditEnv;
+    use crate::core::Env;
+
+    #[test]
+    fn given_configured_arm_when_pulled_many_times_then_rewards_match_its_distribution() {
+        let mut env = NArmedBanditEnv::from_parameters(vec![0., 2.], vec![1., 0.5]);
+        env.reset(Some(0), false, None);
+
+        let samples = 20_000;
+        let rewards: Vec<f64> = (0..samples)
+            .map(|_| env.step(1).reward.into_inner())
+            .collect();
+        let mean = rewards.iter().sum::<f64>() / samples as f64;
+        let var = rewards.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / samples as f64;
+
+        assert!((mean - 2.).abs() < 0.02, "mean {}", mean);
+        assert!((var.sqrt() - 0.5).abs() < 0.02, "std {}", var.sqrt());
+        assert_eq!(env.optimal_action(), 1);
+    }
+
+    #[test]
+    fn given_non_stationary_bandit_when_reset_then_means_are_resampled() {
+        let mut env = NArmedBanditEnv::new(10, true);
+        let means = env.means.clone();
+
+        env.reset(Some(0), false, None);
+
+        assert_eq!(env.n_arms, 10);
+        assert_ne!(env.means, means);
+    }
+}
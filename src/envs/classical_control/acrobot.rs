@@ -0,0 +1,736 @@
+use std::f64::consts::PI;
+
+use derivative::Derivative;
+use derive_new::new;
+use ordered_float::{OrderedFloat, UniformOrdered};
+use rand::{
+    distributions::{
+        uniform::{SampleUniform, UniformSampler},
+        Uniform,
+    },
+    prelude::Distribution,
+    Rng,
+};
+use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId, TerminationStatus},
+    spaces::{BoundedObservation, BoxR, Discrete, Space},
+    utils::{
+        custom::{
+            canvas::{Color, DrawRenderer},
+            screen::{Screen, ScreenGuiTransformations},
+            structs::Metadata,
+            traits::Sample,
+            types::O64,
+            util_fns::clip,
+        },
+        renderer::{RenderMode, Renderer, Renders},
+        seeding::rand_random,
+    },
+};
+
+/// The torques which can be applied onto the joint between both links, indexed by action.
+const AVAILABLE_TORQUE: [f64; 3] = [-1., 0., 1.];
+
+/// The number of steps after which an episode is truncated.
+const ACROBOT_EPISODE_LENGTH: usize = 500;
+
+/// An implementation of the acrobot problem described in Sutton & Barto's
+/// [Reinforcement Learning: An Introduction](http://incompleteideas.net/book/the-book-2nd.html).
+///
+/// The system consists of two links connected linearly to form a chain, with one end of the chain
+/// fixed. The joint between the two links is actuated, and the goal is to apply torques on the
+/// actuated joint to swing the free end of the chain above a given height, while starting from the
+/// initial state of hanging downwards. The dynamics follow the equations found in the book and are
+/// integrated using Euler's method. The agent is rewarded '-1' for every step taken until the
+/// episode ends.
+///
+/// The episode ends when any of the following conditions occur:
+///
+/// 1. Termination: The free end reaches one link length above the fixed joint.
+/// 2. Truncation: The episode reaches 500 steps.
+#[derive(Serialize, Derivative, Clone)]
+#[derivative(Debug)]
+pub struct AcrobotEnv {
+    /// The available actions that can be taken.
+    pub action_space: Discrete,
+    /// The range of values that can be observed.
+    pub observation_space: BoxR<AcrobotObservation>,
+    /// The type of renders produced.
+    pub render_mode: RenderMode,
+    /// The current state of the environment.
+    pub state: AcrobotObservation,
+    /// Additional pieces of information provided by the environment.
+    pub metadata: Metadata<Self>,
+    /// The number of seconds between state updates.
+    pub dt: O64,
+    /// The gravity constant applied to the environment.
+    pub gravity: O64,
+    /// The length of the link attached to the fixed joint.
+    pub link_length_1: O64,
+    /// The length of the link holding the free end.
+    pub link_length_2: O64,
+    /// The mass of the link attached to the fixed joint.
+    pub link_mass_1: O64,
+    /// The mass of the link holding the free end.
+    pub link_mass_2: O64,
+    /// The position of the center of mass of the link attached to the fixed joint.
+    pub link_com_pos_1: O64,
+    /// The position of the center of mass of the link holding the free end.
+    pub link_com_pos_2: O64,
+    /// The moments of inertia of both links.
+    pub link_moi: O64,
+    /// The largest angular velocity the link attached to the fixed joint can reach.
+    pub max_vel_1: O64,
+    /// The largest angular velocity the link holding the free end can reach.
+    pub max_vel_2: O64,
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+    /// The reason the last episode ended, if it did.
+    pub termination_status: TerminationStatus,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    rand_random: Pcg64,
+    screen: Screen,
+    renderer: Renderer,
+}
+
+const ACROBOT_RENDER_MODES: &[RenderMode] = &[
+    RenderMode::Human,
+    RenderMode::RgbArray,
+    RenderMode::SingleRgbArray,
+    RenderMode::None,
+];
+
+impl Default for Metadata<AcrobotEnv> {
+    fn default() -> Self {
+        Metadata::new(ACROBOT_RENDER_MODES, 15)
+            .with_reward_threshold(-100.)
+            .with_max_episode_steps(ACROBOT_EPISODE_LENGTH)
+    }
+}
+
+impl AcrobotEnv {
+    /// Draws a random action from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> usize {
+        self.action_space.sample(&mut self.rand_random)
+    }
+
+    /// Creates an acrobot environment using the defaults found in the book.
+    pub fn new(render_mode: RenderMode) -> Self {
+        let (mut rand_random, _) = rand_random(None);
+
+        let dt = OrderedFloat(0.2);
+        let gravity = OrderedFloat(9.8);
+        let link_length_1 = OrderedFloat(1.);
+        let link_length_2 = OrderedFloat(1.);
+        let link_mass_1 = OrderedFloat(1.);
+        let link_mass_2 = OrderedFloat(1.);
+        let link_com_pos_1 = OrderedFloat(0.5);
+        let link_com_pos_2 = OrderedFloat(0.5);
+        let link_moi = OrderedFloat(1.);
+        let max_vel_1 = OrderedFloat(4. * PI);
+        let max_vel_2 = OrderedFloat(9. * PI);
+
+        let high = AcrobotObservation::new(
+            OrderedFloat(1.),
+            OrderedFloat(1.),
+            OrderedFloat(1.),
+            OrderedFloat(1.),
+            max_vel_1,
+            max_vel_2,
+        );
+        let low = AcrobotObservation::new(
+            OrderedFloat(-1.),
+            OrderedFloat(-1.),
+            OrderedFloat(-1.),
+            OrderedFloat(-1.),
+            -max_vel_1,
+            -max_vel_2,
+        );
+
+        let action_space = Discrete(3);
+        let observation_space = BoxR::new(low, high);
+
+        let renderer = Renderer::new(render_mode, None, None);
+
+        let metadata = Metadata::default();
+        let screen = Screen::new(500, 500, "Acrobot", metadata.render_fps, render_mode);
+
+        let state = AcrobotObservation::sample_between(&mut rand_random, None);
+
+        Self {
+            action_space,
+            observation_space,
+            render_mode,
+            state,
+            metadata,
+            dt,
+            gravity,
+            link_length_1,
+            link_length_2,
+            link_mass_1,
+            link_mass_2,
+            link_com_pos_1,
+            link_com_pos_2,
+            link_moi,
+            max_vel_1,
+            max_vel_2,
+            step_count: 0,
+            termination_status: TerminationStatus::Running,
+            rand_random,
+            screen,
+            renderer,
+        }
+    }
+
+    /// Computes the angular accelerations of both links given the torque applied onto the
+    /// actuated joint.
+    fn angular_accelerations(&self, torque: f64) -> (f64, f64) {
+        let m1 = self.link_mass_1.into_inner();
+        let m2 = self.link_mass_2.into_inner();
+        let l1 = self.link_length_1.into_inner();
+        let lc1 = self.link_com_pos_1.into_inner();
+        let lc2 = self.link_com_pos_2.into_inner();
+        let i1 = self.link_moi.into_inner();
+        let i2 = self.link_moi.into_inner();
+        let g = self.gravity.into_inner();
+
+        let theta1 = self.state.theta1();
+        let theta2 = self.state.theta2();
+        let dtheta1 = self.state.theta1_dot.into_inner();
+        let dtheta2 = self.state.theta2_dot.into_inner();
+
+        let d1 = m1 * lc1.powi(2)
+            + m2 * (l1.powi(2) + lc2.powi(2) + 2. * l1 * lc2 * theta2.cos())
+            + i1
+            + i2;
+        let d2 = m2 * (lc2.powi(2) + l1 * lc2 * theta2.cos()) + i2;
+        let phi2 = m2 * lc2 * g * (theta1 + theta2 - PI / 2.).cos();
+        let phi1 = -m2 * l1 * lc2 * dtheta2.powi(2) * theta2.sin()
+            - 2. * m2 * l1 * lc2 * dtheta2 * dtheta1 * theta2.sin()
+            + (m1 * lc1 + m2 * l1) * g * (theta1 - PI / 2.).cos()
+            + phi2;
+
+        let ddtheta2 =
+            (torque + d2 / d1 * phi1 - m2 * l1 * lc2 * dtheta1.powi(2) * theta2.sin() - phi2)
+                / (m2 * lc2.powi(2) + i2 - d2.powi(2) / d1);
+        let ddtheta1 = -(d2 * ddtheta2 + phi1) / d1;
+
+        (ddtheta1, ddtheta2)
+    }
+
+    /// Wraps an angle into the range `[-PI, PI)`.
+    fn wrap(angle: f64) -> f64 {
+        (angle + PI).rem_euclid(2. * PI) - PI
+    }
+
+    fn render(
+        mode: RenderMode,
+        screen: &mut Screen,
+        metadata: &Metadata<Self>,
+        link_length_1: O64,
+        link_length_2: O64,
+        state: AcrobotObservation,
+    ) -> Renders {
+        assert!(metadata.render_modes.contains(&mode));
+
+        screen.load_gui();
+        screen.consume_events();
+
+        let screen_width = screen.screen_width();
+        let bound = (link_length_1 + link_length_2).into_inner() + 0.2;
+        let scale = screen_width as f64 / (bound * 2.);
+        let offset = screen_width as f64 / 2.;
+
+        let theta1 = state.theta1();
+        let theta2 = state.theta2();
+
+        let joint = (
+            link_length_1.into_inner() * theta1.sin(),
+            -link_length_1.into_inner() * theta1.cos(),
+        );
+        let tip = (
+            joint.0 + link_length_2.into_inner() * (theta1 + theta2).sin(),
+            joint.1 - link_length_2.into_inner() * (theta1 + theta2).cos(),
+        );
+
+        let [pivot, joint, tip] = [(0., 0.), joint, tip].map(|(x, y)| {
+            (
+                (x * scale + offset).floor() as i16,
+                (y * scale + offset).floor() as i16,
+            )
+        });
+        let goal_height = (link_length_1.into_inner() * scale + offset).floor() as i16;
+        let link_width = (0.1 * scale).floor() as u8;
+        let joint_radius = (0.1 * scale / 2.).floor() as i16;
+
+        screen.draw_on_canvas(
+            |canvas| {
+                canvas.set_draw_color(Color::WHITE);
+                canvas.clear();
+
+                canvas
+                    .hline(0, screen_width as i16, goal_height, Color::BLACK)
+                    .unwrap();
+
+                for (start, end) in [(pivot, joint), (joint, tip)] {
+                    canvas
+                        .thick_line(
+                            start.0,
+                            start.1,
+                            end.0,
+                            end.1,
+                            link_width,
+                            Color::RGB(0, 204, 204),
+                        )
+                        .unwrap();
+                }
+
+                for (x, y) in [pivot, joint] {
+                    canvas
+                        .aa_circle(x, y, joint_radius, Color::RGB(204, 204, 0))
+                        .unwrap();
+                    canvas
+                        .filled_circle(x, y, joint_radius, Color::RGB(204, 204, 0))
+                        .unwrap();
+                }
+            },
+            ScreenGuiTransformations::default(),
+        );
+
+        screen.render(mode)
+    }
+}
+
+/// Defines the state found in the acrobot environment.
+///
+/// Angles are described through their cosine and sine, where an angle of `0` for the first link
+/// means it is pointing downwards, and an angle of `0` for the second link means it is aligned
+/// with the first link.
+#[derive(new, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AcrobotObservation {
+    /// The cosine of the angle of the link attached to the fixed joint.
+    pub cos_theta1: O64,
+    /// The sine of the angle of the link attached to the fixed joint.
+    pub sin_theta1: O64,
+    /// The cosine of the angle of the link holding the free end, relative to the first link.
+    pub cos_theta2: O64,
+    /// The sine of the angle of the link holding the free end, relative to the first link.
+    pub sin_theta2: O64,
+    /// The angular velocity of the link attached to the fixed joint.
+    pub theta1_dot: O64,
+    /// The angular velocity of the link holding the free end.
+    pub theta2_dot: O64,
+}
+
+impl AcrobotObservation {
+    /// Creates an observation from the angles and angular velocities of both links.
+    pub fn from_angles(theta1: f64, theta2: f64, theta1_dot: f64, theta2_dot: f64) -> Self {
+        Self {
+            cos_theta1: OrderedFloat(theta1.cos()),
+            sin_theta1: OrderedFloat(theta1.sin()),
+            cos_theta2: OrderedFloat(theta2.cos()),
+            sin_theta2: OrderedFloat(theta2.sin()),
+            theta1_dot: OrderedFloat(theta1_dot),
+            theta2_dot: OrderedFloat(theta2_dot),
+        }
+    }
+
+    /// The angle of the link attached to the fixed joint.
+    pub fn theta1(&self) -> f64 {
+        self.sin_theta1
+            .into_inner()
+            .atan2(self.cos_theta1.into_inner())
+    }
+
+    /// The angle of the link holding the free end, relative to the first link.
+    pub fn theta2(&self) -> f64 {
+        self.sin_theta2
+            .into_inner()
+            .atan2(self.cos_theta2.into_inner())
+    }
+}
+
+/// The sampler responsible for generating an observation using uniform probability.
+///
+/// Angles are sampled between the angles described by the bounds, ensuring that the cosine and
+/// sine of each sampled angle remain consistent with each other.
+pub struct UniformAcrobotObservation {
+    theta1_sampler: UniformOrdered<f64>,
+    theta2_sampler: UniformOrdered<f64>,
+    theta1_dot_sampler: UniformOrdered<f64>,
+    theta2_dot_sampler: UniformOrdered<f64>,
+}
+
+impl SampleUniform for AcrobotObservation {
+    type Sampler = UniformAcrobotObservation;
+}
+
+impl UniformSampler for UniformAcrobotObservation {
+    type X = AcrobotObservation;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+        B2: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformAcrobotObservation {
+            theta1_sampler: UniformOrdered::new(
+                OrderedFloat(low.theta1()),
+                OrderedFloat(high.theta1()),
+            ),
+            theta2_sampler: UniformOrdered::new(
+                OrderedFloat(low.theta2()),
+                OrderedFloat(high.theta2()),
+            ),
+            theta1_dot_sampler: UniformOrdered::new(low.theta1_dot, high.theta1_dot),
+            theta2_dot_sampler: UniformOrdered::new(low.theta2_dot, high.theta2_dot),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+        B2: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformAcrobotObservation {
+            theta1_sampler: UniformOrdered::new_inclusive(
+                OrderedFloat(low.theta1()),
+                OrderedFloat(high.theta1()),
+            ),
+            theta2_sampler: UniformOrdered::new_inclusive(
+                OrderedFloat(low.theta2()),
+                OrderedFloat(high.theta2()),
+            ),
+            theta1_dot_sampler: UniformOrdered::new_inclusive(low.theta1_dot, high.theta1_dot),
+            theta2_dot_sampler: UniformOrdered::new_inclusive(low.theta2_dot, high.theta2_dot),
+        }
+    }
+
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        AcrobotObservation::from_angles(
+            self.theta1_sampler.sample(rng).into_inner(),
+            self.theta2_sampler.sample(rng).into_inner(),
+            self.theta1_dot_sampler.sample(rng).into_inner(),
+            self.theta2_dot_sampler.sample(rng).into_inner(),
+        )
+    }
+}
+
+impl Sample for AcrobotObservation {
+    fn sample_between<R: Rng>(rng: &mut R, bounds: Option<BoxR<Self>>) -> Self {
+        let BoxR { low, high } = bounds.unwrap_or({
+            BoxR::new(
+                AcrobotObservation::from_angles(-0.1, -0.1, -0.1, -0.1),
+                AcrobotObservation::from_angles(0.1, 0.1, 0.1, 0.1),
+            )
+        });
+
+        Uniform::new(low, high).sample(rng)
+    }
+}
+
+impl From<AcrobotObservation> for Vec<f64> {
+    fn from(observation: AcrobotObservation) -> Self {
+        Vec::from_iter(
+            [
+                observation.cos_theta1,
+                observation.sin_theta1,
+                observation.cos_theta2,
+                observation.sin_theta2,
+                observation.theta1_dot,
+                observation.theta2_dot,
+            ]
+            .iter()
+            .map(|v| v.into_inner()),
+        )
+    }
+}
+
+impl BoundedObservation for AcrobotObservation {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        self.cos_theta1.is_within(&low.cos_theta1, &high.cos_theta1)
+            && self.sin_theta1.is_within(&low.sin_theta1, &high.sin_theta1)
+            && self.cos_theta2.is_within(&low.cos_theta2, &high.cos_theta2)
+            && self.sin_theta2.is_within(&low.sin_theta2, &high.sin_theta2)
+            && self.theta1_dot.is_within(&low.theta1_dot, &high.theta1_dot)
+            && self.theta2_dot.is_within(&low.theta2_dot, &high.theta2_dot)
+    }
+}
+
+impl Env for AcrobotEnv {
+    type Action = usize;
+    type Info = ();
+    type ResetInfo = ();
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        assert!(
+            self.action_space.contains(action),
+            "{} (usize) invalid",
+            action
+        );
+
+        let torque = AVAILABLE_TORQUE[action];
+        let (ddtheta1, ddtheta2) = self.angular_accelerations(torque);
+        let dt = self.dt.into_inner();
+
+        let theta1 = Self::wrap(self.state.theta1() + dt * self.state.theta1_dot.into_inner());
+        let theta2 = Self::wrap(self.state.theta2() + dt * self.state.theta2_dot.into_inner());
+        let theta1_dot = clip(
+            self.state.theta1_dot + dt * ddtheta1,
+            -self.max_vel_1,
+            self.max_vel_1,
+        );
+        let theta2_dot = clip(
+            self.state.theta2_dot + dt * ddtheta2,
+            -self.max_vel_2,
+            self.max_vel_2,
+        );
+
+        self.state = AcrobotObservation::from_angles(
+            theta1,
+            theta2,
+            theta1_dot.into_inner(),
+            theta2_dot.into_inner(),
+        );
+
+        self.step_count += 1;
+
+        let terminated = -self.link_length_1.into_inner() * theta1.cos()
+            - self.link_length_2.into_inner() * (theta1 + theta2).cos()
+            > self.link_length_1.into_inner();
+        let truncated = self
+            .episode_length()
+            .is_some_and(|episode_length| self.step_count >= episode_length);
+        let done = terminated || truncated;
+        self.termination_status = TerminationStatus::from_conditions(terminated, truncated);
+        let reward = if terminated {
+            OrderedFloat(0.)
+        } else {
+            OrderedFloat(-1.)
+        };
+
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+        let link_length_1 = self.link_length_1;
+        let link_length_2 = self.link_length_2;
+        let state = self.state;
+
+        self.renderer.render_step(&mut |mode| {
+            Self::render(mode, screen, metadata, link_length_1, link_length_2, state)
+        });
+
+        ActionReward {
+            observation: self.state,
+            reward,
+            done,
+            truncated,
+            info: None,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (rand_random, _) = rand_random(seed);
+        self.rand_random = rand_random;
+        self.step_count = 0;
+        self.termination_status = TerminationStatus::Running;
+
+        self.state = AcrobotObservation::sample_between(&mut self.rand_random, options);
+
+        self.renderer.reset();
+
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+        let link_length_1 = self.link_length_1;
+        let link_length_2 = self.link_length_2;
+        let state = self.state;
+
+        self.renderer.render_step(&mut |mode| {
+            Self::render(mode, screen, metadata, link_length_1, link_length_2, state)
+        });
+
+        if return_info {
+            (self.state, Some(()))
+        } else {
+            (self.state, None)
+        }
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.step_count = 0;
+        self.termination_status = TerminationStatus::Running;
+        self.state = state;
+
+        self.renderer.reset();
+
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+        let link_length_1 = self.link_length_1;
+        let link_length_2 = self.link_length_2;
+        let state = self.state;
+
+        self.renderer.render_step(&mut |mode| {
+            Self::render(mode, screen, metadata, link_length_1, link_length_2, state)
+        });
+
+        self.state
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+        let link_length_1 = self.link_length_1;
+        let link_length_2 = self.link_length_2;
+        let state = self.state;
+
+        let render_fn =
+            &mut |mode| Self::render(mode, screen, metadata, link_length_1, link_length_2, state);
+        if self.render_mode != RenderMode::None {
+            self.renderer.get_renders(render_fn)
+        } else {
+            render_fn(mode)
+        }
+    }
+
+    fn close(&mut self) {
+        self.screen.close();
+    }
+}
+
+impl EnvironmentId for AcrobotEnv {
+    const ID: &'static str = "Acrobot-v1";
+}
+
+impl EnvProperties for AcrobotEnv {
+    type Observation = AcrobotObservation;
+    type ActionSpace = Discrete;
+    type ObservationSpace = BoxR<AcrobotObservation>;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: Some(ACROBOT_EPISODE_LENGTH),
+            reward_threshold: Some(-100.),
+            nondeterministic: false,
+        }
+    }
+
+    fn metadata(&self) -> &Metadata<Self> {
+        &self.metadata
+    }
+
+    fn rand_random(&self) -> &Pcg64 {
+        &self.rand_random
+    }
+
+    fn episode_length(&self) -> Option<usize> {
+        Some(ACROBOT_EPISODE_LENGTH)
+    }
+
+    fn is_terminated(&self) -> bool {
+        self.termination_status == TerminationStatus::Terminated
+    }
+
+    fn is_truncated(&self) -> bool {
+        self.termination_status == TerminationStatus::Truncated
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        &self.action_space
+    }
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::{AcrobotEnv, AcrobotObservation};
+    use crate::{
+        core::{Env, EnvProperties},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_chain_at_rest_when_torque_applied_then_links_accelerate_in_opposite_directions() {
+        let mut env = AcrobotEnv::new(RenderMode::None);
+        env.reset_to_state(AcrobotObservation::from_angles(0., 0., 0., 0.));
+
+        let observation = env.step(2).observation;
+
+        // Hanging at rest, gravity exerts no torque, so only the applied torque accelerates the
+        // links, where d1 = 4.5 and d2 = 1.75 for the default links.
+        let ddtheta2 = 1. / (1.25 - 1.75_f64.powi(2) / 4.5);
+        let ddtheta1 = -1.75 * ddtheta2 / 4.5;
+        assert!((observation.theta1_dot.into_inner() - 0.2 * ddtheta1).abs() < 1e-12);
+        assert!((observation.theta2_dot.into_inner() - 0.2 * ddtheta2).abs() < 1e-12);
+        assert_eq!((observation.theta1(), observation.theta2()), (0., 0.));
+    }
+
+    #[test]
+    fn given_chain_hanging_at_rest_when_no_torque_applied_then_step_is_penalized() {
+        let mut env = AcrobotEnv::new(RenderMode::None);
+        env.reset_to_state(AcrobotObservation::from_angles(0., 0., 0., 0.));
+
+        let action_reward = env.step(1);
+
+        let observation = action_reward.observation;
+        assert_eq!((observation.theta1(), observation.theta2()), (0., 0.));
+        assert!(observation.theta1_dot.abs() < 1e-12 && observation.theta2_dot.abs() < 1e-12);
+        assert_eq!(action_reward.reward.into_inner(), -1.);
+        assert!(!action_reward.done);
+    }
+
+    #[test]
+    fn given_free_end_above_goal_height_when_stepping_then_episode_terminates_without_penalty() {
+        let mut env = AcrobotEnv::new(RenderMode::None);
+        env.reset_to_state(AcrobotObservation::from_angles(PI, 0., 0., 0.));
+
+        let action_reward = env.step(1);
+
+        assert!(action_reward.done);
+        assert!(!action_reward.truncated);
+        assert!(env.is_terminated() && !env.is_truncated());
+        assert_eq!(action_reward.reward.into_inner(), 0.);
+    }
+
+    #[test]
+    fn given_chain_at_rest_when_stepping_500_times_then_last_step_is_truncated() {
+        let mut env = AcrobotEnv::new(RenderMode::None);
+        let state = AcrobotObservation::from_angles(0., 0., 0., 0.);
+        env.reset_to_state(state);
+
+        for _ in 1..500 {
+            // Holds the chain at rest, such that rounding errors cannot build up into a swing.
+            env.state = state;
+            let action_reward = env.step(1);
+            assert!(!action_reward.done && !action_reward.truncated);
+        }
+        let action_reward = env.step(1);
+
+        assert!(action_reward.done);
+        assert!(action_reward.truncated);
+        assert!(env.is_truncated() && !env.is_terminated());
+
+        env.reset(Some(0), false, None);
+        assert_eq!(env.step_count, 0);
+        assert!(!env.is_truncated());
+    }
+}
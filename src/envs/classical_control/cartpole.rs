@@ -1,4 +1,4 @@
-use std::{f64::consts::PI, ops::Neg};
+use std::{f64::consts::PI, fmt::Display, ops::Neg};
 
 use derive_new::new;
 use log::warn;
@@ -13,21 +13,19 @@ use rand::{
     Rng,
 };
 use rand_pcg::Pcg64;
-use sdl2::{
-    gfx::primitives::DrawRenderer,
-    pixels::{self, Color},
-};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    core::{ActionReward, Env, EnvProperties},
-    spaces::{BoxR, Discrete, Space},
+    core::{ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId, TerminationStatus},
+    spaces::{BoundedObservation, BoxR, Clamp, Discrete, InvalidDiscreteValue, Space},
     utils::{
         custom::{
+            canvas::{Color, DrawRenderer},
             screen::{Screen, ScreenGuiTransformations},
             structs::Metadata,
             traits::Sample,
             types::O64,
+            util_fns::{clip, sample_standard_normal},
         },
         renderer::{RenderMode, Renderer, Renders},
         seeding::{self, rand_random},
@@ -72,7 +70,8 @@ pub struct CartPoleEnv {
     pub force_mag: O64,
     /// The number of seconds between state updates.
     pub tau: O64,
-    /// The type of integration done on the differential equations found in the paper.
+    /// The type of integration done on the differential equations found in the paper, being
+    /// either explicit Euler or semi-implicit Euler integration.
     pub kinematics_integrator: KinematicsIntegrator,
     /// The angle that the pole can lean to before an episode is considered terminated.
     pub theta_threshold_radians: O64,
@@ -80,6 +79,16 @@ pub struct CartPoleEnv {
     pub x_threshold: O64,
     /// The number of steps taken after the episode was terminated.
     pub steps_beyond_terminated: Option<usize>,
+    /// The reason the current episode ended, if it has.
+    pub termination_status: TerminationStatus,
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+    /// The number of columns used when rendering in Ansi mode.
+    pub ansi_width: usize,
+    /// The ranges from which physical parameters are re-sampled on every reset, if any.
+    pub domain_randomization: Option<DomainRandomizationConfig>,
+    /// The standard deviations of the noise added to the state after each step, if any.
+    pub state_noise: Option<StochasticCartPoleConfig>,
     renderer: Renderer,
     screen: Screen,
     #[serde(skip_serializing)]
@@ -89,92 +98,211 @@ pub struct CartPoleEnv {
 impl CartPoleEnv {
     /// Creates a cart pole environment using defaults from the paper.
     pub fn new(render_mode: RenderMode) -> Self {
-        let (mut rand_random, _) = rand_random(None);
+        CartPoleEnvBuilder::default()
+            .render_mode(render_mode)
+            .build()
+    }
 
-        let gravity = OrderedFloat(9.8);
-        let masscart = OrderedFloat(1.0);
-        let masspole = OrderedFloat(0.1);
-        let length = OrderedFloat(0.5);
-        let force_mag = OrderedFloat(10.0);
-        let tau = OrderedFloat(0.02);
-        let kinematics_integrator = KinematicsIntegrator::Euler;
+    /// Provides a builder to configure the parameters of the environment, starting from the
+    /// defaults found in the paper.
+    pub fn builder() -> CartPoleEnvBuilder {
+        CartPoleEnvBuilder::default()
+    }
 
-        let theta_threshold_radians = OrderedFloat(12. * 2. * PI / 360.);
-        let x_threshold = OrderedFloat(2.4);
+    /// Draws a random action from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> CartPoleAction {
+        CartPoleAction::try_from(self.action_space.sample(&mut self.rand_random))
+            .expect("The action space only holds valid actions.")
+    }
 
-        let high = CartPoleObservation::new(
-            x_threshold * 2.,
-            OrderedFloat(f64::INFINITY),
-            theta_threshold_radians * 2.,
-            OrderedFloat(f64::INFINITY),
-        );
+    /// Acts on the environment using the index of an action, where `0` pushes the cart to the
+    /// left and `1` to the right.
+    ///
+    /// Panics if the index does not correspond to an action.
+    pub fn step_discrete(&mut self, action: usize) -> ActionReward<CartPoleObservation, ()> {
+        let action = CartPoleAction::try_from(action)
+            .unwrap_or_else(|err| panic!("{} usize invalid: {}", action, err));
+        self.step(action)
+    }
 
-        let action_space = Discrete(2);
-        let observation_space = BoxR::new(-high, high);
+    /// Provides the gravity constant applied during the current episode.
+    pub fn current_gravity(&self) -> f64 {
+        self.gravity.into_inner()
+    }
 
-        let renderer = Renderer::new(render_mode, None, None);
+    /// Provides the mass of the cart during the current episode.
+    pub fn current_masscart(&self) -> f64 {
+        self.masscart.into_inner()
+    }
 
-        let metadata = Metadata::default();
-        let screen = Screen::new(400, 600, "Cart Pole", metadata.render_fps, render_mode);
+    /// Provides the mass of the pole during the current episode.
+    pub fn current_masspole(&self) -> f64 {
+        self.masspole.into_inner()
+    }
 
-        let state = CartPoleObservation::sample_between(&mut rand_random, None);
+    /// Provides the force applied to the cart during the current episode.
+    pub fn current_force_mag(&self) -> f64 {
+        self.force_mag.into_inner()
+    }
 
-        let steps_beyond_terminated = None;
+    /// Computes the mechanical energy of the cart and pole, treating the pole as a uniform rod
+    /// whose centre of mass lies `length` away from the hinge.
+    ///
+    /// With `M` the mass of the cart, `m` the mass of the pole, `l` its half-length and `g` the
+    /// gravity constant, the energy is
+    ///
+    /// `0.5 * (M + m) * x_dot^2 + m * l * x_dot * theta_dot * cos(theta)
+    ///     + (2 / 3) * m * l^2 * theta_dot^2 + m * g * l * cos(theta)`,
+    ///
+    /// following the equations of motion in [Correct equations for the dynamics of the cart-pole
+    /// system](https://coneural.org/florian/papers/05_cart_pole.pdf).
+    pub fn energy(&self) -> f64 {
+        let CartPoleObservation {
+            x_dot,
+            theta,
+            theta_dot,
+            ..
+        } = self.state;
+        let (x_dot, theta, theta_dot) = (x_dot.0, theta.0, theta_dot.0);
+        let (masscart, masspole) = (self.masscart.0, self.masspole.0);
+        let (length, gravity) = (self.length.0, self.gravity.0);
 
-        Self {
-            gravity,
-            masscart,
-            masspole,
-            length,
-            force_mag,
-            tau,
-            kinematics_integrator,
-            theta_threshold_radians,
-            x_threshold,
-            action_space,
-            observation_space,
-            render_mode,
-            renderer,
-            screen,
-            state,
-            metadata,
-            rand_random,
-            steps_beyond_terminated,
+        let cart_kinetic = 0.5 * masscart * x_dot.powi(2);
+        let pole_kinetic = 0.5 * masspole * x_dot.powi(2)
+            + masspole * length * x_dot * theta_dot * theta.cos()
+            + 2. / 3. * masspole * length.powi(2) * theta_dot.powi(2);
+        let pole_potential = masspole * gravity * length * theta.cos();
+
+        cart_kinetic + pole_kinetic + pole_potential
+    }
+
+    /// Re-samples the physical parameters covered by the domain randomization, if any.
+    fn randomize_domain(&mut self) {
+        let Some(config) = self.domain_randomization else {
+            return;
+        };
+
+        for (range, parameter) in [
+            (config.gravity_range, &mut self.gravity),
+            (config.masscart_range, &mut self.masscart),
+            (config.masspole_range, &mut self.masspole),
+            (config.force_mag_range, &mut self.force_mag),
+        ] {
+            if let Some((low, high)) = range {
+                *parameter = OrderedFloat(self.rand_random.gen_range(low..=high));
+            }
         }
     }
 
-    fn total_mass(&self) -> O64 {
+    /// Adds Gaussian noise to each component of the state, if the environment is stochastic.
+    fn perturb_state(&mut self) {
+        let Some(config) = self.state_noise else {
+            return;
+        };
+
+        for (std, component) in [
+            (config.x_std, &mut self.state.x),
+            (config.x_dot_std, &mut self.state.x_dot),
+            (config.theta_std, &mut self.state.theta),
+            (config.theta_dot_std, &mut self.state.theta_dot),
+        ] {
+            *component += std * sample_standard_normal(&mut self.rand_random);
+        }
+    }
+
+    /// The combined mass of the cart and the pole, which the applied force accelerates.
+    pub fn total_mass(&self) -> O64 {
         self.masspole + self.masscart
     }
 
-    fn polemass_length(&self) -> O64 {
-        self.masspole + self.length
+    /// The mass of the pole multiplied by half its length, i.e. the first moment of the pole
+    /// about its pivot, which couples the swing of the pole to the motion of the cart.
+    pub fn polemass_length(&self) -> O64 {
+        self.masspole * self.length
     }
 
+    /// Draws the track, cart and pole as rows of text, the track being the bottom row.
+    fn render_ansi(width: usize, x_threshold: O64, state: CartPoleObservation) -> Vec<String> {
+        const POLE_HEIGHT: usize = 4;
+        const CART: &str = "[===]";
+
+        let position = ((state.x + x_threshold) / (x_threshold * 2.)).into_inner();
+        let column = (clip(position, 0., 1.) * (width - 1) as f64).round() as usize;
+        let cart_start = column
+            .saturating_sub(CART.len() / 2)
+            .min(width - CART.len());
+
+        // Terminal cells are roughly twice as tall as they are wide.
+        let theta = state.theta.into_inner();
+        let lean = 2. * theta.tan();
+        let pole = if theta.abs() < 0.1 {
+            '|'
+        } else if theta > 0. {
+            '/'
+        } else {
+            '\\'
+        };
+
+        let mut rows: Vec<String> = (1..=POLE_HEIGHT)
+            .rev()
+            .map(|height| {
+                let mut row = vec![' '; width];
+                let offset = (height as f64 * lean).round() as isize;
+                if let Some(cell) = column
+                    .checked_add_signed(offset)
+                    .and_then(|pole_column| row.get_mut(pole_column))
+                {
+                    *cell = pole;
+                }
+                row.into_iter().collect()
+            })
+            .collect();
+
+        rows.push(format!(
+            "{}{}{}",
+            " ".repeat(cart_start),
+            CART,
+            " ".repeat(width - cart_start - CART.len())
+        ));
+        rows.push("-".repeat(width));
+
+        rows
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn render(
         mode: RenderMode,
         screen: &mut Screen,
         metadata: &Metadata<Self>,
         x_threshold: O64,
         length: O64,
+        ansi_width: usize,
+        step_count: usize,
         state: CartPoleObservation,
     ) -> Renders {
         assert!(metadata.render_modes.contains(&mode));
 
+        if mode == RenderMode::Ansi {
+            return Renders::Ansi(Self::render_ansi(ansi_width, x_threshold, state));
+        }
+
         screen.load_gui();
         screen.consume_events();
 
         let screen_width = screen.screen_width();
+        // The sizes below are given for the default window of 600 by 400 pixels.
+        let x_ratio = OrderedFloat(screen_width as f64 / 600.);
+        let y_ratio = OrderedFloat(screen.screen_height() as f64 / 400.);
         let world_width = x_threshold * 2.;
         let scale = OrderedFloat(screen_width as f64) / world_width;
-        let polewidth: O64 = OrderedFloat(10.);
+        let polewidth: O64 = OrderedFloat(10.) * x_ratio;
         let polelen = scale * 2. * length;
-        let cartwidth = OrderedFloat(50.);
-        let cartheight = OrderedFloat(30.);
+        let cartwidth = OrderedFloat(50.) * x_ratio;
+        let cartheight = OrderedFloat(30.) * y_ratio;
 
         screen.draw_on_canvas(
             |canvas| {
-                canvas.set_draw_color(pixels::Color::WHITE);
+                canvas.set_draw_color(Color::WHITE);
                 canvas.clear();
 
                 let (mut l, mut r, mut t, mut b) = (
@@ -186,7 +314,7 @@ impl CartPoleEnv {
 
                 let axleoffset = cartheight / OrderedFloat(4.0);
                 let cartx = state.x * scale + OrderedFloat(screen_width as f64) / OrderedFloat(2.0);
-                let carty = OrderedFloat(100.);
+                let carty = OrderedFloat(100.) * y_ratio;
                 let cart_coords = [(l, b), (l, t), (r, t), (r, b)]
                     .map(|(x, y)| (x + cartx, y + carty))
                     .map(|(x, y)| (x.floor().into_inner() as i16, y.floor().into_inner() as i16));
@@ -195,11 +323,11 @@ impl CartPoleEnv {
                 let cart_coords_y = &cart_coords.map(|coord| coord.1);
 
                 canvas
-                    .aa_polygon(cart_coords_x, cart_coords_y, pixels::Color::BLACK)
+                    .aa_polygon(cart_coords_x, cart_coords_y, Color::BLACK)
                     .unwrap();
 
                 canvas
-                    .filled_polygon(cart_coords_x, cart_coords_y, pixels::Color::BLACK)
+                    .filled_polygon(cart_coords_x, cart_coords_y, Color::BLACK)
                     .unwrap();
 
                 (l, r, t, b) = (
@@ -258,15 +386,336 @@ impl CartPoleEnv {
             ScreenGuiTransformations::default(),
         );
 
+        // Each step survived is rewarded with 1, so the score is the number of steps taken.
+        screen.draw_text(&format!("Score: {}", step_count), 10, 10, Color::BLACK, 16);
+
         screen.render(mode)
     }
 }
 
-const CART_POLE_RENDER_MODES: &[RenderMode] = &[RenderMode::Human, RenderMode::RgbArray];
+impl Default for CartPoleEnv {
+    fn default() -> Self {
+        Self::new(RenderMode::None)
+    }
+}
+
+/// Describes the ranges from which the physical parameters of a [`CartPoleEnv`] are uniformly
+/// re-sampled on every reset, leaving parameters without a range untouched.
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq)]
+pub struct DomainRandomizationConfig {
+    /// The range of the gravity constant.
+    pub gravity_range: Option<(f64, f64)>,
+    /// The range of the mass of the cart.
+    pub masscart_range: Option<(f64, f64)>,
+    /// The range of the mass of the pole.
+    pub masspole_range: Option<(f64, f64)>,
+    /// The range of the force applied to the cart.
+    pub force_mag_range: Option<(f64, f64)>,
+}
+
+/// Describes the standard deviations of the Gaussian noise added to each component of the state of
+/// a [`CartPoleEnv`] after every integration step, making its physics stochastic.
+///
+/// The noise is drawn from the random number generator of the environment, so episodes remain
+/// reproducible given a seed.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub struct StochasticCartPoleConfig {
+    /// The standard deviation of the noise added to the position of the cart.
+    pub x_std: f64,
+    /// The standard deviation of the noise added to the velocity of the cart.
+    pub x_dot_std: f64,
+    /// The standard deviation of the noise added to the angle of the pole.
+    pub theta_std: f64,
+    /// The standard deviation of the noise added to the angular velocity of the pole.
+    pub theta_dot_std: f64,
+}
+
+impl Default for StochasticCartPoleConfig {
+    fn default() -> Self {
+        Self {
+            x_std: 0.001,
+            x_dot_std: 0.001,
+            theta_std: 0.001,
+            theta_dot_std: 0.001,
+        }
+    }
+}
+
+/// Configures the parameters of a [`CartPoleEnv`] before constructing it.
+///
+/// Each parameter defaults to the value used in the paper.
+#[derive(Debug, Clone)]
+pub struct CartPoleEnvBuilder {
+    gravity: f64,
+    masscart: f64,
+    masspole: f64,
+    length: f64,
+    force_mag: f64,
+    tau: f64,
+    kinematics_integrator: KinematicsIntegrator,
+    theta_threshold_radians: f64,
+    x_threshold: f64,
+    ansi_width: usize,
+    window_width: u32,
+    window_height: u32,
+    domain_randomization: Option<DomainRandomizationConfig>,
+    state_noise: Option<StochasticCartPoleConfig>,
+    render_mode: RenderMode,
+}
+
+impl Default for CartPoleEnvBuilder {
+    fn default() -> Self {
+        Self {
+            gravity: 9.8,
+            masscart: 1.0,
+            masspole: 0.1,
+            length: 0.5,
+            force_mag: 10.0,
+            tau: 0.02,
+            kinematics_integrator: KinematicsIntegrator::default(),
+            theta_threshold_radians: 12. * 2. * PI / 360.,
+            x_threshold: 2.4,
+            ansi_width: 80,
+            window_width: 600,
+            window_height: 400,
+            domain_randomization: None,
+            state_noise: None,
+            render_mode: RenderMode::None,
+        }
+    }
+}
+
+impl CartPoleEnvBuilder {
+    /// Sets the gravity constant applied to the environment.
+    pub fn gravity(mut self, gravity: f64) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Sets the mass of the cart.
+    pub fn masscart(mut self, masscart: f64) -> Self {
+        self.masscart = masscart;
+        self
+    }
+
+    /// Sets the mass of the pole.
+    pub fn masspole(mut self, masspole: f64) -> Self {
+        self.masspole = masspole;
+        self
+    }
+
+    /// Sets half the length of the pole.
+    pub fn length(mut self, length: f64) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Sets the force applied to the cart on each step.
+    pub fn force_mag(mut self, force_mag: f64) -> Self {
+        self.force_mag = force_mag;
+        self
+    }
+
+    /// Sets the number of seconds between state updates.
+    pub fn tau(mut self, tau: f64) -> Self {
+        self.tau = tau;
+        self
+    }
+
+    /// Sets the type of integration done on the differential equations.
+    pub fn kinematics_integrator(mut self, kinematics_integrator: KinematicsIntegrator) -> Self {
+        self.kinematics_integrator = kinematics_integrator;
+        self
+    }
+
+    /// Sets the angle that the pole can lean to before an episode is considered terminated.
+    pub fn theta_threshold_radians(mut self, theta_threshold_radians: f64) -> Self {
+        self.theta_threshold_radians = theta_threshold_radians;
+        self
+    }
+
+    /// Sets the x value that the cart can be at before an episode is considered terminated.
+    pub fn x_threshold(mut self, x_threshold: f64) -> Self {
+        self.x_threshold = x_threshold;
+        self
+    }
+
+    /// Sets the number of columns used when rendering in Ansi mode.
+    pub fn ansi_width(mut self, ansi_width: usize) -> Self {
+        self.ansi_width = ansi_width;
+        self
+    }
+
+    /// Sets the width, in pixels, of the window or frames rendered.
+    pub fn window_width(mut self, window_width: u32) -> Self {
+        self.window_width = window_width;
+        self
+    }
+
+    /// Sets the height, in pixels, of the window or frames rendered.
+    pub fn window_height(mut self, window_height: u32) -> Self {
+        self.window_height = window_height;
+        self
+    }
+
+    /// Sets the ranges from which physical parameters are re-sampled on every reset.
+    pub fn domain_randomization(mut self, config: DomainRandomizationConfig) -> Self {
+        self.domain_randomization = Some(config);
+        self
+    }
+
+    /// Sets the standard deviations of the noise added to the state after each step.
+    pub fn state_noise(mut self, config: StochasticCartPoleConfig) -> Self {
+        self.state_noise = Some(config);
+        self
+    }
+
+    /// Sets the type of renders produced.
+    pub fn render_mode(mut self, render_mode: RenderMode) -> Self {
+        self.render_mode = render_mode;
+        self
+    }
+
+    /// Creates the cart pole environment.
+    ///
+    /// Panics if any of the masses, the length, the time step, the force or the thresholds is not
+    /// strictly positive.
+    pub fn build(self) -> CartPoleEnv {
+        for (name, value) in [
+            ("masscart", self.masscart),
+            ("masspole", self.masspole),
+            ("length", self.length),
+            ("force_mag", self.force_mag),
+            ("tau", self.tau),
+            ("theta_threshold_radians", self.theta_threshold_radians),
+            ("x_threshold", self.x_threshold),
+        ] {
+            assert!(value > 0., "{name} must be strictly positive, got {value}.");
+        }
+        if let Some(config) = self.domain_randomization {
+            for (name, range) in [
+                ("gravity_range", config.gravity_range),
+                ("masscart_range", config.masscart_range),
+                ("masspole_range", config.masspole_range),
+                ("force_mag_range", config.force_mag_range),
+            ] {
+                if let Some((low, high)) = range {
+                    assert!(low <= high, "{name} must not be empty, got {low}..={high}.");
+                }
+            }
+            for (name, range) in [
+                ("masscart_range", config.masscart_range),
+                ("masspole_range", config.masspole_range),
+                ("force_mag_range", config.force_mag_range),
+            ] {
+                if let Some((low, _)) = range {
+                    assert!(low > 0., "{name} must be strictly positive, got {low}.");
+                }
+            }
+        }
+        if let Some(config) = self.state_noise {
+            for (name, std) in [
+                ("x_std", config.x_std),
+                ("x_dot_std", config.x_dot_std),
+                ("theta_std", config.theta_std),
+                ("theta_dot_std", config.theta_dot_std),
+            ] {
+                assert!(std >= 0., "{name} must not be negative, got {std}.");
+            }
+        }
+        assert!(
+            self.ansi_width >= 5,
+            "ansi_width must fit the cart, got {}.",
+            self.ansi_width
+        );
+
+        let (mut rand_random, _) = rand_random(None);
+
+        let gravity = OrderedFloat(self.gravity);
+        let masscart = OrderedFloat(self.masscart);
+        let masspole = OrderedFloat(self.masspole);
+        let length = OrderedFloat(self.length);
+        let force_mag = OrderedFloat(self.force_mag);
+        let tau = OrderedFloat(self.tau);
+        let kinematics_integrator = self.kinematics_integrator;
+        let ansi_width = self.ansi_width;
+        let domain_randomization = self.domain_randomization;
+        let state_noise = self.state_noise;
+        let render_mode = self.render_mode;
+
+        let theta_threshold_radians = OrderedFloat(self.theta_threshold_radians);
+        let x_threshold = OrderedFloat(self.x_threshold);
+
+        let high = CartPoleObservation::new(
+            x_threshold * 2.,
+            OrderedFloat(f64::INFINITY),
+            theta_threshold_radians * 2.,
+            OrderedFloat(f64::INFINITY),
+        );
+
+        let action_space = Discrete(2);
+        let observation_space = BoxR::new(-high, high);
+
+        let renderer = Renderer::new(render_mode, None, None);
+
+        let metadata = Metadata::default();
+        let screen = Screen::new(
+            self.window_width,
+            self.window_height,
+            "Cart Pole",
+            metadata.render_fps,
+            render_mode,
+        );
+
+        let state = CartPoleObservation::sample_between(&mut rand_random, None);
+
+        let steps_beyond_terminated = None;
+        let step_count = 0;
+
+        CartPoleEnv {
+            gravity,
+            masscart,
+            masspole,
+            length,
+            force_mag,
+            tau,
+            kinematics_integrator,
+            theta_threshold_radians,
+            x_threshold,
+            action_space,
+            observation_space,
+            render_mode,
+            renderer,
+            screen,
+            state,
+            metadata,
+            rand_random,
+            steps_beyond_terminated,
+            termination_status: TerminationStatus::Running,
+            step_count,
+            ansi_width,
+            domain_randomization,
+            state_noise,
+        }
+    }
+}
+
+/// The number of steps after which an episode is truncated.
+const CART_POLE_EPISODE_LENGTH: usize = 500;
+
+const CART_POLE_RENDER_MODES: &[RenderMode] = &[
+    RenderMode::Human,
+    RenderMode::RgbArray,
+    RenderMode::SingleRgbArray,
+    RenderMode::Ansi,
+    RenderMode::None,
+];
 
 impl Default for Metadata<CartPoleEnv> {
     fn default() -> Self {
         Metadata::new(CART_POLE_RENDER_MODES, 50)
+            .with_reward_threshold(475.)
+            .with_max_episode_steps(CART_POLE_EPISODE_LENGTH)
     }
 }
 
@@ -325,7 +774,7 @@ impl UniformSampler for UniformCartPoleObservation {
 }
 
 /// Defines the state found in the cart pole environment.
-#[derive(new, Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[derive(new, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CartPoleObservation {
     x: O64,
     x_dot: O64,
@@ -333,6 +782,93 @@ pub struct CartPoleObservation {
     theta_dot: O64,
 }
 
+impl CartPoleObservation {
+    /// The position of the cart.
+    pub fn x(&self) -> f64 {
+        self.x.into_inner()
+    }
+
+    /// The velocity of the cart.
+    pub fn x_dot(&self) -> f64 {
+        self.x_dot.into_inner()
+    }
+
+    /// The angle of the pole in radians, where `0` means the pole is upright.
+    pub fn theta(&self) -> f64 {
+        self.theta.into_inner()
+    }
+
+    /// The angular velocity of the pole.
+    pub fn theta_dot(&self) -> f64 {
+        self.theta_dot.into_inner()
+    }
+
+    /// The angle of the pole in degrees, where `0` means the pole is upright.
+    pub fn angle_degrees(&self) -> f64 {
+        self.theta().to_degrees()
+    }
+
+    /// Checks whether the observation lies within the observation space of the environment, being
+    /// twice as wide as the bounds at which an episode terminates.
+    pub fn is_valid(&self, env: &CartPoleEnv) -> bool {
+        env.observation_space.contains(*self)
+    }
+
+    /// Checks whether the observation satisfies the termination conditions of the environment,
+    /// being the cart leaving the track or the pole falling too far.
+    pub fn is_done(&self, env: &CartPoleEnv) -> bool {
+        self.x < -env.x_threshold
+            || self.x > env.x_threshold
+            || self.theta < -env.theta_threshold_radians
+            || self.theta > env.theta_threshold_radians
+    }
+
+    /// Replaces the position of the cart.
+    pub fn with_x(self, x: f64) -> Self {
+        Self {
+            x: OrderedFloat(x),
+            ..self
+        }
+    }
+
+    /// Replaces the velocity of the cart.
+    pub fn with_x_dot(self, x_dot: f64) -> Self {
+        Self {
+            x_dot: OrderedFloat(x_dot),
+            ..self
+        }
+    }
+
+    /// Replaces the angle of the pole, given in radians.
+    pub fn with_theta(self, theta: f64) -> Self {
+        Self {
+            theta: OrderedFloat(theta),
+            ..self
+        }
+    }
+
+    /// Replaces the angular velocity of the pole.
+    pub fn with_theta_dot(self, theta_dot: f64) -> Self {
+        Self {
+            theta_dot: OrderedFloat(theta_dot),
+            ..self
+        }
+    }
+}
+
+impl Display for CartPoleObservation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "x={:.3}, x_dot={:.3}, θ={:.3}°, θ_dot={:.3}",
+            self.x(),
+            self.x_dot(),
+            self.angle_degrees(),
+            self.theta_dot()
+        )
+    }
+}
+
 impl From<CartPoleObservation> for Vec<f64> {
     fn from(observation: CartPoleObservation) -> Self {
         Vec::from_iter(
@@ -348,6 +884,23 @@ impl From<CartPoleObservation> for Vec<f64> {
     }
 }
 
+impl From<Vec<f64>> for CartPoleObservation {
+    fn from(values: Vec<f64>) -> Self {
+        assert_eq!(
+            values.len(),
+            4,
+            "Expected 4 values to build an observation."
+        );
+
+        CartPoleObservation::new(
+            OrderedFloat(values[0]),
+            OrderedFloat(values[1]),
+            OrderedFloat(values[2]),
+            OrderedFloat(values[3]),
+        )
+    }
+}
+
 impl Sample for CartPoleObservation {
     fn sample_between<R: Rng>(rng: &mut R, bounds: Option<BoxR<Self>>) -> Self {
         let BoxR { low, high } = bounds.unwrap_or({
@@ -364,6 +917,26 @@ impl Sample for CartPoleObservation {
     }
 }
 
+impl BoundedObservation for CartPoleObservation {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        self.x.is_within(&low.x, &high.x)
+            && self.x_dot.is_within(&low.x_dot, &high.x_dot)
+            && self.theta.is_within(&low.theta, &high.theta)
+            && self.theta_dot.is_within(&low.theta_dot, &high.theta_dot)
+    }
+}
+
+impl Clamp for CartPoleObservation {
+    fn clamp_to(self, low: &Self, high: &Self) -> Self {
+        CartPoleObservation {
+            x: self.x.clamp_to(&low.x, &high.x),
+            x_dot: self.x_dot.clamp_to(&low.x_dot, &high.x_dot),
+            theta: self.theta.clamp_to(&low.theta, &high.theta),
+            theta_dot: self.theta_dot.clamp_to(&low.theta_dot, &high.theta_dot),
+        }
+    }
+}
+
 impl Neg for CartPoleObservation {
     type Output = CartPoleObservation;
 
@@ -377,19 +950,89 @@ impl Neg for CartPoleObservation {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Eq, PartialEq)]
+/// The actions which can be applied to the cart.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CartPoleAction {
+    /// Pushes the cart to the left.
+    Left,
+    /// Pushes the cart to the right.
+    Right,
+}
+
+impl From<CartPoleAction> for usize {
+    fn from(action: CartPoleAction) -> Self {
+        match action {
+            CartPoleAction::Left => 0,
+            CartPoleAction::Right => 1,
+        }
+    }
+}
+
+/// Builds the action from a vector holding its index within the action space.
+///
+/// Panics if the vector does not hold exactly one valid index.
+impl From<Vec<f64>> for CartPoleAction {
+    fn from(values: Vec<f64>) -> Self {
+        assert_eq!(values.len(), 1, "Expected 1 value to build an action.");
+
+        let index = values[0];
+        assert!(
+            index.fract() == 0. && index >= 0.,
+            "Expected an integral action index, got {}.",
+            index
+        );
+
+        Self::try_from(index as usize).expect("Expected a valid action index.")
+    }
+}
+
+impl TryFrom<usize> for CartPoleAction {
+    type Error = InvalidDiscreteValue;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(CartPoleAction::Left),
+            1 => Ok(CartPoleAction::Right),
+            _ => Err(InvalidDiscreteValue { value, n: 2 }),
+        }
+    }
+}
+
+impl Space<CartPoleAction> for Discrete {
+    fn contains(&self, value: CartPoleAction) -> bool {
+        self.contains(usize::from(value))
+    }
+}
+
+impl Display for CartPoleAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CartPoleAction::Left => write!(f, "Left"),
+            CartPoleAction::Right => write!(f, "Right"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Eq, PartialEq, Default)]
 /// Describes the available types of integration on cartpole equations.
 pub enum KinematicsIntegrator {
-    /// Euler integration.
+    /// Euler integration, which updates the positions using the velocities from before the step.
+    #[default]
     Euler,
-    /// Semi-implicit Euler integration.
-    Other,
+    /// Semi-implicit Euler integration, which updates the velocities first and uses them to
+    /// update the positions.
+    SemiImplicit,
 }
 
-impl Env for CartPoleEnv {
-    type Action = usize;
+#[allow(non_upper_case_globals)]
+impl KinematicsIntegrator {
+    /// The former name of [`KinematicsIntegrator::SemiImplicit`].
+    #[deprecated(note = "Use SemiImplicit")]
+    pub const Other: Self = Self::SemiImplicit;
+}
 
-    type Observation = CartPoleObservation;
+impl Env for CartPoleEnv {
+    type Action = CartPoleAction;
 
     type Info = ();
 
@@ -399,22 +1042,15 @@ impl Env for CartPoleEnv {
         &mut self,
         action: Self::Action,
     ) -> crate::core::ActionReward<Self::Observation, Self::Info> {
-        assert!(
-            self.action_space.contains(action),
-            "{} usize invalid",
-            action
-        );
-
         let CartPoleObservation {
             mut x,
             mut x_dot,
             mut theta,
             mut theta_dot,
         } = self.state;
-        let force = if action == 1 {
-            self.force_mag
-        } else {
-            -self.force_mag
+        let force = match action {
+            CartPoleAction::Left => -self.force_mag,
+            CartPoleAction::Right => self.force_mag,
         };
 
         let costheta = theta.cos();
@@ -428,31 +1064,39 @@ impl Env for CartPoleEnv {
                     - self.masspole * costheta.powf(OrderedFloat(2.)) / self.total_mass()));
         let xacc = temp - self.polemass_length() * thetaacc * costheta / self.total_mass();
 
-        if self.kinematics_integrator == KinematicsIntegrator::Euler {
-            x += self.tau * x_dot;
-            x_dot += self.tau * xacc;
-            theta += self.tau * theta_dot;
-            theta_dot += self.tau * thetaacc;
-        } else {
-            x_dot += self.tau * xacc;
-            x += self.tau * x_dot;
-            theta_dot += self.tau * thetaacc;
-            theta += self.tau * theta_dot;
+        match self.kinematics_integrator {
+            KinematicsIntegrator::Euler => {
+                x += self.tau * x_dot;
+                x_dot += self.tau * xacc;
+                theta += self.tau * theta_dot;
+                theta_dot += self.tau * thetaacc;
+            }
+            KinematicsIntegrator::SemiImplicit => {
+                x_dot += self.tau * xacc;
+                x += self.tau * x_dot;
+                theta_dot += self.tau * thetaacc;
+                theta += self.tau * theta_dot;
+            }
         }
 
         self.state = CartPoleObservation {
             x,
             x_dot,
-            theta_dot,
             theta,
+            theta_dot,
         };
+        self.perturb_state();
+
+        self.step_count += 1;
 
-        let done = x < -self.x_threshold
-            || x > self.x_threshold
-            || theta < -self.theta_threshold_radians
-            || theta > self.theta_threshold_radians;
+        let terminated = self.state.is_done(self);
+        let truncated = self
+            .episode_length()
+            .is_some_and(|episode_length| self.step_count >= episode_length);
+        let done = terminated || truncated;
+        self.termination_status = TerminationStatus::from_conditions(terminated, truncated);
 
-        let reward = if !done {
+        let reward = if !terminated {
             OrderedFloat(1.0)
         } else if self.steps_beyond_terminated.is_none() {
             self.steps_beyond_terminated = Some(0);
@@ -467,18 +1111,29 @@ impl Env for CartPoleEnv {
         let metadata = &self.metadata;
         let x_threshold = self.x_threshold;
         let length = self.length;
+        let ansi_width = self.ansi_width;
+        let step_count = self.step_count;
         let state = self.state;
 
         self.renderer.render_step(&mut |mode| {
-            Self::render(mode, screen, metadata, x_threshold, length, state)
+            Self::render(
+                mode,
+                screen,
+                metadata,
+                x_threshold,
+                length,
+                ansi_width,
+                step_count,
+                state,
+            )
         });
 
         ActionReward {
             observation: self.state,
             reward,
             done,
-            truncated: false,
-            info: Some(()),
+            truncated,
+            info: None,
         }
     }
 
@@ -491,21 +1146,35 @@ impl Env for CartPoleEnv {
         let (rand_random, _) = seeding::rand_random(seed);
         self.rand_random = rand_random;
 
+        self.randomize_domain();
         self.state = CartPoleObservation::sample_between(&mut self.rand_random, options);
 
         self.renderer.reset();
 
+        self.steps_beyond_terminated = None;
+        self.termination_status = TerminationStatus::Running;
+        self.step_count = 0;
+
         let screen = &mut self.screen;
         let metadata = &self.metadata;
         let x_threshold = self.x_threshold;
         let length = self.length;
+        let ansi_width = self.ansi_width;
+        let step_count = self.step_count;
         let state = self.state;
 
-        self.steps_beyond_terminated = None;
-
         self.renderer.reset();
         self.renderer.render_step(&mut |mode| {
-            Self::render(mode, screen, metadata, x_threshold, length, state)
+            Self::render(
+                mode,
+                screen,
+                metadata,
+                x_threshold,
+                length,
+                ansi_width,
+                step_count,
+                state,
+            )
         });
 
         if return_info {
@@ -515,15 +1184,59 @@ impl Env for CartPoleEnv {
         }
     }
 
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.state = state;
+
+        self.steps_beyond_terminated = None;
+        self.termination_status = TerminationStatus::Running;
+        self.step_count = 0;
+
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+        let x_threshold = self.x_threshold;
+        let length = self.length;
+        let ansi_width = self.ansi_width;
+        let step_count = self.step_count;
+        let state = self.state;
+
+        self.renderer.reset();
+        self.renderer.render_step(&mut |mode| {
+            Self::render(
+                mode,
+                screen,
+                metadata,
+                x_threshold,
+                length,
+                ansi_width,
+                step_count,
+                state,
+            )
+        });
+
+        self.state
+    }
+
     fn render(&mut self, mode: RenderMode) -> crate::utils::renderer::Renders {
         let screen = &mut self.screen;
         let metadata = &self.metadata;
         let x_threshold = self.x_threshold;
         let length = self.length;
+        let ansi_width = self.ansi_width;
+        let step_count = self.step_count;
         let state = self.state;
 
-        let render_fn =
-            &mut |mode| Self::render(mode, screen, metadata, x_threshold, length, state);
+        let render_fn = &mut |mode| {
+            Self::render(
+                mode,
+                screen,
+                metadata,
+                x_threshold,
+                length,
+                ansi_width,
+                step_count,
+                state,
+            )
+        };
         if self.render_mode != RenderMode::None {
             self.renderer.get_renders(render_fn)
         } else {
@@ -534,13 +1247,77 @@ impl Env for CartPoleEnv {
     fn close(&mut self) {
         self.screen.close();
     }
+
+    #[cfg(feature = "checkpoint")]
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let checkpoint = CartPoleCheckpoint {
+            state: self.state,
+            gravity: self.gravity,
+            masscart: self.masscart,
+            masspole: self.masspole,
+            force_mag: self.force_mag,
+            steps_beyond_terminated: self.steps_beyond_terminated,
+            termination_status: self.termination_status,
+            step_count: self.step_count,
+            rand_random: self.rand_random.clone(),
+        };
+
+        Ok(serde_json::to_vec(&checkpoint)?)
+    }
+
+    #[cfg(feature = "checkpoint")]
+    fn load_state(&mut self, bytes: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+        let checkpoint: CartPoleCheckpoint = serde_json::from_slice(bytes)?;
+
+        self.state = checkpoint.state;
+        self.gravity = checkpoint.gravity;
+        self.masscart = checkpoint.masscart;
+        self.masspole = checkpoint.masspole;
+        self.force_mag = checkpoint.force_mag;
+        self.steps_beyond_terminated = checkpoint.steps_beyond_terminated;
+        self.termination_status = checkpoint.termination_status;
+        self.step_count = checkpoint.step_count;
+        self.rand_random = checkpoint.rand_random;
+
+        Ok(())
+    }
+}
+
+/// The parts of a [`CartPoleEnv`] which change while acting on it, including the parameters
+/// altered by domain randomization.
+#[cfg(feature = "checkpoint")]
+#[derive(Serialize, Deserialize)]
+struct CartPoleCheckpoint {
+    state: CartPoleObservation,
+    gravity: O64,
+    masscart: O64,
+    masspole: O64,
+    force_mag: O64,
+    steps_beyond_terminated: Option<usize>,
+    termination_status: TerminationStatus,
+    step_count: usize,
+    rand_random: Pcg64,
+}
+
+impl EnvironmentId for CartPoleEnv {
+    const ID: &'static str = "CartPole-v1";
 }
 
 impl EnvProperties for CartPoleEnv {
+    type Observation = CartPoleObservation;
     type ActionSpace = Discrete;
 
     type ObservationSpace = BoxR<CartPoleObservation>;
 
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: Some(CART_POLE_EPISODE_LENGTH),
+            reward_threshold: Some(475.),
+            nondeterministic: false,
+        }
+    }
+
     fn metadata(&self) -> &Metadata<Self> {
         &self.metadata
     }
@@ -549,6 +1326,22 @@ impl EnvProperties for CartPoleEnv {
         &self.rand_random
     }
 
+    fn episode_length(&self) -> Option<usize> {
+        Some(CART_POLE_EPISODE_LENGTH)
+    }
+
+    fn is_terminated(&self) -> bool {
+        self.termination_status == TerminationStatus::Terminated
+    }
+
+    fn is_truncated(&self) -> bool {
+        self.termination_status == TerminationStatus::Truncated
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
     fn action_space(&self) -> &Self::ActionSpace {
         &self.action_space
     }
@@ -557,3 +1350,458 @@ impl EnvProperties for CartPoleEnv {
         &self.observation_space
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::{
+        CartPoleAction, CartPoleEnv, CartPoleObservation, DomainRandomizationConfig,
+        KinematicsIntegrator, StochasticCartPoleConfig,
+    };
+    use crate::{
+        core::{Env, EnvProperties},
+        envs::wrappers::time_limit::TimeLimit,
+        spaces::{InvalidDiscreteValue, Space},
+        utils::renderer::{RenderMode, Renders},
+    };
+
+    #[test]
+    fn given_wrapped_environment_when_reading_metadata_then_threshold_is_kept_and_limit_is_shortened(
+    ) {
+        let env = TimeLimit::new(CartPoleEnv::new(RenderMode::None), 10);
+        let metadata = env.metadata();
+
+        assert_eq!(metadata.reward_threshold, Some(475.));
+        assert_eq!(metadata.max_episode_steps, Some(10));
+        assert_eq!(metadata.max_episode_steps, env.episode_length());
+        assert_eq!(
+            metadata.reward_threshold,
+            CartPoleEnv::spec().reward_threshold
+        );
+    }
+
+    #[test]
+    fn given_default_parameters_when_deriving_masses_then_products_and_sums_match() {
+        let env = CartPoleEnv::new(RenderMode::None);
+
+        assert_eq!(env.total_mass(), env.masscart + env.masspole);
+        assert_eq!(env.polemass_length(), env.masspole * env.length);
+        assert_eq!(env.polemass_length(), OrderedFloat(0.1 * 0.5));
+    }
+
+    #[test]
+    fn given_default_environment_when_stepped_then_nothing_is_rendered() {
+        let mut env = CartPoleEnv::default();
+        env.reset(Some(0), false, None);
+        env.step(CartPoleAction::Left);
+
+        assert_eq!(env.render_mode, RenderMode::None);
+        assert!(!env.screen.is_open());
+    }
+
+    #[test]
+    #[cfg(feature = "checkpoint")]
+    fn given_saved_state_when_loaded_then_trajectory_is_replayed_identically() {
+        let mut env = CartPoleEnv::new(RenderMode::None);
+        env.reset(Some(0), false, None);
+        let bytes = env.save_state().unwrap();
+
+        let run = |env: &mut CartPoleEnv| {
+            (0..50)
+                .map(|_| {
+                    let action = env.sample_action();
+                    env.step(action)
+                })
+                .collect::<Vec<_>>()
+        };
+        let trajectory = run(&mut env);
+        env.load_state(&bytes).unwrap();
+
+        assert_eq!(run(&mut env), trajectory);
+    }
+
+    #[test]
+    fn given_ansi_mode_when_rendered_then_rows_fit_terminal_width() {
+        let mut env = CartPoleEnv::new(RenderMode::Ansi);
+        env.reset(Some(0), false, None);
+
+        let rows = match env.render(RenderMode::Ansi) {
+            Renders::Ansi(rows) => rows,
+            other => panic!("Expected rows of text, received {:?}.", other),
+        };
+
+        assert!(rows.len() >= 5);
+        assert!(rows.iter().all(|row| row.chars().count() <= 80));
+        assert!(rows.iter().any(|row| row.contains("[===]")));
+    }
+
+    #[cfg(not(feature = "sdl2"))]
+    #[test]
+    fn given_custom_window_size_when_rendered_then_frames_match_configured_dimensions() {
+        for (width, height) in [(300, 200), (800, 600)] {
+            let mut env = CartPoleEnv::builder()
+                .window_width(width)
+                .window_height(height)
+                .render_mode(RenderMode::RgbArray)
+                .build();
+            env.reset(Some(0), false, None);
+            env.step(CartPoleAction::Left);
+
+            let frames = match env.render(RenderMode::RgbArray) {
+                Renders::RgbArray(frames) => frames,
+                other => panic!("Expected a list of frames, received {:?}.", other),
+            };
+
+            assert_eq!(frames.len(), 2);
+            assert!(frames
+                .iter()
+                .all(|frame| frame.width() == width as usize && frame.height() == height as usize));
+        }
+    }
+
+    #[cfg(not(feature = "sdl2"))]
+    #[test]
+    fn given_rgb_array_mode_when_rendered_then_frame_holds_pixels() {
+        let mut env = CartPoleEnv::new(RenderMode::None);
+        env.reset(Some(0), false, None);
+
+        let frame = match env.render(RenderMode::RgbArray) {
+            Renders::SingleRgbArray(frame) => frame,
+            other => panic!("Expected a single frame, received {:?}.", other),
+        };
+
+        assert_eq!(frame.0.len(), 400);
+        assert!(frame.0.iter().all(|row| row.len() == 600));
+        assert!(frame
+            .0
+            .iter()
+            .flatten()
+            .any(|pixel| *pixel != frame.0[0][0]));
+    }
+
+    #[test]
+    fn given_upright_pole_at_rest_when_energy_computed_then_only_potential_energy_remains() {
+        let mut env = CartPoleEnv::new(RenderMode::None);
+        env.reset_to_state(CartPoleObservation::new(
+            OrderedFloat(0.5),
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+        ));
+
+        let expected = (env.masspole * env.gravity * env.length).into_inner();
+        assert!((env.energy() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn given_observations_at_bounds_when_validated_then_termination_and_space_bounds_differ() {
+        let env = CartPoleEnv::new(RenderMode::None);
+        let origin = CartPoleObservation::new(
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+        );
+        let x_threshold = env.x_threshold.into_inner();
+        let theta_threshold = env.theta_threshold_radians.into_inner();
+
+        let at_termination = origin.with_x(x_threshold).with_theta(-theta_threshold);
+        assert!(at_termination.is_valid(&env) && !at_termination.is_done(&env));
+
+        let past_termination = origin.with_x(x_threshold + 1e-9);
+        assert!(past_termination.is_valid(&env) && past_termination.is_done(&env));
+
+        let at_space_bound = origin.with_theta(theta_threshold * 2.);
+        assert!(at_space_bound.is_valid(&env) && at_space_bound.is_done(&env));
+
+        let past_space_bound = origin.with_x(-x_threshold * 2. - 1e-9);
+        assert!(!past_space_bound.is_valid(&env) && past_space_bound.is_done(&env));
+    }
+
+    #[test]
+    fn given_observation_when_fields_replaced_then_accessors_return_new_values() {
+        let observation = CartPoleObservation::new(
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+        )
+        .with_x(1.)
+        .with_x_dot(-0.5)
+        .with_theta(std::f64::consts::FRAC_PI_6)
+        .with_theta_dot(2.);
+
+        assert_eq!(observation.x(), 1.);
+        assert_eq!(observation.x_dot(), -0.5);
+        assert_eq!(observation.theta(), std::f64::consts::FRAC_PI_6);
+        assert_eq!(observation.theta_dot(), 2.);
+        assert!((observation.angle_degrees() - 30.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn given_observation_when_displayed_then_angle_is_shown_in_degrees() {
+        let observation = CartPoleObservation::new(
+            OrderedFloat(0.12345),
+            OrderedFloat(-1.),
+            OrderedFloat(std::f64::consts::FRAC_PI_4),
+            OrderedFloat(0.5),
+        );
+
+        assert_eq!(
+            observation.to_string(),
+            "x=0.123, x_dot=-1.000, θ=45.000°, θ_dot=0.500"
+        );
+    }
+
+    #[test]
+    fn given_gravity_range_when_resetting_then_gravity_varies_within_range() {
+        let mut env = CartPoleEnv::builder()
+            .domain_randomization(DomainRandomizationConfig {
+                gravity_range: Some((8., 12.)),
+                ..Default::default()
+            })
+            .build();
+
+        let gravities: Vec<f64> = (0..100)
+            .map(|seed| {
+                env.reset(Some(seed), false, None);
+                env.current_gravity()
+            })
+            .collect();
+
+        assert!(gravities
+            .iter()
+            .all(|gravity| (8. ..=12.).contains(gravity)));
+        assert!(gravities.iter().any(|gravity| *gravity != gravities[0]));
+        assert_eq!(env.current_masscart(), 1.0);
+    }
+
+    #[test]
+    fn given_known_state_and_actions_when_stepping_then_state_matches_reference_implementation() {
+        let mut env = CartPoleEnv::builder().build();
+        env.reset(Some(0), false, None);
+        env.state = CartPoleObservation::new(
+            OrderedFloat(0.01),
+            OrderedFloat(-0.02),
+            OrderedFloat(0.03),
+            OrderedFloat(0.04),
+        );
+
+        for action in [1, 0, 1, 1, 0, 0, 1, 0, 1, 1] {
+            env.step_discrete(action);
+        }
+
+        let expected = [
+            0.033008390428920745,
+            0.36765098601918744,
+            0.0038214652462337543,
+            -0.4882037724625794,
+        ];
+        for (value, expected) in Vec::from(env.state).iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-12, "{value} != {expected}");
+        }
+    }
+
+    #[test]
+    fn given_fresh_resets_when_stepping_once_then_no_episode_terminates() {
+        let mut env = CartPoleEnv::new(RenderMode::None);
+
+        for seed in 0..1000 {
+            env.reset(Some(seed), false, None);
+            let action_reward = env.step_discrete((seed % 2) as usize);
+
+            assert!(!action_reward.done, "episode with seed {seed} terminated");
+            assert!(action_reward.observation.theta.abs() < env.theta_threshold_radians.0);
+        }
+    }
+
+    #[test]
+    fn given_balanced_pole_when_stepping_500_times_then_last_step_is_truncated() {
+        let mut env = CartPoleEnv::builder().gravity(0.).force_mag(1e-6).build();
+        env.reset(Some(0), false, None);
+        env.state = CartPoleObservation::new(
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+        );
+
+        for step in 1..500 {
+            let action_reward = env.step_discrete(step % 2);
+            assert!(!action_reward.done && !action_reward.truncated);
+        }
+        let action_reward = env.step(CartPoleAction::Left);
+
+        assert!(action_reward.done);
+        assert!(action_reward.truncated);
+        assert_eq!(env.step_count, 500);
+        assert!(env.is_truncated() && !env.is_terminated());
+    }
+
+    #[test]
+    fn given_pole_beyond_threshold_when_stepping_then_episode_is_terminated() {
+        let mut env = CartPoleEnv::new(RenderMode::None);
+        env.reset(Some(0), false, None);
+        env.state = CartPoleObservation::new(
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+            env.theta_threshold_radians,
+            OrderedFloat(1.),
+        );
+
+        let action_reward = env.step(CartPoleAction::Left);
+
+        assert!(action_reward.done && !action_reward.truncated);
+        assert!(env.is_terminated() && !env.is_truncated());
+
+        env.reset(Some(0), false, None);
+        assert!(!env.is_terminated());
+    }
+
+    #[test]
+    fn given_specific_state_when_reset_to_state_called_then_state_matches_exactly() {
+        let mut env = CartPoleEnv::new(RenderMode::None);
+        env.reset(Some(0), false, None);
+        env.step(CartPoleAction::Left);
+        let state = CartPoleObservation::new(
+            OrderedFloat(0.1),
+            OrderedFloat(-0.2),
+            OrderedFloat(0.03),
+            OrderedFloat(-0.04),
+        );
+
+        let observation = env.reset_to_state(state);
+
+        assert_eq!(observation, state);
+        assert_eq!(env.state, state);
+        assert_eq!(env.step_count, 0);
+        assert_eq!(env.steps_beyond_terminated, None);
+    }
+
+    #[test]
+    fn given_custom_gravity_when_stepping_then_state_diverges_from_default() {
+        let mut default_env = CartPoleEnv::builder().build();
+        let mut custom_env = CartPoleEnv::builder().gravity(20.).build();
+        default_env.reset(Some(0), false, None);
+        custom_env.reset(Some(0), false, None);
+
+        for _ in 0..5 {
+            default_env.step(CartPoleAction::Right);
+            custom_env.step(CartPoleAction::Right);
+        }
+
+        assert_eq!(custom_env.gravity.0, 20.);
+        assert_ne!(default_env.state, custom_env.state);
+    }
+
+    #[test]
+    fn given_state_noise_when_stepping_then_trajectory_depends_only_on_seed_and_deviations() {
+        let trajectory = |state_noise| {
+            let mut env = CartPoleEnv::builder().state_noise(state_noise).build();
+            env.reset(Some(0), false, None);
+            (0..20)
+                .map(|_| env.step(CartPoleAction::Right).observation)
+                .collect::<Vec<_>>()
+        };
+        let noiseless = StochasticCartPoleConfig {
+            x_std: 0.,
+            x_dot_std: 0.,
+            theta_std: 0.,
+            theta_dot_std: 0.,
+        };
+
+        let mut deterministic_env = CartPoleEnv::builder().build();
+        deterministic_env.reset(Some(0), false, None);
+        let deterministic: Vec<_> = (0..20)
+            .map(|_| deterministic_env.step(CartPoleAction::Right).observation)
+            .collect();
+
+        assert_eq!(trajectory(noiseless), deterministic);
+        assert_ne!(
+            trajectory(StochasticCartPoleConfig::default()),
+            deterministic
+        );
+        assert_eq!(
+            trajectory(StochasticCartPoleConfig::default()),
+            trajectory(StochasticCartPoleConfig::default())
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn given_semi_implicit_integrator_when_stepping_then_velocity_updates_position_first() {
+        let mut euler_env = CartPoleEnv::builder().build();
+        let mut semi_implicit_env = CartPoleEnv::builder()
+            .kinematics_integrator(KinematicsIntegrator::Other)
+            .build();
+        euler_env.reset(Some(0), false, None);
+        semi_implicit_env.reset(Some(0), false, None);
+        let initial = euler_env.state;
+
+        euler_env.step(CartPoleAction::Right);
+        semi_implicit_env.step(CartPoleAction::Right);
+
+        assert_eq!(
+            semi_implicit_env.kinematics_integrator,
+            KinematicsIntegrator::SemiImplicit
+        );
+        assert_eq!(euler_env.state.x, initial.x + euler_env.tau * initial.x_dot);
+        assert_eq!(
+            semi_implicit_env.state.x,
+            initial.x + semi_implicit_env.tau * semi_implicit_env.state.x_dot
+        );
+    }
+
+    #[test]
+    fn given_environment_when_sampling_actions_then_actions_are_within_action_space() {
+        let mut env = CartPoleEnv::new(RenderMode::None);
+
+        for _ in 0..100 {
+            let action = env.sample_action();
+            assert!(env.action_space.contains(action));
+        }
+    }
+
+    #[test]
+    fn given_cartpole_when_spec_requested_then_matches_gym_registration() {
+        let env = CartPoleEnv::new(RenderMode::None);
+        let spec = CartPoleEnv::spec();
+
+        assert_eq!(spec.id, "CartPole-v1");
+        assert_eq!(spec.max_episode_steps, env.episode_length());
+        assert_eq!(spec.reward_threshold, Some(475.));
+    }
+
+    #[test]
+    fn given_index_outside_action_space_when_converted_then_error_is_returned() {
+        assert_eq!(CartPoleAction::try_from(1), Ok(CartPoleAction::Right));
+        assert_eq!(
+            CartPoleAction::try_from(2),
+            Err(InvalidDiscreteValue { value: 2, n: 2 })
+        );
+    }
+
+    #[test]
+    fn given_integral_index_when_converted_from_vector_then_action_is_returned() {
+        assert_eq!(CartPoleAction::from(vec![1.]), CartPoleAction::Right);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected an integral action index")]
+    fn given_negative_index_when_converted_from_vector_then_panics() {
+        let _ = CartPoleAction::from(vec![-1.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected an integral action index")]
+    fn given_fractional_index_when_converted_from_vector_then_panics() {
+        let _ = CartPoleAction::from(vec![0.7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "length must be strictly positive")]
+    fn given_non_positive_length_when_built_then_panics() {
+        CartPoleEnv::builder().length(0.).build();
+    }
+}
@@ -0,0 +1,475 @@
+use derivative::Derivative;
+use num_traits::Float;
+use ordered_float::OrderedFloat;
+use rand_pcg::Pcg64;
+use serde::Serialize;
+
+use super::mountain_car::{
+    MountainCarEnv, MountainCarObservation, MOUNTAIN_CAR_EPISODE_LENGTH, MOUNTAIN_CAR_RENDER_MODES,
+};
+use crate::{
+    core::{ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId, TerminationStatus},
+    spaces::BoxR,
+    utils::{
+        custom::{screen::Screen, structs::Metadata, traits::Sample, types::O64, util_fns::clip},
+        renderer::{RenderMode, Renderer, Renders},
+        seeding::rand_random,
+    },
+};
+
+/// The force applied to the car, where `-1.0` pushes fully to the left and `1.0` fully to the
+/// right.
+pub type ContinuousMountainCarAction = f64;
+
+/// The state observed in the continuous mountain car environment.
+pub type ContinuousMountainCarObservation = MountainCarObservation;
+
+/// A variant of the mountain car environment which accepts a continuous force instead of a
+/// discrete set of actions.
+///
+/// The action is clipped to `[-1.0, 1.0]` and scaled by [`ContinuousMountainCarEnv::force`]
+/// before being applied to the car. Apart from the action space, the physics, reward and
+/// termination conditions are identical to [`MountainCarEnv`], so both solve the same task.
+///
+/// An episode ends when one of the following conditions occur:
+///     1. Termination: The car reaches the goal position.
+///     2. Truncation: The episode exceeds 200 steps.
+#[derive(Serialize, Derivative, Clone)]
+#[derivative(Debug)]
+pub struct ContinuousMountainCarEnv {
+    /// The minimum position the car can be spawned at.
+    pub min_position: O64,
+    /// The maximum position the cart can be spawned at.
+    pub max_position: O64,
+    /// The max speed the car can reach.
+    pub max_speed: O64,
+    /// The position on the map, where when passed, an episode can be considered terminated.
+    pub goal_position: O64,
+    /// The velocity at which an episode can be considered terminated.
+    pub goal_velocity: O64,
+
+    /// The force applied to the car when the largest action is taken.
+    pub force: O64,
+    /// The gravity constant applied to the environment.
+    pub gravity: O64,
+
+    /// The type of renders produced.
+    pub render_mode: RenderMode,
+
+    /// The range of forces which can be applied.
+    pub action_space: BoxR<ContinuousMountainCarAction>,
+    /// The range of values that can be observed.
+    pub observation_space: BoxR<ContinuousMountainCarObservation>,
+
+    /// The state of the environment.
+    pub state: ContinuousMountainCarObservation,
+
+    /// Additional information provided by the environment.
+    pub metadata: Metadata<Self>,
+
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+    /// The reason the last episode ended, if it did.
+    pub termination_status: TerminationStatus,
+
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    rand_random: Pcg64,
+    screen: Screen,
+    renderer: Renderer,
+}
+
+impl Default for Metadata<ContinuousMountainCarEnv> {
+    fn default() -> Self {
+        Metadata::new(MOUNTAIN_CAR_RENDER_MODES, 30)
+            .with_max_episode_steps(MOUNTAIN_CAR_EPISODE_LENGTH)
+    }
+}
+
+impl ContinuousMountainCarEnv {
+    /// Draws a random action from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> ContinuousMountainCarAction {
+        self.action_space.sample(&mut self.rand_random)
+    }
+
+    /// Generates an instance of the continuous mountain car environment using the same defaults
+    /// as the discrete variant.
+    pub fn new(render_mode: RenderMode) -> Self {
+        let (mut rng, _) = rand_random(None);
+
+        let min_position = OrderedFloat(-1.2);
+        let max_position = OrderedFloat(0.6);
+        let max_speed = OrderedFloat(0.07);
+        let goal_position = OrderedFloat(0.5);
+        let goal_velocity = OrderedFloat(0.);
+
+        let force = OrderedFloat(0.001);
+        let gravity = OrderedFloat(0.0025);
+
+        let low = MountainCarObservation::new(min_position, -max_speed);
+        let high = MountainCarObservation::new(max_position, max_speed);
+
+        let renderer = Renderer::new(render_mode, None, None);
+
+        let state = MountainCarObservation::sample_between(&mut rng, None);
+
+        let metadata = Metadata::default();
+        let screen = Screen::new(
+            600,
+            400,
+            "Continuous Mountain Car",
+            metadata.render_fps,
+            render_mode,
+        );
+
+        let action_space = BoxR::new(-1., 1.);
+        let observation_space = BoxR::new(low, high);
+
+        Self {
+            min_position,
+            max_position,
+            max_speed,
+            goal_position,
+            goal_velocity,
+
+            force,
+            gravity,
+
+            render_mode,
+            renderer,
+
+            action_space,
+            observation_space,
+
+            state,
+            step_count: 0,
+            termination_status: TerminationStatus::Running,
+            rand_random: rng,
+
+            screen,
+
+            metadata,
+        }
+    }
+}
+
+impl Env for ContinuousMountainCarEnv {
+    type Action = ContinuousMountainCarAction;
+    type Info = ();
+    type ResetInfo = ();
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let action = clip(action, self.action_space.low, self.action_space.high);
+
+        let mut position = self.state.position;
+        let mut velocity = self.state.velocity;
+
+        velocity += OrderedFloat(action) * self.force
+            + (OrderedFloat(3.) * position).cos() * (-self.gravity);
+        velocity = clip(velocity, -self.max_speed, self.max_speed);
+
+        position += velocity;
+        position = clip(position, self.min_position, self.max_position);
+
+        if position == self.min_position && velocity < OrderedFloat(0.) {
+            velocity = OrderedFloat(0.);
+        }
+
+        self.step_count += 1;
+
+        let terminated = position >= self.goal_position && velocity >= self.goal_velocity;
+        let truncated = self
+            .episode_length()
+            .is_some_and(|episode_length| self.step_count >= episode_length);
+        let done = terminated || truncated;
+        self.termination_status = TerminationStatus::from_conditions(terminated, truncated);
+
+        let reward: O64 = OrderedFloat(-1.0);
+
+        self.state = MountainCarObservation { position, velocity };
+
+        let max_position = self.max_position;
+        let min_position = self.min_position;
+        let goal_position = self.goal_position;
+        let state = self.state;
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+
+        self.renderer.render_step(&mut |mode| {
+            MountainCarEnv::render(
+                mode,
+                max_position,
+                min_position,
+                goal_position,
+                state,
+                screen,
+                metadata,
+            )
+        });
+
+        ActionReward {
+            observation: self.state,
+            reward,
+            done,
+            truncated,
+            info: None,
+        }
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        let max_position = self.max_position;
+        let min_position = self.min_position;
+        let goal_position = self.goal_position;
+        let state = self.state;
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+
+        let render_fn = &mut |mode| {
+            MountainCarEnv::render(
+                mode,
+                max_position,
+                min_position,
+                goal_position,
+                state,
+                screen,
+                metadata,
+            )
+        };
+
+        if self.render_mode != RenderMode::None {
+            self.renderer.get_renders(render_fn)
+        } else {
+            render_fn(mode)
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (rand_random, _) = rand_random(seed);
+        self.rand_random = rand_random;
+        self.step_count = 0;
+        self.termination_status = TerminationStatus::Running;
+
+        self.state = MountainCarObservation::sample_between(&mut self.rand_random, options);
+
+        self.renderer.reset();
+
+        let max_position = self.max_position;
+        let min_position = self.min_position;
+        let goal_position = self.goal_position;
+        let state = self.state;
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+
+        self.renderer.render_step(&mut |mode| {
+            MountainCarEnv::render(
+                mode,
+                max_position,
+                min_position,
+                goal_position,
+                state,
+                screen,
+                metadata,
+            )
+        });
+
+        if return_info {
+            (self.state, Some(()))
+        } else {
+            (self.state, None)
+        }
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.step_count = 0;
+        self.termination_status = TerminationStatus::Running;
+        self.state = state;
+
+        self.renderer.reset();
+
+        let max_position = self.max_position;
+        let min_position = self.min_position;
+        let goal_position = self.goal_position;
+        let state = self.state;
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+
+        self.renderer.render_step(&mut |mode| {
+            MountainCarEnv::render(
+                mode,
+                max_position,
+                min_position,
+                goal_position,
+                state,
+                screen,
+                metadata,
+            )
+        });
+
+        self.state
+    }
+
+    fn close(&mut self) {
+        self.screen.close();
+    }
+}
+
+impl EnvironmentId for ContinuousMountainCarEnv {
+    const ID: &'static str = "MountainCarContinuous-v0";
+}
+
+impl EnvProperties for ContinuousMountainCarEnv {
+    type Observation = ContinuousMountainCarObservation;
+    type ActionSpace = BoxR<ContinuousMountainCarAction>;
+    type ObservationSpace = BoxR<ContinuousMountainCarObservation>;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            ..MountainCarEnv::spec()
+        }
+    }
+
+    fn metadata(&self) -> &Metadata<Self> {
+        &self.metadata
+    }
+
+    fn rand_random(&self) -> &Pcg64 {
+        &self.rand_random
+    }
+
+    fn episode_length(&self) -> Option<usize> {
+        Some(MOUNTAIN_CAR_EPISODE_LENGTH)
+    }
+
+    fn is_terminated(&self) -> bool {
+        self.termination_status == TerminationStatus::Terminated
+    }
+
+    fn is_truncated(&self) -> bool {
+        self.termination_status == TerminationStatus::Truncated
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        &self.action_space
+    }
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::ContinuousMountainCarEnv;
+    use crate::{
+        core::{Env, EnvProperties},
+        envs::classical_control::mountain_car::{
+            MountainCarAction, MountainCarEnv, MountainCarObservation,
+        },
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_matching_actions_when_stepping_both_variants_then_episodes_are_identical() {
+        let mut continuous = ContinuousMountainCarEnv::new(RenderMode::None);
+        let mut discrete = MountainCarEnv::new(RenderMode::None);
+        assert_eq!(
+            continuous.reset(Some(3), false, None).0,
+            discrete.reset(Some(3), false, None).0
+        );
+
+        for step in 0..300 {
+            let action = if (step / 20) % 2 == 0 {
+                MountainCarAction::Forward
+            } else {
+                MountainCarAction::Backward
+            };
+            let continuous_reward = continuous.step(action.into());
+            let discrete_reward = discrete.step(action);
+
+            assert_eq!(continuous_reward.observation, discrete_reward.observation);
+            assert_eq!(continuous_reward.reward, discrete_reward.reward);
+            assert_eq!(continuous_reward.done, discrete_reward.done);
+            assert_eq!(continuous_reward.truncated, discrete_reward.truncated);
+            if discrete_reward.done {
+                break;
+            }
+        }
+        assert_eq!(continuous.episode_length(), discrete.episode_length());
+        assert_eq!(
+            continuous.metadata().max_episode_steps,
+            discrete.metadata().max_episode_steps
+        );
+    }
+
+    #[test]
+    fn given_action_beyond_bounds_when_stepping_then_clipped_force_is_applied() {
+        let mut env = ContinuousMountainCarEnv::new(RenderMode::None);
+        env.reset_to_state(MountainCarObservation::new(
+            OrderedFloat(-0.5),
+            OrderedFloat(0.),
+        ));
+
+        let action_reward = env.step(3.);
+
+        let velocity = 0.001 - (3. * -0.5_f64).cos() * 0.0025;
+        assert!((action_reward.observation.velocity.into_inner() - velocity).abs() < 1e-12);
+        assert!(
+            (action_reward.observation.position.into_inner() - (-0.5 + velocity)).abs() < 1e-12
+        );
+        assert_eq!(action_reward.reward, OrderedFloat(-1.));
+        assert!(!action_reward.done);
+    }
+
+    #[test]
+    fn given_car_next_to_goal_when_pushed_forward_then_episode_terminates() {
+        let mut env = ContinuousMountainCarEnv::new(RenderMode::None);
+        env.reset_to_state(MountainCarObservation::new(
+            OrderedFloat(0.49),
+            OrderedFloat(0.02),
+        ));
+
+        let action_reward = env.step(0.5);
+
+        assert!(action_reward.done);
+        assert!(!action_reward.truncated);
+        assert!(env.is_terminated() && !env.is_truncated());
+        assert_eq!(action_reward.reward, OrderedFloat(-1.));
+    }
+
+    #[test]
+    fn given_car_stuck_at_min_position_when_stepping_200_times_then_last_step_is_truncated() {
+        let mut env = ContinuousMountainCarEnv::new(RenderMode::None);
+        env.reset(Some(0), false, None);
+        env.state = MountainCarObservation::new(env.min_position, OrderedFloat(0.));
+
+        for _ in 1..200 {
+            let action_reward = env.step(-1.);
+            assert!(!action_reward.done && !action_reward.truncated);
+        }
+        let action_reward = env.step(-1.);
+
+        assert!(action_reward.done);
+        assert!(action_reward.truncated);
+        assert!(env.is_truncated() && !env.is_terminated());
+        assert_eq!(
+            ContinuousMountainCarEnv::spec().max_episode_steps,
+            env.episode_length()
+        );
+
+        env.reset(Some(0), false, None);
+        assert_eq!(env.step_count, 0);
+        assert!(!env.is_truncated());
+    }
+}
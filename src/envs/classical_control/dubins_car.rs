@@ -0,0 +1,580 @@
+use std::{
+    f64::consts::{FRAC_PI_4, PI},
+    fmt::Display,
+};
+
+use derivative::Derivative;
+use derive_new::new;
+use ordered_float::OrderedFloat;
+use rand::{distributions::Uniform, prelude::Distribution, Rng};
+use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId, RewardRange, TerminationStatus,
+    },
+    spaces::{BoundedObservation, BoxR, Discrete, InvalidDiscreteValue, Space},
+    utils::{
+        custom::{structs::Metadata, traits::Sample, util_fns::clip},
+        renderer::{RenderMode, Renders},
+        seeding::rand_random,
+    },
+};
+
+/// The number of steps after which an episode is truncated.
+const DUBINS_CAR_EPISODE_LENGTH: usize = 500;
+/// The reward of every step which does not reach the goal.
+const DUBINS_CAR_STEP_REWARD: f64 = -0.1;
+/// The reward of the step reaching the goal.
+const DUBINS_CAR_GOAL_REWARD: f64 = 1.;
+
+/// The number of columns used when rendering in Ansi mode, spanning `x` within `[-2, 2]`.
+const DUBINS_CAR_ANSI_WIDTH: usize = 41;
+/// The number of rows used when rendering in Ansi mode, spanning `y` within `[-1, 1]`.
+const DUBINS_CAR_ANSI_HEIGHT: usize = 21;
+/// The distance covered by each cell when rendering in Ansi mode.
+const DUBINS_CAR_ANSI_CELL_SIZE: f64 = 0.1;
+
+const DUBINS_CAR_RENDER_MODES: &[RenderMode] = &[RenderMode::Ansi, RenderMode::None];
+
+impl Default for Metadata<DubinsCarEnv> {
+    fn default() -> Self {
+        Metadata::new(DUBINS_CAR_RENDER_MODES, 20).with_max_episode_steps(DUBINS_CAR_EPISODE_LENGTH)
+    }
+}
+
+/// The steering commands which can be applied to the car.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DubinsCarAction {
+    /// Turns the car counterclockwise along its smallest turning circle.
+    TurnLeft,
+    /// Drives the car straight ahead.
+    Straight,
+    /// Turns the car clockwise along its smallest turning circle.
+    TurnRight,
+}
+
+impl DubinsCarAction {
+    /// The direction in which the heading changes, being positive when turning counterclockwise.
+    fn steering(self) -> f64 {
+        match self {
+            DubinsCarAction::TurnLeft => 1.,
+            DubinsCarAction::Straight => 0.,
+            DubinsCarAction::TurnRight => -1.,
+        }
+    }
+}
+
+impl From<DubinsCarAction> for usize {
+    fn from(action: DubinsCarAction) -> Self {
+        match action {
+            DubinsCarAction::TurnLeft => 0,
+            DubinsCarAction::Straight => 1,
+            DubinsCarAction::TurnRight => 2,
+        }
+    }
+}
+
+impl TryFrom<usize> for DubinsCarAction {
+    type Error = InvalidDiscreteValue;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(DubinsCarAction::TurnLeft),
+            1 => Ok(DubinsCarAction::Straight),
+            2 => Ok(DubinsCarAction::TurnRight),
+            _ => Err(InvalidDiscreteValue { value, n: 3 }),
+        }
+    }
+}
+
+/// Builds the action from a vector holding its index within the action space.
+///
+/// Panics if the vector does not hold exactly one valid index.
+impl From<Vec<f64>> for DubinsCarAction {
+    fn from(values: Vec<f64>) -> Self {
+        assert_eq!(values.len(), 1, "Expected 1 value to build an action.");
+
+        let index = values[0];
+        assert!(
+            index.fract() == 0. && index >= 0.,
+            "Expected an integral action index, got {}.",
+            index
+        );
+
+        Self::try_from(index as usize).expect("Expected a valid action index.")
+    }
+}
+
+impl Space<DubinsCarAction> for Discrete {
+    fn contains(&self, value: DubinsCarAction) -> bool {
+        self.contains(usize::from(value))
+    }
+}
+
+impl Display for DubinsCarAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DubinsCarAction::TurnLeft => write!(f, "TurnLeft"),
+            DubinsCarAction::Straight => write!(f, "Straight"),
+            DubinsCarAction::TurnRight => write!(f, "TurnRight"),
+        }
+    }
+}
+
+/// Defines the state found in the Dubins car environment.
+#[derive(new, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct DubinsCarObservation {
+    /// The horizontal position of the car.
+    pub x: f64,
+    /// The vertical position of the car.
+    pub y: f64,
+    /// The heading of the car in radians within `[-PI, PI)`, where `0` points along the `x` axis.
+    pub theta: f64,
+}
+
+impl Sample for DubinsCarObservation {
+    /// Draws each component uniformly between the bounds, which default to a position within
+    /// `0.1` of the origin and any heading.
+    fn sample_between<R: Rng>(rng: &mut R, bounds: Option<BoxR<Self>>) -> Self {
+        let BoxR { low, high } = bounds.unwrap_or({
+            BoxR::new(
+                DubinsCarObservation::new(-0.1, -0.1, -PI),
+                DubinsCarObservation::new(0.1, 0.1, PI),
+            )
+        });
+
+        DubinsCarObservation {
+            x: Uniform::new(low.x, high.x).sample(rng),
+            y: Uniform::new(low.y, high.y).sample(rng),
+            theta: Uniform::new(low.theta, high.theta).sample(rng),
+        }
+    }
+}
+
+impl BoundedObservation for DubinsCarObservation {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        self.x.is_within(&low.x, &high.x)
+            && self.y.is_within(&low.y, &high.y)
+            && self.theta.is_within(&low.theta, &high.theta)
+    }
+}
+
+impl From<DubinsCarObservation> for Vec<f64> {
+    fn from(observation: DubinsCarObservation) -> Self {
+        vec![observation.x, observation.y, observation.theta]
+    }
+}
+
+impl From<Vec<f64>> for DubinsCarObservation {
+    fn from(values: Vec<f64>) -> Self {
+        assert_eq!(
+            values.len(),
+            3,
+            "Expected 3 values to build an observation."
+        );
+
+        DubinsCarObservation::new(values[0], values[1], values[2])
+    }
+}
+
+/// An implementation of the Dubins car, a classical motion planning problem.
+///
+/// The car moves through the plane at a constant speed and can steer left or right along a
+/// circle of a fixed turning radius, but can neither stop nor reverse. Starting near the origin
+/// with a random heading, it should reach the goal region, a circle of radius `0.1` around
+/// `(1, 0)`. Every step is rewarded with `-0.1`, except for the step reaching the goal, which is
+/// rewarded with `1`.
+///
+/// An episode ends when one of the following conditions occur:
+///     1. Termination: The car reaches the goal region.
+///     2. Truncation: The episode exceeds 500 steps.
+#[derive(Serialize, Derivative, Clone)]
+#[derivative(Debug)]
+pub struct DubinsCarEnv {
+    /// The radius of the smallest circle the car can turn along.
+    pub turn_radius: f64,
+    /// The distance covered by the car per second.
+    pub speed: f64,
+    /// The number of seconds between state updates.
+    pub dt: f64,
+    /// The horizontal position of the center of the goal region.
+    pub goal_x: f64,
+    /// The vertical position of the center of the goal region.
+    pub goal_y: f64,
+    /// The radius of the goal region.
+    pub goal_radius: f64,
+
+    /// The type of renders produced.
+    pub render_mode: RenderMode,
+
+    /// The set of actions which can be taken.
+    pub action_space: Discrete,
+    /// The range of values that can be observed.
+    pub observation_space: BoxR<DubinsCarObservation>,
+
+    /// The current state of the environment.
+    pub state: DubinsCarObservation,
+
+    /// Additional information provided by the environment.
+    pub metadata: Metadata<Self>,
+
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+    /// The reason the current episode ended, if it has.
+    pub termination_status: TerminationStatus,
+
+    reward_range: RewardRange,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    rand_random: Pcg64,
+}
+
+impl DubinsCarEnv {
+    /// Generates a Dubins car with a turning radius of `0.5`, a speed of `1` and steps of `0.05`
+    /// seconds.
+    pub fn new(render_mode: RenderMode) -> Self {
+        DubinsCarEnvBuilder::default()
+            .render_mode(render_mode)
+            .build()
+    }
+
+    /// Provides a builder to configure the parameters of the environment, starting from the
+    /// defaults.
+    pub fn builder() -> DubinsCarEnvBuilder {
+        DubinsCarEnvBuilder::default()
+    }
+
+    /// Checks whether the car lies within the goal region.
+    pub fn is_at_goal(&self, observation: &DubinsCarObservation) -> bool {
+        (observation.x - self.goal_x).hypot(observation.y - self.goal_y) <= self.goal_radius
+    }
+
+    /// Draws a random action from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> DubinsCarAction {
+        DubinsCarAction::try_from(self.action_space.sample(&mut self.rand_random))
+            .expect("The action space only holds valid actions.")
+    }
+
+    /// Wraps an angle into the range `[-PI, PI)`.
+    fn angle_normalize(angle: f64) -> f64 {
+        (angle + PI).rem_euclid(2. * PI) - PI
+    }
+
+    /// Draws the plane as rows of text, where the goal is marked with a `G`, the car with an
+    /// arrow pointing closest to its heading and every other cell with `.`.
+    ///
+    /// Positions outside of the drawn area are placed on its nearest edge.
+    fn render_ansi(&self) -> Vec<String> {
+        let cell_of = |x: f64, y: f64| {
+            let column =
+                (x / DUBINS_CAR_ANSI_CELL_SIZE).round() + (DUBINS_CAR_ANSI_WIDTH / 2) as f64;
+            let row = (DUBINS_CAR_ANSI_HEIGHT / 2) as f64 - (y / DUBINS_CAR_ANSI_CELL_SIZE).round();
+            (
+                clip(row, 0., (DUBINS_CAR_ANSI_HEIGHT - 1) as f64) as usize,
+                clip(column, 0., (DUBINS_CAR_ANSI_WIDTH - 1) as f64) as usize,
+            )
+        };
+
+        let theta = self.state.theta;
+        let arrow = if theta.abs() <= FRAC_PI_4 {
+            '>'
+        } else if theta.abs() >= 3. * FRAC_PI_4 {
+            '<'
+        } else if theta > 0. {
+            '^'
+        } else {
+            'v'
+        };
+
+        let mut grid = vec![vec!['.'; DUBINS_CAR_ANSI_WIDTH]; DUBINS_CAR_ANSI_HEIGHT];
+        for (symbol, (x, y)) in [
+            ('G', (self.goal_x, self.goal_y)),
+            (arrow, (self.state.x, self.state.y)),
+        ] {
+            let (row, column) = cell_of(x, y);
+            grid[row][column] = symbol;
+        }
+
+        grid.into_iter().map(String::from_iter).collect()
+    }
+}
+
+/// Creates a Dubins car which does not render.
+impl Default for DubinsCarEnv {
+    fn default() -> Self {
+        Self::new(RenderMode::None)
+    }
+}
+
+/// Configures the parameters of a [`DubinsCarEnv`] before constructing it.
+#[derive(Debug, Clone)]
+pub struct DubinsCarEnvBuilder {
+    turn_radius: f64,
+    speed: f64,
+    dt: f64,
+    render_mode: RenderMode,
+}
+
+impl Default for DubinsCarEnvBuilder {
+    fn default() -> Self {
+        Self {
+            turn_radius: 0.5,
+            speed: 1.,
+            dt: 0.05,
+            render_mode: RenderMode::None,
+        }
+    }
+}
+
+impl DubinsCarEnvBuilder {
+    /// Sets the radius of the smallest circle the car can turn along.
+    pub fn turn_radius(mut self, turn_radius: f64) -> Self {
+        self.turn_radius = turn_radius;
+        self
+    }
+
+    /// Sets the distance covered by the car per second.
+    pub fn speed(mut self, speed: f64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets the number of seconds between state updates.
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = dt;
+        self
+    }
+
+    /// Sets the type of renders produced.
+    pub fn render_mode(mut self, render_mode: RenderMode) -> Self {
+        self.render_mode = render_mode;
+        self
+    }
+
+    /// Creates the Dubins car environment.
+    ///
+    /// Panics if the turning radius, the speed or the time step is not strictly positive.
+    pub fn build(self) -> DubinsCarEnv {
+        for (name, value) in [
+            ("turn_radius", self.turn_radius),
+            ("speed", self.speed),
+            ("dt", self.dt),
+        ] {
+            assert!(value > 0., "{name} must be strictly positive, got {value}.");
+        }
+
+        let (mut rand_random, _) = rand_random(None);
+        let state = DubinsCarObservation::sample_between(&mut rand_random, None);
+
+        DubinsCarEnv {
+            turn_radius: self.turn_radius,
+            speed: self.speed,
+            dt: self.dt,
+            goal_x: 1.,
+            goal_y: 0.,
+            goal_radius: 0.1,
+            render_mode: self.render_mode,
+            action_space: Discrete(3),
+            observation_space: BoxR::new(
+                DubinsCarObservation::new(f64::NEG_INFINITY, f64::NEG_INFINITY, -PI),
+                DubinsCarObservation::new(f64::INFINITY, f64::INFINITY, PI),
+            ),
+            state,
+            metadata: Metadata::default(),
+            step_count: 0,
+            termination_status: TerminationStatus::Running,
+            reward_range: RewardRange::new(
+                OrderedFloat(DUBINS_CAR_STEP_REWARD),
+                OrderedFloat(DUBINS_CAR_GOAL_REWARD),
+            ),
+            rand_random,
+        }
+    }
+}
+
+impl Env for DubinsCarEnv {
+    type Action = DubinsCarAction;
+    type Info = ();
+    type ResetInfo = ();
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let DubinsCarObservation { x, y, theta } = self.state;
+        let distance = self.speed * self.dt;
+
+        self.state = DubinsCarObservation {
+            x: x + distance * theta.cos(),
+            y: y + distance * theta.sin(),
+            theta: Self::angle_normalize(theta + action.steering() * distance / self.turn_radius),
+        };
+
+        self.step_count += 1;
+
+        let terminated = self.is_at_goal(&self.state);
+        let truncated = self
+            .episode_length()
+            .is_some_and(|episode_length| self.step_count >= episode_length);
+        self.termination_status = TerminationStatus::from_conditions(terminated, truncated);
+
+        let reward = if terminated {
+            DUBINS_CAR_GOAL_REWARD
+        } else {
+            DUBINS_CAR_STEP_REWARD
+        };
+
+        ActionReward {
+            observation: self.state,
+            reward: OrderedFloat(reward),
+            done: terminated || truncated,
+            truncated,
+            info: None,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (rand_random, _) = rand_random(seed);
+        self.rand_random = rand_random;
+
+        self.state = DubinsCarObservation::sample_between(&mut self.rand_random, options);
+        self.step_count = 0;
+        self.termination_status = TerminationStatus::Running;
+
+        if return_info {
+            (self.state, Some(()))
+        } else {
+            (self.state, None)
+        }
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.state = state;
+        self.step_count = 0;
+        self.termination_status = TerminationStatus::Running;
+
+        self.state
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        assert!(self.metadata.render_modes.contains(&mode));
+
+        match mode {
+            RenderMode::Ansi => Renders::Ansi(self.render_ansi()),
+            _ => Renders::None,
+        }
+    }
+
+    fn close(&mut self) {}
+}
+
+impl EnvironmentId for DubinsCarEnv {
+    const ID: &'static str = "DubinsCar-v0";
+}
+
+impl EnvProperties for DubinsCarEnv {
+    type Observation = DubinsCarObservation;
+    type ActionSpace = Discrete;
+    type ObservationSpace = BoxR<DubinsCarObservation>;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: Some(DUBINS_CAR_EPISODE_LENGTH),
+            reward_threshold: None,
+            nondeterministic: false,
+        }
+    }
+
+    fn metadata(&self) -> &Metadata<Self> {
+        &self.metadata
+    }
+
+    fn rand_random(&self) -> &Pcg64 {
+        &self.rand_random
+    }
+
+    fn render_mode(&self) -> &RenderMode {
+        &self.render_mode
+    }
+
+    fn reward_range(&self) -> &RewardRange {
+        &self.reward_range
+    }
+
+    fn episode_length(&self) -> Option<usize> {
+        Some(DUBINS_CAR_EPISODE_LENGTH)
+    }
+
+    fn is_terminated(&self) -> bool {
+        self.termination_status == TerminationStatus::Terminated
+    }
+
+    fn is_truncated(&self) -> bool {
+        self.termination_status == TerminationStatus::Truncated
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        &self.action_space
+    }
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::{DubinsCarAction, DubinsCarEnv, DubinsCarObservation};
+    use crate::{
+        core::{Env, EnvProperties},
+        utils::renderer::{RenderMode, Renders},
+    };
+
+    #[test]
+    fn given_car_facing_goal_when_driving_straight_then_goal_is_reached_with_reward() {
+        let mut env = DubinsCarEnv::new(RenderMode::Ansi);
+        env.reset_to_state(DubinsCarObservation::new(0., 0., 0.));
+
+        let mut action_reward = env.step(DubinsCarAction::Straight);
+        while !action_reward.done {
+            assert_eq!(action_reward.reward, OrderedFloat(-0.1));
+            action_reward = env.step(DubinsCarAction::Straight);
+        }
+
+        assert_eq!(action_reward.reward, OrderedFloat(1.));
+        assert!(env.is_terminated());
+        assert_eq!(env.step_count(), 18);
+
+        let Renders::Ansi(rows) = env.render(RenderMode::Ansi) else {
+            panic!("Expected an Ansi render.");
+        };
+        assert_eq!(rows.len(), 21);
+        assert_eq!(&rows[10][28..31], ".>G");
+    }
+
+    #[test]
+    fn given_car_circling_when_stepping_500_times_then_episode_is_truncated() {
+        let mut env = DubinsCarEnv::new(RenderMode::None);
+        env.reset(Some(0), false, None);
+
+        let dones: Vec<bool> = (0..500)
+            .map(|_| env.step(DubinsCarAction::TurnLeft).done)
+            .collect();
+
+        assert!(dones[..499].iter().all(|done| !done));
+        assert!(dones[499]);
+        assert!(env.is_truncated());
+        assert!((-std::f64::consts::PI..std::f64::consts::PI).contains(&env.state.theta));
+    }
+}
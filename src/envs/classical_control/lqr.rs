@@ -0,0 +1,316 @@
+use derivative::Derivative;
+use ordered_float::OrderedFloat;
+use rand_pcg::Pcg64;
+use serde::Serialize;
+
+use crate::{
+    core::{ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId},
+    spaces::BoxR,
+    utils::{
+        custom::{structs::Metadata, traits::Sample},
+        renderer::{RenderMode, Renders},
+        seeding::rand_random,
+    },
+};
+
+/// The largest magnitude of each input, beyond which actions are clipped.
+const LQR_MAX_ACTION: f64 = 10.;
+
+const LQR_RENDER_MODES: &[RenderMode] = &[RenderMode::None];
+
+impl Default for Metadata<LqrEnv> {
+    fn default() -> Self {
+        Metadata::new(LQR_RENDER_MODES, 0)
+    }
+}
+
+/// An implementation of the linear quadratic regulator, the simplest continuous control problem
+/// whose optimal policy is known in closed form.
+///
+/// The system holds `N` states driven by `M` inputs, following `x' = A x + B u`, which is
+/// integrated using explicit Euler steps of `dt` seconds. The agent is rewarded with the negated
+/// cost `x^T Q x + u^T R u` of the state each action is taken from, where each input is first
+/// clipped to `[-10, 10]`. Each episode starts from a state drawn uniformly within `[-1, 1]^N`.
+///
+/// The optimal policy is the linear feedback `u = -K x`, where `K` follows from the discrete-time
+/// Riccati equation of `I + dt A` and `dt B`, making the environment well suited to validating
+/// implementations of learning algorithms.
+///
+/// Episodes never end, so the environment should be wrapped by a
+/// [`TimeLimit`](crate::envs::wrappers::time_limit::TimeLimit).
+#[derive(Serialize, Derivative, Clone)]
+#[derivative(Debug)]
+pub struct LqrEnv {
+    /// The `N x N` matrix describing how the state evolves by itself.
+    pub a: Vec<Vec<f64>>,
+    /// The `N x M` matrix describing how the inputs act on the state.
+    pub b: Vec<Vec<f64>>,
+    /// The `N x N` matrix weighing the cost of the state.
+    pub q: Vec<Vec<f64>>,
+    /// The `M x M` matrix weighing the cost of the inputs.
+    pub r: Vec<Vec<f64>>,
+    /// The number of seconds between state updates.
+    pub dt: f64,
+
+    /// The current state of the system.
+    pub state: Vec<f64>,
+
+    /// The range of inputs which can be applied.
+    pub action_space: BoxR<Vec<f64>>,
+    /// The range of values that can be observed.
+    pub observation_space: BoxR<Vec<f64>>,
+
+    /// Additional information provided by the environment.
+    pub metadata: Metadata<Self>,
+
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    rand_random: Pcg64,
+}
+
+impl LqrEnv {
+    /// Generates a regulator for the given system and costs.
+    ///
+    /// Panics if the dimensions of the matrices are inconsistent with each other, or if `dt` is
+    /// not strictly positive.
+    pub fn new(
+        a: Vec<Vec<f64>>,
+        b: Vec<Vec<f64>>,
+        q: Vec<Vec<f64>>,
+        r: Vec<Vec<f64>>,
+        dt: f64,
+    ) -> Self {
+        let n = a.len();
+        let m = b.first().map_or(0, Vec::len);
+        assert!(n > 0 && m > 0, "A regulator requires states and inputs.");
+        for (name, matrix, rows, columns) in [
+            ("a", &a, n, n),
+            ("b", &b, n, m),
+            ("q", &q, n, n),
+            ("r", &r, m, m),
+        ] {
+            assert!(
+                matrix.len() == rows && matrix.iter().all(|row| row.len() == columns),
+                "{name} must be a {rows}x{columns} matrix."
+            );
+        }
+        assert!(dt > 0., "dt must be strictly positive, got {dt}.");
+
+        let (mut rand_random, _) = rand_random(None);
+        let state = Vec::sample_between(&mut rand_random, Some(Self::initial_bounds(n)));
+
+        Self {
+            a,
+            b,
+            q,
+            r,
+            dt,
+            state,
+            action_space: BoxR::new(vec![-LQR_MAX_ACTION; m], vec![LQR_MAX_ACTION; m]),
+            observation_space: BoxR::new(vec![f64::NEG_INFINITY; n], vec![f64::INFINITY; n]),
+            metadata: Metadata::default(),
+            step_count: 0,
+            rand_random,
+        }
+    }
+
+    /// Generates a regulator of the double integrator, a unit mass whose position and velocity
+    /// are controlled by the force applied, with identity costs and steps of `0.1` seconds.
+    pub fn double_integrator() -> Self {
+        Self::new(
+            vec![vec![0., 1.], vec![0., 0.]],
+            vec![vec![0.], vec![1.]],
+            vec![vec![1., 0.], vec![0., 1.]],
+            vec![vec![1.]],
+            0.1,
+        )
+    }
+
+    /// Computes the cost `x^T Q x + u^T R u` of applying the inputs from the given state.
+    pub fn cost(&self, state: &[f64], action: &[f64]) -> f64 {
+        quadratic_form(&self.q, state) + quadratic_form(&self.r, action)
+    }
+
+    /// Draws a random action from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> Vec<f64> {
+        self.action_space.sample(&mut self.rand_random)
+    }
+
+    fn initial_bounds(n: usize) -> BoxR<Vec<f64>> {
+        BoxR::new(vec![-1.; n], vec![1.; n])
+    }
+}
+
+/// Creates the regulator of the double integrator.
+impl Default for LqrEnv {
+    fn default() -> Self {
+        Self::double_integrator()
+    }
+}
+
+/// Multiplies the matrix with the vector.
+fn mat_vec(matrix: &[Vec<f64>], vector: &[f64]) -> Vec<f64> {
+    matrix
+        .iter()
+        .map(|row| row.iter().zip(vector).map(|(a, b)| a * b).sum())
+        .collect()
+}
+
+/// Computes `v^T M v`.
+fn quadratic_form(matrix: &[Vec<f64>], vector: &[f64]) -> f64 {
+    mat_vec(matrix, vector)
+        .iter()
+        .zip(vector)
+        .map(|(a, b)| a * b)
+        .sum()
+}
+
+impl Env for LqrEnv {
+    type Action = Vec<f64>;
+    type Info = ();
+    type ResetInfo = ();
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        assert_eq!(
+            action.len(),
+            self.r.len(),
+            "Expected {} inputs.",
+            self.r.len()
+        );
+        let action: Vec<f64> = action
+            .iter()
+            .zip(self.action_space.low.iter().zip(&self.action_space.high))
+            .map(|(input, (low, high))| input.clamp(*low, *high))
+            .collect();
+
+        let cost = self.cost(&self.state, &action);
+
+        let drift = mat_vec(&self.a, &self.state);
+        let control = mat_vec(&self.b, &action);
+        for ((x, drift), control) in self.state.iter_mut().zip(drift).zip(control) {
+            *x += self.dt * (drift + control);
+        }
+
+        self.step_count += 1;
+
+        ActionReward {
+            observation: self.state.clone(),
+            reward: OrderedFloat(-cost),
+            done: false,
+            truncated: false,
+            info: None,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (rand_random, _) = rand_random(seed);
+        self.rand_random = rand_random;
+        self.step_count = 0;
+
+        let bounds = options.unwrap_or_else(|| Self::initial_bounds(self.a.len()));
+        self.state = Vec::sample_between(&mut self.rand_random, Some(bounds));
+
+        if return_info {
+            (self.state.clone(), Some(()))
+        } else {
+            (self.state.clone(), None)
+        }
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.step_count = 0;
+        self.state = state;
+
+        self.state.clone()
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        assert!(self.metadata.render_modes.contains(&mode));
+
+        Renders::None
+    }
+
+    fn close(&mut self) {}
+}
+
+impl EnvironmentId for LqrEnv {
+    const ID: &'static str = "LinearQuadraticRegulator-v0";
+}
+
+impl EnvProperties for LqrEnv {
+    type Observation = Vec<f64>;
+    type ActionSpace = BoxR<Vec<f64>>;
+    type ObservationSpace = BoxR<Vec<f64>>;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: None,
+            reward_threshold: None,
+            nondeterministic: false,
+        }
+    }
+
+    fn metadata(&self) -> &Metadata<Self> {
+        &self.metadata
+    }
+
+    fn rand_random(&self) -> &Pcg64 {
+        &self.rand_random
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        &self.action_space
+    }
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LqrEnv;
+    use crate::core::Env;
+
+    /// The optimal gain of the default double integrator, solving the discrete-time Riccati
+    /// equation of its Euler discretization.
+    const OPTIMAL_GAIN: [f64; 2] = [0.917_041_547_351_757_5, 1.682_052_159_042_119_4];
+
+    fn episode_return(env: &mut LqrEnv, mut policy: impl FnMut(&mut LqrEnv) -> Vec<f64>) -> f64 {
+        env.reset(Some(0), false, None);
+        (0..200)
+            .map(|_| {
+                let action = policy(env);
+                env.step(action).reward.into_inner()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn given_double_integrator_when_following_optimal_gain_then_return_beats_random_actions() {
+        let mut env = LqrEnv::double_integrator();
+
+        let optimal = episode_return(&mut env, |env| {
+            vec![-OPTIMAL_GAIN[0] * env.state[0] - OPTIMAL_GAIN[1] * env.state[1]]
+        });
+        assert!(env.state.iter().all(|x| x.abs() < 1e-3));
+        let random = episode_return(&mut env, LqrEnv::sample_action);
+        let idle = episode_return(&mut env, |_| vec![0.]);
+
+        assert!(optimal > random);
+        assert!(optimal > idle);
+    }
+}
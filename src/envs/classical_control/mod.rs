@@ -1,4 +1,14 @@
+/// Holds all structures related to the acrobot environment.
+pub mod acrobot;
 /// Holds all structures related to the cartpole environment.
 pub mod cartpole;
+/// Holds all structures related to the continuous mountain car environment.
+pub mod continuous_mountain_car;
+/// Holds all structures related to the Dubins car environment.
+pub mod dubins_car;
+/// Holds all structures related to the linear quadratic regulator environment.
+pub mod lqr;
 /// Holds all structures related to mountain car environment.
 pub mod mountain_car;
+/// Holds all structures related to the pendulum environment.
+pub mod pendulum;
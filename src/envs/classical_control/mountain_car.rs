@@ -1,4 +1,7 @@
-use std::{fmt::Debug, iter::zip};
+use std::{
+    fmt::{Debug, Display},
+    iter::zip,
+};
 
 use derivative::Derivative;
 use derive_new::new;
@@ -15,14 +18,14 @@ use rand::{
     Rng,
 };
 use rand_pcg::Pcg64;
-use sdl2::{gfx::primitives::DrawRenderer, pixels::Color, rect::Point};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    core::{ActionReward, Env, EnvProperties},
-    spaces::{self, BoxR, Discrete, Space},
+    core::{ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId, TerminationStatus},
+    spaces::{self, BoundedObservation, BoxR, Clamp, Discrete, InvalidDiscreteValue, Space},
     utils::{
         custom::{
+            canvas::{Color, DrawRenderer, Point},
             screen::{Screen, ScreenGuiTransformations},
             structs::Metadata,
             traits::Sample,
@@ -76,6 +79,11 @@ pub struct MountainCarEnv {
     /// Additional information provided by the environment.
     pub metadata: Metadata<Self>,
 
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+    /// The reason the current episode ended, if it has.
+    pub termination_status: TerminationStatus,
+
     #[serde(skip_serializing)]
     #[derivative(Debug = "ignore")]
     rand_random: Pcg64,
@@ -101,25 +109,115 @@ impl Clone for MountainCarEnv {
             state: self.state,
             rand_random: self.rand_random.clone(),
             metadata: self.metadata.clone(),
+            step_count: self.step_count,
+            termination_status: self.termination_status,
         }
     }
 }
 
-const MOUNTAIN_CAR_RENDER_MODES: &[RenderMode] = &[
+pub(crate) const MOUNTAIN_CAR_RENDER_MODES: &[RenderMode] = &[
     RenderMode::Human,
     RenderMode::RgbArray,
     RenderMode::SingleRgbArray,
+    RenderMode::Ansi,
     RenderMode::None,
 ];
 
+/// The number of columns used when rendering in Ansi mode.
+const MOUNTAIN_CAR_ANSI_WIDTH: usize = 80;
+/// The number of rows the terrain spans when rendering in Ansi mode.
+const MOUNTAIN_CAR_ANSI_HEIGHT: usize = 10;
+
+/// The number of steps after which an episode is truncated.
+pub(crate) const MOUNTAIN_CAR_EPISODE_LENGTH: usize = 200;
+
 impl Default for Metadata<MountainCarEnv> {
     fn default() -> Self {
         Metadata::new(MOUNTAIN_CAR_RENDER_MODES, 30)
+            .with_max_episode_steps(MOUNTAIN_CAR_EPISODE_LENGTH)
+    }
+}
+
+/// The accelerations which can be applied to the car.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MountainCarAction {
+    /// Accelerates the car to the left.
+    Backward,
+    /// Leaves the car to move under gravity alone.
+    Neutral,
+    /// Accelerates the car to the right.
+    Forward,
+}
+
+impl From<MountainCarAction> for f64 {
+    fn from(action: MountainCarAction) -> Self {
+        match action {
+            MountainCarAction::Backward => -1.,
+            MountainCarAction::Neutral => 0.,
+            MountainCarAction::Forward => 1.,
+        }
+    }
+}
+
+impl From<MountainCarAction> for usize {
+    fn from(action: MountainCarAction) -> Self {
+        match action {
+            MountainCarAction::Backward => 0,
+            MountainCarAction::Neutral => 1,
+            MountainCarAction::Forward => 2,
+        }
+    }
+}
+
+/// Builds the action from a vector holding its index within the action space.
+///
+/// Panics if the vector does not hold exactly one valid index.
+impl From<Vec<f64>> for MountainCarAction {
+    fn from(values: Vec<f64>) -> Self {
+        assert_eq!(values.len(), 1, "Expected 1 value to build an action.");
+
+        let index = values[0];
+        assert!(
+            index.fract() == 0. && index >= 0.,
+            "Expected an integral action index, got {}.",
+            index
+        );
+
+        Self::try_from(index as usize).expect("Expected a valid action index.")
+    }
+}
+
+impl TryFrom<usize> for MountainCarAction {
+    type Error = InvalidDiscreteValue;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MountainCarAction::Backward),
+            1 => Ok(MountainCarAction::Neutral),
+            2 => Ok(MountainCarAction::Forward),
+            _ => Err(InvalidDiscreteValue { value, n: 3 }),
+        }
+    }
+}
+
+impl Space<MountainCarAction> for Discrete {
+    fn contains(&self, value: MountainCarAction) -> bool {
+        self.contains(usize::from(value))
+    }
+}
+
+impl Display for MountainCarAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MountainCarAction::Backward => write!(f, "Backward"),
+            MountainCarAction::Neutral => write!(f, "Neutral"),
+            MountainCarAction::Forward => write!(f, "Forward"),
+        }
     }
 }
 
 /// Utility structure intended to reduce confusion around meaning of properties.
-#[derive(Debug, new, Copy, Clone, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, new, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MountainCarObservation {
     /// The position the car exists on the mountain.
     pub position: O64,
@@ -127,6 +225,20 @@ pub struct MountainCarObservation {
     pub velocity: O64,
 }
 
+impl MountainCarObservation {
+    /// Checks whether the position and velocity lie within the observation space of the
+    /// environment.
+    pub fn is_valid(&self, env: &MountainCarEnv) -> bool {
+        env.observation_space.contains(*self)
+    }
+}
+
+impl Display for MountainCarObservation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "pos={:.3}, vel={:.4}", self.position, self.velocity)
+    }
+}
+
 /// The structure reponsible for uniformly sampling a mountain car observation.
 pub struct UniformMountainCarObservation {
     /// The sampler responsible for deriving a position.
@@ -190,12 +302,40 @@ impl Sample for MountainCarObservation {
     }
 }
 
+impl BoundedObservation for MountainCarObservation {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        self.position.is_within(&low.position, &high.position)
+            && self.velocity.is_within(&low.velocity, &high.velocity)
+    }
+}
+
+impl Clamp for MountainCarObservation {
+    fn clamp_to(self, low: &Self, high: &Self) -> Self {
+        MountainCarObservation {
+            position: self.position.clamp_to(&low.position, &high.position),
+            velocity: self.velocity.clamp_to(&low.velocity, &high.velocity),
+        }
+    }
+}
+
 impl From<MountainCarObservation> for Vec<f64> {
     fn from(o: MountainCarObservation) -> Self {
         vec![o.position.into_inner(), o.velocity.into_inner()]
     }
 }
 
+impl From<Vec<f64>> for MountainCarObservation {
+    fn from(values: Vec<f64>) -> Self {
+        assert_eq!(
+            values.len(),
+            2,
+            "Expected 2 values to build an observation."
+        );
+
+        MountainCarObservation::new(OrderedFloat(values[0]), OrderedFloat(values[1]))
+    }
+}
+
 impl MountainCarEnv {
     fn height(xs: &[O64]) -> Vec<O64> {
         Vec::from_iter(
@@ -204,17 +344,78 @@ impl MountainCarEnv {
         )
     }
 
-    fn render(
+    /// Draws the terrain, the car (`C`) and the flag (`F`) as rows of text, each placed on top
+    /// of the terrain at its horizontal position.
+    fn render_ansi(
+        max_position: O64,
+        min_position: O64,
+        goal_position: O64,
+        state: MountainCarObservation,
+    ) -> Vec<String> {
+        let width = MOUNTAIN_CAR_ANSI_WIDTH;
+        let world_width = max_position - min_position;
+        let column_of = |position: O64| {
+            let fraction = ((position - min_position) / world_width).into_inner();
+            (clip(fraction, 0., 1.) * (width - 1) as f64).round() as usize
+        };
+
+        let xs: Vec<O64> = (0..width)
+            .map(|column| min_position + world_width * (column as f64 / (width - 1) as f64))
+            .collect();
+        let heights = Self::height(&xs);
+        // The height of the terrain lies within [0.1, 1.0].
+        let rows: Vec<usize> = heights
+            .iter()
+            .map(|height| {
+                let fraction = (height.into_inner() - 0.1) / 0.9;
+                ((1. - fraction) * (MOUNTAIN_CAR_ANSI_HEIGHT - 1) as f64).round() as usize + 1
+            })
+            .collect();
+
+        let mut grid = vec![vec![' '; width]; MOUNTAIN_CAR_ANSI_HEIGHT + 1];
+        for (column, &row) in rows.iter().enumerate() {
+            let left = heights[column.saturating_sub(1)];
+            let right = heights[(column + 1).min(width - 1)];
+            let height = heights[column];
+            grid[row][column] = if height <= left && height <= right {
+                '_'
+            } else if height >= left && height >= right {
+                '^'
+            } else if left < right {
+                '/'
+            } else {
+                '\\'
+            };
+        }
+
+        for (symbol, position) in [('F', goal_position), ('C', state.position)] {
+            let column = column_of(position);
+            grid[rows[column] - 1][column] = symbol;
+        }
+
+        grid.into_iter().map(String::from_iter).collect()
+    }
+
+    pub(crate) fn render<T>(
         mode: RenderMode,
         max_position: O64,
         min_position: O64,
         goal_position: O64,
         state: MountainCarObservation,
         screen: &mut Screen,
-        metadata: &Metadata<Self>,
+        metadata: &Metadata<T>,
     ) -> Renders {
         assert!(metadata.render_modes.contains(&mode));
 
+        if mode == RenderMode::Ansi {
+            return Renders::Ansi(Self::render_ansi(
+                max_position,
+                min_position,
+                goal_position,
+                state,
+            ));
+        }
+
         screen.load_gui();
         screen.consume_events();
 
@@ -232,7 +433,7 @@ impl MountainCarEnv {
 
                 let xs = Vec::from_iter(
                     (0..100)
-                        .map(|index| (((max_position - min_position) / 100.) * index as f64))
+                        .map(|index| ((max_position - min_position) / 100.) * index as f64)
                         .map(|value| value + min_position),
                 );
 
@@ -336,19 +537,184 @@ impl MountainCarEnv {
         screen.render(mode)
     }
 
+    /// Computes the mechanical energy of the car, being the sum of its kinetic energy
+    /// `0.5 * velocity^2` and its potential energy `gravity * height(position)`, where the height
+    /// is the one used when rendering the hills.
+    pub fn energy(&self) -> f64 {
+        self.energy_at(self.state.position, self.state.velocity)
+    }
+
+    /// Computes the mechanical energy of a car at the goal position, moving at the goal velocity.
+    pub fn goal_energy(&self) -> f64 {
+        self.energy_at(self.goal_position, self.goal_velocity)
+    }
+
+    pub(crate) fn energy_at(&self, position: O64, velocity: O64) -> f64 {
+        let height = Self::height(&[position])[0];
+        (velocity * velocity * 0.5 + self.gravity * height).into_inner()
+    }
+
+    /// Draws a random action from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> MountainCarAction {
+        MountainCarAction::try_from(self.action_space.sample(&mut self.rand_random))
+            .expect("The action space only holds valid actions.")
+    }
+
+    /// Acts on the environment using the index of an action, where `0` accelerates the car
+    /// backward, `1` leaves it be and `2` accelerates it forward.
+    ///
+    /// Panics if the index does not correspond to an action.
+    pub fn step_discrete(&mut self, action: usize) -> ActionReward<MountainCarObservation, ()> {
+        let action = MountainCarAction::try_from(action)
+            .unwrap_or_else(|err| panic!("{} (usize) invalid: {}", action, err));
+        self.step(action)
+    }
+
     /// Generates an instance of the mountain car environment using the defaults provided in the
     /// paper.
     pub fn new(render_mode: RenderMode) -> Self {
-        let (mut rng, _) = rand_random(None);
+        MountainCarEnvBuilder::default()
+            .render_mode(render_mode)
+            .build()
+    }
 
-        let min_position = OrderedFloat(-1.2);
-        let max_position = OrderedFloat(0.6);
-        let max_speed = OrderedFloat(0.07);
-        let goal_position = OrderedFloat(0.5);
-        let goal_velocity = OrderedFloat(0.);
+    /// Provides a builder to configure the parameters of the environment, starting from the
+    /// defaults found in the paper.
+    pub fn builder() -> MountainCarEnvBuilder {
+        MountainCarEnvBuilder::default()
+    }
+}
 
-        let force = OrderedFloat(0.001);
-        let gravity = OrderedFloat(0.0025);
+/// Creates a mountain car environment which does not render.
+///
+/// # Examples
+///
+/// ```rust
+/// use gym_rs::{core::Env, envs::classical_control::mountain_car::MountainCarEnv};
+///
+/// let mut env = MountainCarEnv::default();
+/// env.reset(None, false, None);
+/// ```
+impl Default for MountainCarEnv {
+    fn default() -> Self {
+        Self::new(RenderMode::None)
+    }
+}
+
+/// Configures the parameters of a [`MountainCarEnv`] before constructing it.
+///
+/// Each parameter defaults to the value used in the paper.
+#[derive(Debug, Clone)]
+pub struct MountainCarEnvBuilder {
+    min_position: O64,
+    max_position: O64,
+    max_speed: O64,
+    goal_position: O64,
+    goal_velocity: O64,
+    force: O64,
+    gravity: O64,
+    render_mode: RenderMode,
+    seed: Option<u64>,
+}
+
+impl Default for MountainCarEnvBuilder {
+    fn default() -> Self {
+        Self {
+            min_position: OrderedFloat(-1.2),
+            max_position: OrderedFloat(0.6),
+            max_speed: OrderedFloat(0.07),
+            goal_position: OrderedFloat(0.5),
+            goal_velocity: OrderedFloat(0.),
+            force: OrderedFloat(0.001),
+            gravity: OrderedFloat(0.0025),
+            render_mode: RenderMode::None,
+            seed: None,
+        }
+    }
+}
+
+impl MountainCarEnvBuilder {
+    /// Sets the gravity constant applied to the environment.
+    pub fn gravity(mut self, gravity: O64) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Sets the force applied to the car by an accelerating action.
+    pub fn force(mut self, force: O64) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Sets the minimum position the car can reach.
+    pub fn min_position(mut self, min_position: O64) -> Self {
+        self.min_position = min_position;
+        self
+    }
+
+    /// Sets the maximum position the car can reach.
+    pub fn max_position(mut self, max_position: O64) -> Self {
+        self.max_position = max_position;
+        self
+    }
+
+    /// Sets the max speed the car can reach.
+    pub fn max_speed(mut self, max_speed: O64) -> Self {
+        self.max_speed = max_speed;
+        self
+    }
+
+    /// Sets the position which, when passed, terminates an episode.
+    pub fn goal_position(mut self, goal_position: O64) -> Self {
+        self.goal_position = goal_position;
+        self
+    }
+
+    /// Sets the velocity the car must reach at the goal position to terminate an episode.
+    pub fn goal_velocity(mut self, goal_velocity: O64) -> Self {
+        self.goal_velocity = goal_velocity;
+        self
+    }
+
+    /// Sets the type of renders produced.
+    pub fn render_mode(mut self, render_mode: RenderMode) -> Self {
+        self.render_mode = render_mode;
+        self
+    }
+
+    /// Sets the seed of the generator responsible for the initial state.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Creates the mountain car environment.
+    ///
+    /// Panics unless `min_position < goal_position <= max_position` and the max speed is strictly
+    /// positive.
+    pub fn build(self) -> MountainCarEnv {
+        let MountainCarEnvBuilder {
+            min_position,
+            max_position,
+            max_speed,
+            goal_position,
+            goal_velocity,
+            force,
+            gravity,
+            render_mode,
+            seed,
+        } = self;
+
+        assert!(
+            min_position < goal_position && goal_position <= max_position,
+            "goal_position must lie within ({min_position}, {max_position}], got {goal_position}."
+        );
+        assert!(
+            max_speed > OrderedFloat(0.),
+            "max_speed must be strictly positive, got {max_speed}."
+        );
+
+        let (mut rng, _) = rand_random(seed);
 
         let low = MountainCarObservation::new(min_position, -max_speed);
         let high = MountainCarObservation::new(max_position, max_speed);
@@ -358,12 +724,12 @@ impl MountainCarEnv {
         let state = MountainCarObservation::sample_between(&mut rng, None);
 
         let metadata = Metadata::default();
-        let screen = Screen::new(400, 600, "Mountain Car", metadata.render_fps, render_mode);
+        let screen = Screen::new(600, 400, "Mountain Car", metadata.render_fps, render_mode);
 
         let action_space = spaces::Discrete(3);
         let observation_space = spaces::BoxR::new(low, high);
 
-        Self {
+        MountainCarEnv {
             min_position,
             max_position,
             max_speed,
@@ -385,30 +751,26 @@ impl MountainCarEnv {
             screen,
 
             metadata,
+
+            step_count: 0,
+            termination_status: TerminationStatus::Running,
         }
     }
 }
 
 impl Env for MountainCarEnv {
-    type Action = usize;
-    type Observation = MountainCarObservation;
+    type Action = MountainCarAction;
     type Info = ();
     type ResetInfo = ();
 
     fn step(
         &mut self,
         action: Self::Action,
-    ) -> ActionReward<<Self as Env>::Observation, Self::Info> {
-        assert!(
-            self.action_space.contains(action),
-            "{} (usize) invalid",
-            action
-        );
-
+    ) -> ActionReward<<Self as EnvProperties>::Observation, Self::Info> {
         let mut position = self.state.position;
         let mut velocity = self.state.velocity;
 
-        velocity += OrderedFloat((action as f64) - 1.) * self.force
+        velocity += OrderedFloat(f64::from(action)) * self.force
             + (OrderedFloat(3.) * position).cos() * (-self.gravity);
         velocity = clip(velocity, -self.max_speed, self.max_speed);
 
@@ -419,17 +781,42 @@ impl Env for MountainCarEnv {
             velocity = OrderedFloat(0.);
         }
 
-        let done: bool = position >= self.goal_position && velocity >= self.goal_velocity;
+        self.step_count += 1;
+
+        let terminated = position >= self.goal_position && velocity >= self.goal_velocity;
+        let truncated = self
+            .episode_length()
+            .is_some_and(|episode_length| self.step_count >= episode_length);
+        let done = terminated || truncated;
+        self.termination_status = TerminationStatus::from_conditions(terminated, truncated);
         let reward: O64 = OrderedFloat(-1.0);
 
         self.state = MountainCarObservation { position, velocity };
-        self.render(self.render_mode);
+
+        let max_position = self.max_position;
+        let min_position = self.min_position;
+        let goal_position = self.goal_position;
+        let state = self.state;
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+
+        self.renderer.render_step(&mut |mode| {
+            Self::render(
+                mode,
+                max_position,
+                min_position,
+                goal_position,
+                state,
+                screen,
+                metadata,
+            )
+        });
 
         ActionReward {
             observation: self.state,
             reward,
             done,
-            truncated: false,
+            truncated,
             info: None,
         }
     }
@@ -471,6 +858,8 @@ impl Env for MountainCarEnv {
         self.rand_random = rand_random;
 
         self.state = MountainCarObservation::sample_between(&mut self.rand_random, options);
+        self.step_count = 0;
+        self.termination_status = TerminationStatus::Running;
 
         self.renderer.reset();
 
@@ -500,17 +889,60 @@ impl Env for MountainCarEnv {
         }
     }
 
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.state = state;
+        self.step_count = 0;
+        self.termination_status = TerminationStatus::Running;
+
+        self.renderer.reset();
+
+        let max_position = self.max_position;
+        let min_position = self.min_position;
+        let goal_position = self.goal_position;
+        let state = self.state;
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+
+        self.renderer.render_step(&mut |mode| {
+            Self::render(
+                mode,
+                max_position,
+                min_position,
+                goal_position,
+                state,
+                screen,
+                metadata,
+            )
+        });
+
+        self.state
+    }
+
     fn close(&mut self) {
         self.screen.close();
     }
 }
 
+impl EnvironmentId for MountainCarEnv {
+    const ID: &'static str = "MountainCar-v0";
+}
+
 impl EnvProperties for MountainCarEnv
 where
     Self: Sized,
 {
+    type Observation = MountainCarObservation;
     type ActionSpace = Discrete;
-    type ObservationSpace = spaces::BoxR<<Self as Env>::Observation>;
+    type ObservationSpace = spaces::BoxR<<Self as EnvProperties>::Observation>;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: Some(MOUNTAIN_CAR_EPISODE_LENGTH),
+            reward_threshold: Some(-110.),
+            nondeterministic: false,
+        }
+    }
 
     fn metadata(&self) -> &Metadata<Self> {
         &self.metadata
@@ -520,6 +952,22 @@ where
         &self.rand_random
     }
 
+    fn episode_length(&self) -> Option<usize> {
+        Some(MOUNTAIN_CAR_EPISODE_LENGTH)
+    }
+
+    fn is_terminated(&self) -> bool {
+        self.termination_status == TerminationStatus::Terminated
+    }
+
+    fn is_truncated(&self) -> bool {
+        self.termination_status == TerminationStatus::Truncated
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
     fn action_space(&self) -> &Self::ActionSpace {
         &self.action_space
     }
@@ -528,3 +976,435 @@ where
         &self.observation_space
     }
 }
+
+/// The lowest desired goal drawn at a reset without bounds, being the right edge of the region
+/// the car starts in, so no goal is achieved before the first step.
+const GOAL_CONDITIONED_MIN_GOAL: f64 = -0.4;
+/// The highest desired goal drawn at a reset without bounds, being the goal of mountain car.
+const GOAL_CONDITIONED_MAX_GOAL: f64 = 0.5;
+
+/// The observation of a [`GoalConditionedMountainCarEnv`], pairing the state of the car with the
+/// goal it has achieved and the goal it should reach.
+#[derive(new, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct GoalConditionedObservation {
+    /// The state of the car.
+    pub observation: MountainCarObservation,
+    /// The goal achieved so far, being the current position of the car.
+    pub achieved_goal: f64,
+    /// The position the car should reach.
+    pub desired_goal: f64,
+}
+
+impl Sample for GoalConditionedObservation {
+    /// Draws the state of the car as [`MountainCarObservation`] does, and the desired goal
+    /// uniformly between the given bounds, or within `[-0.4, 0.5]` without any.
+    fn sample_between<R: Rng>(rng: &mut R, bounds: Option<BoxR<Self>>) -> Self {
+        let (observation_bounds, goal_bounds) = match bounds {
+            Some(BoxR { low, high }) => (
+                Some(BoxR::new(low.observation, high.observation)),
+                (low.desired_goal, high.desired_goal),
+            ),
+            None => (None, (GOAL_CONDITIONED_MIN_GOAL, GOAL_CONDITIONED_MAX_GOAL)),
+        };
+
+        let observation = MountainCarObservation::sample_between(rng, observation_bounds);
+        let desired_goal = Uniform::new(goal_bounds.0, goal_bounds.1).sample(rng);
+
+        GoalConditionedObservation {
+            observation,
+            achieved_goal: observation.position.into_inner(),
+            desired_goal,
+        }
+    }
+}
+
+impl BoundedObservation for GoalConditionedObservation {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        self.observation
+            .is_within(&low.observation, &high.observation)
+            && (low.achieved_goal..=high.achieved_goal).contains(&self.achieved_goal)
+            && (low.desired_goal..=high.desired_goal).contains(&self.desired_goal)
+    }
+}
+
+impl From<GoalConditionedObservation> for Vec<f64> {
+    fn from(o: GoalConditionedObservation) -> Self {
+        let mut values: Vec<f64> = o.observation.into();
+        values.extend([o.achieved_goal, o.desired_goal]);
+        values
+    }
+}
+
+/// A variant of mountain car where the position to reach is drawn anew at each reset and made
+/// part of the observation, as required by goal-conditioned methods such as hindsight experience
+/// replay.
+///
+/// The car moves as in [`MountainCarEnv`], and the episode terminates once its position reaches
+/// the desired goal. Since the reward only depends on the achieved and desired goal, as computed
+/// by [`GoalConditionedMountainCarEnv::compute_reward`], transitions can be relabelled with other
+/// goals without simulating them again.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoalConditionedMountainCarEnv {
+    /// The environment moving the car.
+    pub env: MountainCarEnv,
+    /// The position the car should reach in the current episode.
+    pub desired_goal: f64,
+    /// The range of values that can be observed.
+    pub observation_space: BoxR<GoalConditionedObservation>,
+    /// The reason the current episode ended, if it has.
+    pub termination_status: TerminationStatus,
+    #[serde(skip_serializing)]
+    rand_random: Pcg64,
+    metadata: Metadata<Self>,
+}
+
+impl GoalConditionedMountainCarEnv {
+    /// Creates a goal-conditioned environment, using the defaults of [`MountainCarEnv`] for the
+    /// movement of the car.
+    pub fn new(render_mode: RenderMode) -> Self {
+        let env = MountainCarEnv::new(render_mode);
+        let BoxR { low, high } = env.observation_space.clone();
+        let observation_space = BoxR::new(
+            GoalConditionedObservation::new(
+                low,
+                low.position.into_inner(),
+                low.position.into_inner(),
+            ),
+            GoalConditionedObservation::new(
+                high,
+                high.position.into_inner(),
+                high.position.into_inner(),
+            ),
+        );
+        let metadata = Metadata::new(MOUNTAIN_CAR_RENDER_MODES, env.metadata.render_fps)
+            .with_max_episode_steps(MOUNTAIN_CAR_EPISODE_LENGTH);
+        let (rand_random, _) = rand_random(None);
+
+        Self {
+            env,
+            desired_goal: GOAL_CONDITIONED_MAX_GOAL,
+            observation_space,
+            termination_status: TerminationStatus::Running,
+            rand_random,
+            metadata,
+        }
+    }
+
+    /// Computes the reward of reaching `achieved_goal` when `desired_goal` was to be reached,
+    /// being `0` once the desired goal is reached and `-1` otherwise.
+    pub fn compute_reward(achieved_goal: f64, desired_goal: f64, _info: ()) -> f64 {
+        if achieved_goal >= desired_goal {
+            0.
+        } else {
+            -1.
+        }
+    }
+
+    fn observe(&self, observation: MountainCarObservation) -> GoalConditionedObservation {
+        GoalConditionedObservation::new(
+            observation,
+            observation.position.into_inner(),
+            self.desired_goal,
+        )
+    }
+}
+
+impl Env for GoalConditionedMountainCarEnv {
+    type Action = MountainCarAction;
+    type Info = ();
+    type ResetInfo = ();
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let action_reward = self.env.step(action);
+        let observation = self.observe(action_reward.observation);
+
+        let reward = Self::compute_reward(observation.achieved_goal, observation.desired_goal, ());
+        let terminated = reward == 0.;
+        let truncated = action_reward.truncated;
+        self.termination_status = TerminationStatus::from_conditions(terminated, truncated);
+
+        ActionReward {
+            observation,
+            reward: OrderedFloat(reward),
+            done: terminated || truncated,
+            truncated,
+            info: None,
+        }
+    }
+
+    /// Draws the state of the car and the desired goal, within the bounds given by `options` if
+    /// any.
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (rand_random, _) = rand_random(seed);
+        self.rand_random = rand_random;
+
+        let state = GoalConditionedObservation::sample_between(&mut self.rand_random, options);
+        let observation = self.reset_to_state(state);
+
+        if return_info {
+            (observation, Some(()))
+        } else {
+            (observation, None)
+        }
+    }
+
+    /// Resets the car to the given state and takes on its desired goal, while the achieved goal
+    /// follows from the position of the car.
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.desired_goal = state.desired_goal;
+        self.termination_status = TerminationStatus::Running;
+
+        let observation = self.env.reset_to_state(state.observation);
+        self.observe(observation)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl EnvironmentId for GoalConditionedMountainCarEnv {
+    const ID: &'static str = "GoalConditionedMountainCar-v0";
+}
+
+impl EnvProperties for GoalConditionedMountainCarEnv {
+    type Observation = GoalConditionedObservation;
+    type ActionSpace = Discrete;
+    type ObservationSpace = BoxR<GoalConditionedObservation>;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: Some(MOUNTAIN_CAR_EPISODE_LENGTH),
+            reward_threshold: None,
+            nondeterministic: false,
+        }
+    }
+
+    fn metadata(&self) -> &Metadata<Self> {
+        &self.metadata
+    }
+
+    fn rand_random(&self) -> &Pcg64 {
+        &self.rand_random
+    }
+
+    fn render_mode(&self) -> &RenderMode {
+        self.env.render_mode()
+    }
+
+    fn episode_length(&self) -> Option<usize> {
+        self.env.episode_length()
+    }
+
+    fn is_terminated(&self) -> bool {
+        self.termination_status == TerminationStatus::Terminated
+    }
+
+    fn is_truncated(&self) -> bool {
+        self.termination_status == TerminationStatus::Truncated
+    }
+
+    fn step_count(&self) -> usize {
+        self.env.step_count()
+    }
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        self.env.action_space()
+    }
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::{
+        GoalConditionedMountainCarEnv, GoalConditionedObservation, MountainCarAction,
+        MountainCarEnv, MountainCarObservation,
+    };
+    use crate::{
+        core::{Env, EnvProperties},
+        utils::renderer::{RenderMode, Renders},
+    };
+
+    /// Pushes the car along its velocity until it reaches the goal, returning the steps taken.
+    fn steps_to_goal(env: &mut MountainCarEnv) -> usize {
+        env.reset_to_state(MountainCarObservation::new(
+            OrderedFloat(-0.5),
+            OrderedFloat(0.),
+        ));
+
+        (1..=1000)
+            .find(|_| {
+                let action = if env.state.velocity >= OrderedFloat(0.) {
+                    MountainCarAction::Forward
+                } else {
+                    MountainCarAction::Backward
+                };
+                env.step(action);
+                env.state.position >= env.goal_position
+            })
+            .expect("The car should reach the goal.")
+    }
+
+    #[test]
+    fn given_higher_gravity_when_pushed_along_velocity_then_goal_is_reached_in_fewer_steps() {
+        let mut default_env = MountainCarEnv::builder().seed(0).build();
+        let mut heavy_env = MountainCarEnv::builder()
+            .gravity(OrderedFloat(0.003))
+            .seed(0)
+            .build();
+
+        assert!(steps_to_goal(&mut heavy_env) < steps_to_goal(&mut default_env));
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_goal_beyond_max_position_when_built_then_panics() {
+        MountainCarEnv::builder()
+            .goal_position(OrderedFloat(1.))
+            .build();
+    }
+
+    #[test]
+    fn given_car_at_rest_when_energy_computed_then_goal_holds_more_energy_than_min_position() {
+        let mut env = MountainCarEnv::new(RenderMode::None);
+        env.reset_to_state(MountainCarObservation::new(
+            env.min_position,
+            OrderedFloat(0.),
+        ));
+
+        assert!(env.goal_energy() > env.energy());
+    }
+
+    #[test]
+    fn given_observations_at_space_bounds_when_validated_then_only_outside_values_are_rejected() {
+        let env = MountainCarEnv::new(RenderMode::None);
+
+        assert!(MountainCarObservation::new(env.min_position, -env.max_speed).is_valid(&env));
+        assert!(MountainCarObservation::new(env.max_position, env.max_speed).is_valid(&env));
+        assert!(!MountainCarObservation::new(
+            env.max_position + OrderedFloat(1e-9),
+            OrderedFloat(0.)
+        )
+        .is_valid(&env));
+        assert!(!MountainCarObservation::new(
+            OrderedFloat(0.),
+            -env.max_speed - OrderedFloat(1e-9)
+        )
+        .is_valid(&env));
+    }
+
+    #[test]
+    #[cfg(not(feature = "sdl2"))]
+    fn given_loaded_gui_when_cloned_detached_then_clone_screen_is_closed() {
+        let mut env = MountainCarEnv::new(RenderMode::RgbArray);
+        env.render(RenderMode::RgbArray);
+        assert!(env.screen.is_open());
+
+        let clone = env.clone_detached();
+
+        assert!(!clone.screen.is_open());
+        assert!(env.screen.is_open());
+    }
+
+    #[test]
+    #[cfg(all(feature = "gif", not(feature = "sdl2")))]
+    fn given_five_rendered_frames_when_exported_then_gif_file_is_written() {
+        let path = std::env::temp_dir().join("gym_rs_mountain_car_export.gif");
+        let mut env = MountainCarEnv::new(RenderMode::RgbArray);
+        env.reset(Some(0), false, None);
+        for _ in 0..4 {
+            env.step(MountainCarAction::Forward);
+        }
+        assert_eq!(env.renderer.frame_count(), 5);
+
+        env.renderer.export_gif(&path, 5).unwrap();
+        let size = std::fs::metadata(&path).unwrap().len();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(size > 0);
+    }
+
+    #[test]
+    fn given_known_position_when_rendered_as_ansi_then_car_is_drawn_at_matching_column() {
+        let mut env = MountainCarEnv::new(RenderMode::Ansi);
+        env.reset_to_state(MountainCarObservation::new(
+            OrderedFloat(-0.5),
+            OrderedFloat(0.),
+        ));
+
+        let rows = match env.render(RenderMode::Ansi) {
+            Renders::Ansi(rows) => rows,
+            other => panic!("Expected rows of text, received {:?}.", other),
+        };
+
+        // -0.5 lies 0.7 / 1.8 of the way along the 80 columns spanning [-1.2, 0.6].
+        let car_columns: Vec<_> = rows.iter().filter_map(|row| row.find('C')).collect();
+        assert_eq!(car_columns, vec![31]);
+        let flag_columns: Vec<_> = rows.iter().filter_map(|row| row.find('F')).collect();
+        assert_eq!(flag_columns, vec![75]);
+    }
+
+    #[test]
+    fn given_car_stuck_at_min_position_when_stepping_200_times_then_last_step_is_truncated() {
+        let mut env = MountainCarEnv::new(RenderMode::None);
+        env.reset(Some(0), false, None);
+        env.state = MountainCarObservation::new(env.min_position, OrderedFloat(0.));
+
+        for _ in 1..200 {
+            let action_reward = env.step(MountainCarAction::Backward);
+            assert!(!action_reward.done && !action_reward.truncated);
+        }
+        let action_reward = env.step(MountainCarAction::Backward);
+
+        assert!(action_reward.done);
+        assert!(action_reward.truncated);
+        assert!(env.is_truncated() && !env.is_terminated());
+
+        env.reset(Some(0), false, None);
+        assert_eq!(env.step_count, 0);
+        assert!(!env.is_truncated());
+    }
+
+    #[test]
+    fn given_desired_goal_when_reached_then_episode_terminates_without_penalty() {
+        let mut env = GoalConditionedMountainCarEnv::new(RenderMode::None);
+        let (observation, _) = env.reset(Some(0), false, None);
+        assert!((-0.4..0.5).contains(&observation.desired_goal));
+        assert_eq!(Vec::<f64>::from(observation).len(), 4);
+
+        let start = MountainCarObservation::new(OrderedFloat(-0.5), OrderedFloat(0.));
+        env.reset_to_state(GoalConditionedObservation::new(start, -0.5, -0.45));
+        let mut action_reward = env.step(MountainCarAction::Forward);
+        while !action_reward.done {
+            assert_eq!(action_reward.reward, OrderedFloat(-1.));
+            action_reward = env.step(MountainCarAction::Forward);
+        }
+
+        let observation = action_reward.observation;
+        assert_eq!(action_reward.reward, OrderedFloat(0.));
+        assert!(env.is_terminated());
+        assert_eq!(
+            observation.achieved_goal,
+            observation.observation.position.into_inner()
+        );
+        assert_eq!(
+            GoalConditionedMountainCarEnv::compute_reward(observation.achieved_goal, 0.5, ()),
+            -1.
+        );
+    }
+}
@@ -0,0 +1,578 @@
+use std::f64::consts::PI;
+
+use derivative::Derivative;
+use derive_new::new;
+use ordered_float::{OrderedFloat, UniformOrdered};
+use rand::{
+    distributions::{
+        uniform::{SampleUniform, UniformSampler},
+        Uniform,
+    },
+    prelude::Distribution,
+    Rng,
+};
+use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId, TerminationStatus},
+    spaces::{BoundedObservation, BoxR},
+    utils::{
+        custom::{
+            canvas::{Color, DrawRenderer},
+            screen::{Screen, ScreenGuiTransformations},
+            structs::Metadata,
+            traits::Sample,
+            types::O64,
+            util_fns::clip,
+        },
+        renderer::{RenderMode, Renderer, Renders},
+        seeding::rand_random,
+    },
+};
+
+/// The torque applied onto the free end of the pendulum.
+pub type PendulumAction = f64;
+
+/// The number of steps after which an episode is truncated.
+const PENDULUM_EPISODE_LENGTH: usize = 200;
+
+/// An implementation of the inverted pendulum swing-up problem.
+///
+/// The system consists of a pendulum attached at one end to a fixed point, and the other end being
+/// free. The pendulum starts in a random position and the goal is to apply torque on the free end
+/// to swing it into an upright position, with its center of gravity right above the fixed point.
+/// The agent is rewarded with the negated cost `theta^2 + 0.1 * theta_dot^2 + 0.001 * torque^2`,
+/// where `theta` is normalized between `[-PI, PI)` with `0` being the upright position.
+///
+/// The episode ends when any of the following conditions occur:
+///
+/// 1. Truncation: The episode reaches 200 steps.
+#[derive(Serialize, Derivative, Clone)]
+#[derivative(Debug)]
+pub struct PendulumEnv {
+    /// The range of torques that can be applied.
+    pub action_space: BoxR<PendulumAction>,
+    /// The range of values that can be observed.
+    pub observation_space: BoxR<PendulumObservation>,
+    /// The type of renders produced.
+    pub render_mode: RenderMode,
+    /// The current state of the environment.
+    pub state: PendulumObservation,
+    /// Additional pieces of information provided by the environment.
+    pub metadata: Metadata<Self>,
+    /// The largest angular velocity the pendulum can reach.
+    pub max_speed: O64,
+    /// The largest torque which can be applied onto the pendulum.
+    pub max_torque: O64,
+    /// The number of seconds between state updates.
+    pub dt: O64,
+    /// The gravity constant applied to the environment.
+    pub gravity: O64,
+    /// The mass of the pendulum.
+    pub mass: O64,
+    /// The length of the pendulum.
+    pub length: O64,
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+    /// The reason the last episode ended, if it did.
+    pub termination_status: TerminationStatus,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    rand_random: Pcg64,
+    screen: Screen,
+    renderer: Renderer,
+}
+
+const PENDULUM_RENDER_MODES: &[RenderMode] = &[
+    RenderMode::Human,
+    RenderMode::RgbArray,
+    RenderMode::SingleRgbArray,
+    RenderMode::None,
+];
+
+impl Default for Metadata<PendulumEnv> {
+    fn default() -> Self {
+        Metadata::new(PENDULUM_RENDER_MODES, 30).with_max_episode_steps(PENDULUM_EPISODE_LENGTH)
+    }
+}
+
+impl PendulumEnv {
+    /// Draws a random action from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> PendulumAction {
+        self.action_space.sample(&mut self.rand_random)
+    }
+
+    /// Creates a pendulum environment using the defaults found in OpenAI's implementation.
+    pub fn new(render_mode: RenderMode) -> Self {
+        let (mut rand_random, _) = rand_random(None);
+
+        let max_speed = OrderedFloat(8.);
+        let max_torque = OrderedFloat(2.);
+        let dt = OrderedFloat(0.05);
+        let gravity = OrderedFloat(10.);
+        let mass = OrderedFloat(1.);
+        let length = OrderedFloat(1.);
+
+        let high = PendulumObservation::new(OrderedFloat(1.), OrderedFloat(1.), max_speed);
+
+        let action_space = BoxR::new(-max_torque.into_inner(), max_torque.into_inner());
+        let observation_space = BoxR::new(-high, high);
+
+        let renderer = Renderer::new(render_mode, None, None);
+
+        let metadata = Metadata::default();
+        let screen = Screen::new(500, 500, "Pendulum", metadata.render_fps, render_mode);
+
+        let state = PendulumObservation::sample_between(&mut rand_random, None);
+
+        Self {
+            action_space,
+            observation_space,
+            render_mode,
+            state,
+            metadata,
+            max_speed,
+            max_torque,
+            dt,
+            gravity,
+            mass,
+            length,
+            step_count: 0,
+            termination_status: TerminationStatus::Running,
+            rand_random,
+            screen,
+            renderer,
+        }
+    }
+
+    /// Normalizes an angle into the range `[-PI, PI)`.
+    fn angle_normalize(angle: f64) -> f64 {
+        (angle + PI).rem_euclid(2. * PI) - PI
+    }
+
+    fn render(
+        mode: RenderMode,
+        screen: &mut Screen,
+        metadata: &Metadata<Self>,
+        length: O64,
+        state: PendulumObservation,
+    ) -> Renders {
+        assert!(metadata.render_modes.contains(&mode));
+
+        screen.load_gui();
+        screen.consume_events();
+
+        let screen_width = screen.screen_width();
+        let bound = length.into_inner() + 1.2;
+        let scale = screen_width as f64 / (bound * 2.);
+        let offset = screen_width as f64 / 2.;
+
+        let theta = state.theta();
+        let [center, tip] = [
+            (0., 0.),
+            (
+                -length.into_inner() * theta.sin(),
+                length.into_inner() * theta.cos(),
+            ),
+        ]
+        .map(|(x, y)| {
+            (
+                (x * scale + offset).floor() as i16,
+                (y * scale + offset).floor() as i16,
+            )
+        });
+        let rod_width = (0.2 * scale).floor() as u8;
+        let axle_radius = (0.05 * scale).floor() as i16;
+
+        screen.draw_on_canvas(
+            |canvas| {
+                canvas.set_draw_color(Color::WHITE);
+                canvas.clear();
+
+                canvas
+                    .thick_line(
+                        center.0,
+                        center.1,
+                        tip.0,
+                        tip.1,
+                        rod_width,
+                        Color::RGB(204, 77, 77),
+                    )
+                    .unwrap();
+
+                canvas
+                    .aa_circle(center.0, center.1, axle_radius, Color::BLACK)
+                    .unwrap();
+                canvas
+                    .filled_circle(center.0, center.1, axle_radius, Color::BLACK)
+                    .unwrap();
+            },
+            ScreenGuiTransformations::default(),
+        );
+
+        screen.render(mode)
+    }
+}
+
+/// Defines the state found in the pendulum environment.
+///
+/// The angle of the pendulum is described through its cosine and sine, where an angle of `0`
+/// means the pendulum is upright.
+#[derive(new, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PendulumObservation {
+    /// The cosine of the angle of the pendulum.
+    pub cos_theta: O64,
+    /// The sine of the angle of the pendulum.
+    pub sin_theta: O64,
+    /// The angular velocity of the pendulum.
+    pub theta_dot: O64,
+}
+
+impl PendulumObservation {
+    /// Creates an observation from the angle and angular velocity of the pendulum.
+    pub fn from_angle(theta: f64, theta_dot: f64) -> Self {
+        Self {
+            cos_theta: OrderedFloat(theta.cos()),
+            sin_theta: OrderedFloat(theta.sin()),
+            theta_dot: OrderedFloat(theta_dot),
+        }
+    }
+
+    /// The angle of the pendulum.
+    pub fn theta(&self) -> f64 {
+        self.sin_theta
+            .into_inner()
+            .atan2(self.cos_theta.into_inner())
+    }
+}
+
+impl std::ops::Neg for PendulumObservation {
+    type Output = PendulumObservation;
+
+    fn neg(self) -> Self::Output {
+        PendulumObservation {
+            cos_theta: -self.cos_theta,
+            sin_theta: -self.sin_theta,
+            theta_dot: -self.theta_dot,
+        }
+    }
+}
+
+/// The sampler responsible for generating an observation using uniform probability.
+///
+/// The angle is sampled between the angles described by the bounds, ensuring that its cosine and
+/// sine remain consistent with each other.
+pub struct UniformPendulumObservation {
+    theta_sampler: UniformOrdered<f64>,
+    theta_dot_sampler: UniformOrdered<f64>,
+}
+
+impl SampleUniform for PendulumObservation {
+    type Sampler = UniformPendulumObservation;
+}
+
+impl UniformSampler for UniformPendulumObservation {
+    type X = PendulumObservation;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+        B2: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformPendulumObservation {
+            theta_sampler: UniformOrdered::new(
+                OrderedFloat(low.theta()),
+                OrderedFloat(high.theta()),
+            ),
+            theta_dot_sampler: UniformOrdered::new(low.theta_dot, high.theta_dot),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+        B2: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformPendulumObservation {
+            theta_sampler: UniformOrdered::new_inclusive(
+                OrderedFloat(low.theta()),
+                OrderedFloat(high.theta()),
+            ),
+            theta_dot_sampler: UniformOrdered::new_inclusive(low.theta_dot, high.theta_dot),
+        }
+    }
+
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        PendulumObservation::from_angle(
+            self.theta_sampler.sample(rng).into_inner(),
+            self.theta_dot_sampler.sample(rng).into_inner(),
+        )
+    }
+}
+
+impl Sample for PendulumObservation {
+    fn sample_between<R: Rng>(rng: &mut R, bounds: Option<BoxR<Self>>) -> Self {
+        let BoxR { low, high } = bounds.unwrap_or({
+            BoxR::new(
+                PendulumObservation::from_angle(-PI, -1.),
+                PendulumObservation::from_angle(PI, 1.),
+            )
+        });
+
+        Uniform::new(low, high).sample(rng)
+    }
+}
+
+impl From<PendulumObservation> for Vec<f64> {
+    fn from(observation: PendulumObservation) -> Self {
+        vec![
+            observation.cos_theta.into_inner(),
+            observation.sin_theta.into_inner(),
+            observation.theta_dot.into_inner(),
+        ]
+    }
+}
+
+impl BoundedObservation for PendulumObservation {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        self.cos_theta.is_within(&low.cos_theta, &high.cos_theta)
+            && self.sin_theta.is_within(&low.sin_theta, &high.sin_theta)
+            && self.theta_dot.is_within(&low.theta_dot, &high.theta_dot)
+    }
+}
+
+impl Env for PendulumEnv {
+    type Action = PendulumAction;
+    type Info = ();
+    type ResetInfo = ();
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let torque = clip(action, self.action_space.low, self.action_space.high);
+
+        let gravity = self.gravity.into_inner();
+        let mass = self.mass.into_inner();
+        let length = self.length.into_inner();
+        let dt = self.dt.into_inner();
+        let max_speed = self.max_speed.into_inner();
+
+        let theta = self.state.theta();
+        let theta_dot = self.state.theta_dot.into_inner();
+
+        let cost =
+            Self::angle_normalize(theta).powi(2) + 0.1 * theta_dot.powi(2) + 0.001 * torque.powi(2);
+
+        let new_theta_dot = theta_dot
+            + (3. * gravity / (2. * length) * theta.sin() + 3. / (mass * length.powi(2)) * torque)
+                * dt;
+        let new_theta_dot = clip(new_theta_dot, -max_speed, max_speed);
+        let new_theta = theta + new_theta_dot * dt;
+
+        self.state = PendulumObservation::from_angle(new_theta, new_theta_dot);
+
+        self.step_count += 1;
+
+        let truncated = self
+            .episode_length()
+            .is_some_and(|episode_length| self.step_count >= episode_length);
+        self.termination_status = TerminationStatus::from_conditions(false, truncated);
+
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+        let length = self.length;
+        let state = self.state;
+
+        self.renderer
+            .render_step(&mut |mode| Self::render(mode, screen, metadata, length, state));
+
+        ActionReward {
+            observation: self.state,
+            reward: OrderedFloat(-cost),
+            done: truncated,
+            truncated,
+            info: None,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (rand_random, _) = rand_random(seed);
+        self.rand_random = rand_random;
+        self.step_count = 0;
+        self.termination_status = TerminationStatus::Running;
+
+        self.state = PendulumObservation::sample_between(&mut self.rand_random, options);
+
+        self.renderer.reset();
+
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+        let length = self.length;
+        let state = self.state;
+
+        self.renderer
+            .render_step(&mut |mode| Self::render(mode, screen, metadata, length, state));
+
+        if return_info {
+            (self.state, Some(()))
+        } else {
+            (self.state, None)
+        }
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.step_count = 0;
+        self.termination_status = TerminationStatus::Running;
+        self.state = state;
+
+        self.renderer.reset();
+
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+        let length = self.length;
+        let state = self.state;
+
+        self.renderer
+            .render_step(&mut |mode| Self::render(mode, screen, metadata, length, state));
+
+        self.state
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        let screen = &mut self.screen;
+        let metadata = &self.metadata;
+        let length = self.length;
+        let state = self.state;
+
+        let render_fn = &mut |mode| Self::render(mode, screen, metadata, length, state);
+        if self.render_mode != RenderMode::None {
+            self.renderer.get_renders(render_fn)
+        } else {
+            render_fn(mode)
+        }
+    }
+
+    fn close(&mut self) {
+        self.screen.close();
+    }
+}
+
+impl EnvironmentId for PendulumEnv {
+    const ID: &'static str = "Pendulum-v1";
+}
+
+impl EnvProperties for PendulumEnv {
+    type Observation = PendulumObservation;
+    type ActionSpace = BoxR<PendulumAction>;
+    type ObservationSpace = BoxR<PendulumObservation>;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: Some(PENDULUM_EPISODE_LENGTH),
+            reward_threshold: None,
+            nondeterministic: false,
+        }
+    }
+
+    fn metadata(&self) -> &Metadata<Self> {
+        &self.metadata
+    }
+
+    fn rand_random(&self) -> &Pcg64 {
+        &self.rand_random
+    }
+
+    fn episode_length(&self) -> Option<usize> {
+        Some(PENDULUM_EPISODE_LENGTH)
+    }
+
+    fn is_truncated(&self) -> bool {
+        self.termination_status == TerminationStatus::Truncated
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        &self.action_space
+    }
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::{PendulumEnv, PendulumObservation};
+    use crate::{
+        core::{Env, EnvProperties},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_torque_beyond_max_torque_when_stepping_then_max_torque_is_applied() {
+        let mut env = PendulumEnv::new(RenderMode::None);
+        let upright = PendulumObservation::from_angle(0., 0.);
+
+        env.reset_to_state(upright);
+        let clipped = env.step(5.);
+        env.reset_to_state(upright);
+        let max = env.step(2.);
+
+        assert_eq!(clipped, max);
+        // Upright and at rest, only the torque accelerates the pendulum, by 3 * 2 * 0.05.
+        assert!((max.observation.theta_dot.into_inner() - 0.3).abs() < 1e-12);
+        assert!((max.reward.into_inner() + 0.001 * 4.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn given_pendulum_hanging_down_when_stepping_then_reward_is_negated_cost_of_prior_state() {
+        let mut env = PendulumEnv::new(RenderMode::None);
+        env.reset_to_state(PendulumObservation::from_angle(PI, 1.));
+
+        let action_reward = env.step(1.);
+
+        let cost = PI.powi(2) + 0.1 + 0.001;
+        assert!((action_reward.reward.into_inner() + cost).abs() < 1e-12);
+        assert!(!action_reward.done);
+    }
+
+    #[test]
+    fn given_angles_beyond_half_turn_when_normalized_then_equivalent_angle_within_pi_is_returned() {
+        assert!((PendulumEnv::angle_normalize(1.5 * PI) + 0.5 * PI).abs() < 1e-12);
+        assert!((PendulumEnv::angle_normalize(-1.5 * PI) - 0.5 * PI).abs() < 1e-12);
+        assert!((PendulumEnv::angle_normalize(4. * PI + 0.25) - 0.25).abs() < 1e-12);
+        assert_eq!(PendulumEnv::angle_normalize(PI), -PI);
+    }
+
+    #[test]
+    fn given_pendulum_when_stepping_200_times_then_last_step_is_truncated() {
+        let mut env = PendulumEnv::new(RenderMode::None);
+        env.reset(Some(0), false, None);
+
+        for _ in 1..200 {
+            let action_reward = env.step(0.);
+            assert!(!action_reward.done && !action_reward.truncated);
+        }
+        let action_reward = env.step(0.);
+
+        assert!(action_reward.done);
+        assert!(action_reward.truncated);
+        assert!(env.is_truncated() && !env.is_terminated());
+
+        env.reset(Some(0), false, None);
+        assert_eq!(env.step_count, 0);
+        assert!(!env.is_truncated());
+    }
+}
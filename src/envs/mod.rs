@@ -1,2 +1,13 @@
+/// Holds examples of stateless bandit environments.
+pub mod bandit;
 /// Holds examples of control environments.
 pub mod classical_control;
+/// Holds structures which step through several environments concurrently.
+#[cfg(feature = "parallel")]
+pub mod parallel_vec_env;
+/// Holds examples of tabular, text-based environments.
+pub mod toy_text;
+/// Holds structures which step through several environments at once.
+pub mod vec_env;
+/// Holds wrappers which alter the behaviour of existing environments.
+pub mod wrappers;
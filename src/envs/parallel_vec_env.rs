@@ -0,0 +1,106 @@
+use rayon::prelude::*;
+
+use crate::core::{ActionReward, Env};
+
+/// Steps through several instances of an environment concurrently, exposing the same batched API
+/// as [`SyncVectorEnv`](crate::envs::vec_env::SyncVectorEnv).
+///
+/// The environments must be `Send`. Environments drawing through SDL2 hold a non-`Send` screen, so
+/// they can only be batched here when the `sdl2` feature is disabled.
+#[derive(Debug, Clone)]
+pub struct ParallelVecEnv<E> {
+    /// The environments being stepped through.
+    pub envs: Vec<E>,
+}
+
+impl<E: Env + Send> ParallelVecEnv<E>
+where
+    E::Action: Send,
+    E::Observation: Send,
+    E::Info: Send,
+{
+    /// Batches the given environments.
+    pub fn new(envs: Vec<E>) -> Self {
+        Self { envs }
+    }
+
+    /// Batches `n` environments, each created by calling `f` with its index.
+    pub fn from_fn<F: Fn(usize) -> E>(n: usize, f: F) -> Self {
+        Self::new((0..n).map(f).collect())
+    }
+
+    /// The number of environments in the batch.
+    pub fn num_envs(&self) -> usize {
+        self.envs.len()
+    }
+
+    /// Applies each action to the environment with the same index, stepping the environments in
+    /// parallel.
+    ///
+    /// Panics if the number of actions differs from the number of environments.
+    pub fn step_all(
+        &mut self,
+        actions: Vec<E::Action>,
+    ) -> Vec<ActionReward<E::Observation, E::Info>> {
+        assert_eq!(
+            actions.len(),
+            self.num_envs(),
+            "Expected one action per environment."
+        );
+
+        self.envs
+            .par_iter_mut()
+            .zip(actions.into_par_iter())
+            .map(|(env, action)| env.step(action))
+            .collect()
+    }
+
+    /// Resets every environment in parallel, where the environment at index `i` is seeded with
+    /// `seed + i`, wrapping around at `u64::MAX`.
+    pub fn reset_all(&mut self, seed: Option<u64>) -> Vec<E::Observation> {
+        self.envs
+            .par_iter_mut()
+            .enumerate()
+            .map(|(i, env)| {
+                env.reset(seed.map(|seed| seed.wrapping_add(i as u64)), false, None)
+                    .0
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParallelVecEnv;
+    use crate::{
+        envs::{
+            classical_control::dubins_car::{DubinsCarAction, DubinsCarEnv},
+            vec_env::SyncVectorEnv,
+        },
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_deterministic_environments_when_stepped_then_results_match_sequential_execution() {
+        let mut parallel = ParallelVecEnv::from_fn(8, |_| DubinsCarEnv::new(RenderMode::None));
+        let mut sequential = SyncVectorEnv::from_fn(8, |_| DubinsCarEnv::new(RenderMode::None));
+
+        assert_eq!(parallel.reset_all(Some(7)), sequential.reset_all(Some(7)));
+        for step in 0..20 {
+            let actions: Vec<_> = (0..8_usize)
+                .map(|i| {
+                    if (i + step).is_multiple_of(2) {
+                        DubinsCarAction::TurnLeft
+                    } else {
+                        DubinsCarAction::TurnRight
+                    }
+                })
+                .collect();
+
+            assert_eq!(
+                parallel.step_all(actions.clone()),
+                sequential.step_all(actions)
+            );
+        }
+    }
+}
@@ -0,0 +1,592 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use derivative::Derivative;
+use ordered_float::OrderedFloat;
+use rand::{
+    distributions::{
+        uniform::{SampleBorrow, SampleUniform, UniformSampler},
+        Uniform,
+    },
+    prelude::Distribution,
+    Rng,
+};
+use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId, RewardRange},
+    spaces::{BoundedObservation, BoxR, Discrete, InvalidDiscreteValue, Space},
+    utils::{
+        custom::{structs::Metadata, traits::Sample, types::O64},
+        renderer::{RenderMode, Renders},
+        seeding::rand_random,
+    },
+};
+
+/// The values of the cards in a deck, where an ace counts as `1` and face cards as `10`.
+const BLACKJACK_DECK: [u8; 13] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 10, 10];
+
+/// The sum the dealer draws cards until reaching.
+const BLACKJACK_DEALER_THRESHOLD: u8 = 17;
+
+const BLACKJACK_RENDER_MODES: &[RenderMode] = &[RenderMode::Ansi, RenderMode::None];
+
+impl Default for Metadata<BlackjackEnv> {
+    fn default() -> Self {
+        Metadata::new(BLACKJACK_RENDER_MODES, 4)
+    }
+}
+
+/// Draws a card from an infinite deck.
+fn draw_card<R: Rng + ?Sized>(rng: &mut R) -> u8 {
+    BLACKJACK_DECK[rng.gen_range(0..BLACKJACK_DECK.len())]
+}
+
+/// Checks whether the hand holds an ace which can count as `11` without busting.
+fn usable_ace(hand: &[u8]) -> bool {
+    hand.contains(&1) && hand.iter().sum::<u8>() + 10 <= 21
+}
+
+/// Computes the value of the hand, counting a usable ace as `11`.
+fn sum_hand(hand: &[u8]) -> u8 {
+    hand.iter().sum::<u8>() + if usable_ace(hand) { 10 } else { 0 }
+}
+
+/// Computes the score of the hand, being `0` when it is bust.
+fn score(hand: &[u8]) -> u8 {
+    match sum_hand(hand) {
+        sum if sum > 21 => 0,
+        sum => sum,
+    }
+}
+
+/// Checks whether the hand is a natural blackjack, being an ace and a ten-valued card.
+fn is_natural(hand: &[u8]) -> bool {
+    hand.len() == 2 && sum_hand(hand) == 21
+}
+
+/// Checks whether a hand adding up to the given sum can hold a usable ace, which counts as `11`
+/// and therefore requires a sum between `12` and `21`.
+fn can_hold_usable_ace(sum: u8) -> bool {
+    (12..=21).contains(&sum)
+}
+
+/// Builds a hand of cards which adds up to the given sum and holds a usable ace if requested.
+///
+/// Panics if a usable ace is requested for a sum no such hand can add up to.
+fn hand_for(sum: u8, usable_ace: bool) -> Vec<u8> {
+    assert!(
+        !usable_ace || can_hold_usable_ace(sum),
+        "A hand holding a usable ace must add up to between 12 and 21, got {}.",
+        sum
+    );
+
+    let (mut hand, mut rest) = if usable_ace {
+        (vec![1], sum.saturating_sub(11))
+    } else {
+        (vec![], sum)
+    };
+
+    while rest > 11 {
+        hand.push(10);
+        rest -= 10;
+    }
+    match rest {
+        // A single card worth 1 or 11 would be an ace, which could become usable.
+        11 => hand.extend([9, 2]),
+        0 => {}
+        rest => hand.push(rest),
+    }
+
+    hand
+}
+
+/// The actions the player can take.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BlackjackAction {
+    /// Ends the player's turn, letting the dealer play.
+    Stick,
+    /// Draws another card.
+    Hit,
+}
+
+impl From<BlackjackAction> for usize {
+    fn from(action: BlackjackAction) -> Self {
+        match action {
+            BlackjackAction::Stick => 0,
+            BlackjackAction::Hit => 1,
+        }
+    }
+}
+
+impl TryFrom<usize> for BlackjackAction {
+    type Error = InvalidDiscreteValue;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BlackjackAction::Stick),
+            1 => Ok(BlackjackAction::Hit),
+            _ => Err(InvalidDiscreteValue { value, n: 2 }),
+        }
+    }
+}
+
+impl Space<BlackjackAction> for Discrete {
+    fn contains(&self, value: BlackjackAction) -> bool {
+        self.contains(usize::from(value))
+    }
+}
+
+impl Display for BlackjackAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlackjackAction::Stick => write!(f, "Stick"),
+            BlackjackAction::Hit => write!(f, "Hit"),
+        }
+    }
+}
+
+/// Utility structure intended to reduce confusion around meaning of properties.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BlackjackObservation {
+    /// The value of the player's hand.
+    pub player_sum: u8,
+    /// The value of the card the dealer shows, where an ace counts as `1`.
+    pub dealer_showing: u8,
+    /// Whether the player holds an ace which counts as `11`.
+    pub usable_ace: bool,
+}
+
+/// The structure reponsible for uniformly sampling a blackjack observation.
+pub struct UniformBlackjackObservation {
+    /// The sampler responsible for deriving the value of the player's hand.
+    pub player_sum_sampler: Uniform<u8>,
+    /// The sampler responsible for deriving the value of the dealer's card.
+    pub dealer_showing_sampler: Uniform<u8>,
+    /// The values a usable ace can take.
+    pub usable_ace_bounds: (bool, bool),
+}
+
+impl UniformSampler for UniformBlackjackObservation {
+    type X = BlackjackObservation;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformBlackjackObservation {
+            player_sum_sampler: Uniform::new(low.player_sum, high.player_sum),
+            dealer_showing_sampler: Uniform::new(low.dealer_showing, high.dealer_showing),
+            usable_ace_bounds: (low.usable_ace, high.usable_ace),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformBlackjackObservation {
+            player_sum_sampler: Uniform::new_inclusive(low.player_sum, high.player_sum),
+            dealer_showing_sampler: Uniform::new_inclusive(low.dealer_showing, high.dealer_showing),
+            usable_ace_bounds: (low.usable_ace, high.usable_ace),
+        }
+    }
+
+    /// Draws an observation, only holding a usable ace when the bounds require one or when the
+    /// drawn sum allows for one.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        let (low, high) = self.usable_ace_bounds;
+        let player_sum = self.player_sum_sampler.sample(rng);
+        BlackjackObservation {
+            player_sum,
+            dealer_showing: self.dealer_showing_sampler.sample(rng),
+            usable_ace: if low == high {
+                low
+            } else {
+                can_hold_usable_ace(player_sum) && rng.gen()
+            },
+        }
+    }
+}
+
+impl SampleUniform for BlackjackObservation {
+    type Sampler = UniformBlackjackObservation;
+}
+
+impl Sample for BlackjackObservation {
+    /// Draws an observation uniformly between the given inclusive bounds, or deals a fresh round
+    /// when no bounds are given.
+    fn sample_between<R: Rng>(rng: &mut R, bounds: Option<BoxR<Self>>) -> Self {
+        match bounds {
+            Some(BoxR { low, high }) => Uniform::new_inclusive(low, high).sample(rng),
+            None => {
+                let player = [draw_card(rng), draw_card(rng)];
+                BlackjackObservation {
+                    player_sum: sum_hand(&player),
+                    dealer_showing: draw_card(rng),
+                    usable_ace: usable_ace(&player),
+                }
+            }
+        }
+    }
+}
+
+impl BoundedObservation for BlackjackObservation {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        (low.player_sum..=high.player_sum).contains(&self.player_sum)
+            && (low.dealer_showing..=high.dealer_showing).contains(&self.dealer_showing)
+            && (low.usable_ace..=high.usable_ace).contains(&self.usable_ace)
+    }
+}
+
+impl From<BlackjackObservation> for Vec<f64> {
+    fn from(o: BlackjackObservation) -> Self {
+        vec![
+            o.player_sum as f64,
+            o.dealer_showing as f64,
+            if o.usable_ace { 1. } else { 0. },
+        ]
+    }
+}
+
+/// An implementation of the card game blackjack, as described in Sutton and Barto's book.
+///
+/// The player draws cards from an infinite deck, aiming for a hand closer to 21 than the dealer's
+/// without exceeding it. Once the player sticks, the dealer reveals their hidden card and draws
+/// until their hand is worth at least 17. Winning is rewarded with `1`, drawing with `0` and
+/// losing with `-1`. When `natural` is set, winning with a natural blackjack is rewarded with
+/// `1.5` instead.
+///
+/// An episode ends when one of the following conditions occur:
+///     1. Termination: The player sticks or their hand exceeds 21.
+#[derive(Serialize, Derivative, Clone)]
+#[derivative(Debug)]
+pub struct BlackjackEnv {
+    /// Whether a natural blackjack is rewarded with `1.5`.
+    pub natural: bool,
+
+    /// The cards held by the player.
+    pub player: Vec<u8>,
+    /// The cards held by the dealer, the first of which is shown to the player.
+    pub dealer: Vec<u8>,
+
+    /// The type of renders produced.
+    pub render_mode: RenderMode,
+
+    /// The set of actions which can be taken.
+    pub action_space: Discrete,
+    /// The range of values that can be observed.
+    pub observation_space: BoxR<BlackjackObservation>,
+
+    /// Additional information provided by the environment.
+    pub metadata: Metadata<Self>,
+
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+
+    reward_range: RewardRange,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    rand_random: Pcg64,
+}
+
+impl BlackjackEnv {
+    /// Generates an instance of the blackjack environment, dealing the first round.
+    pub fn new(natural: bool, render_mode: RenderMode) -> Self {
+        let (rand_random, _) = rand_random(None);
+
+        let low = BlackjackObservation {
+            player_sum: 4,
+            dealer_showing: 1,
+            usable_ace: false,
+        };
+        let high = BlackjackObservation {
+            player_sum: 31,
+            dealer_showing: 10,
+            usable_ace: true,
+        };
+
+        let mut env = Self {
+            natural,
+            player: vec![],
+            dealer: vec![],
+            render_mode,
+            action_space: Discrete(2),
+            observation_space: BoxR::new(low, high),
+            metadata: Metadata::default(),
+            step_count: 0,
+            reward_range: RewardRange::new(OrderedFloat(-1.), OrderedFloat(1.5)),
+            rand_random,
+        };
+        env.deal(None);
+        env
+    }
+
+    /// Provides what the player can observe of the round.
+    pub fn observation(&self) -> BlackjackObservation {
+        BlackjackObservation {
+            player_sum: sum_hand(&self.player),
+            dealer_showing: self.dealer[0],
+            usable_ace: usable_ace(&self.player),
+        }
+    }
+
+    /// Deals the hands of a new round, matching the given observation if any.
+    fn deal(&mut self, observation: Option<BlackjackObservation>) {
+        match observation {
+            Some(observation) => {
+                self.player = hand_for(observation.player_sum, observation.usable_ace);
+                self.dealer = vec![observation.dealer_showing, draw_card(&mut self.rand_random)];
+            }
+            None => {
+                self.player = vec![
+                    draw_card(&mut self.rand_random),
+                    draw_card(&mut self.rand_random),
+                ];
+                self.dealer = vec![
+                    draw_card(&mut self.rand_random),
+                    draw_card(&mut self.rand_random),
+                ];
+            }
+        }
+    }
+
+    /// Draws a random action from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> BlackjackAction {
+        BlackjackAction::try_from(self.action_space.sample(&mut self.rand_random))
+            .expect("The action space only holds valid actions.")
+    }
+}
+
+impl Env for BlackjackEnv {
+    type Action = BlackjackAction;
+    type Info = ();
+    type ResetInfo = ();
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let (reward, done): (O64, bool) = match action {
+            BlackjackAction::Hit => {
+                self.player.push(draw_card(&mut self.rand_random));
+                if score(&self.player) == 0 {
+                    (OrderedFloat(-1.), true)
+                } else {
+                    (OrderedFloat(0.), false)
+                }
+            }
+            BlackjackAction::Stick => {
+                while sum_hand(&self.dealer) < BLACKJACK_DEALER_THRESHOLD {
+                    self.dealer.push(draw_card(&mut self.rand_random));
+                }
+
+                let reward = match score(&self.player).cmp(&score(&self.dealer)) {
+                    Ordering::Greater if self.natural && is_natural(&self.player) => 1.5,
+                    Ordering::Greater => 1.,
+                    Ordering::Equal => 0.,
+                    Ordering::Less => -1.,
+                };
+                (OrderedFloat(reward), true)
+            }
+        };
+
+        self.step_count += 1;
+
+        ActionReward {
+            observation: self.observation(),
+            reward,
+            done,
+            truncated: false,
+            info: None,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (rand_random, _) = rand_random(seed);
+        self.rand_random = rand_random;
+        self.step_count = 0;
+
+        let observation = options.map(|bounds| {
+            BlackjackObservation::sample_between(&mut self.rand_random, Some(bounds))
+        });
+        self.deal(observation);
+
+        if return_info {
+            (self.observation(), Some(()))
+        } else {
+            (self.observation(), None)
+        }
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.step_count = 0;
+        self.deal(Some(state));
+        self.observation()
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        assert!(self.metadata.render_modes.contains(&mode));
+
+        match mode {
+            RenderMode::Ansi => {
+                let observation = self.observation();
+                let ace = if observation.usable_ace {
+                    " (usable ace)"
+                } else {
+                    ""
+                };
+                Renders::Ansi(vec![
+                    format!("Player: {}{}", observation.player_sum, ace),
+                    format!("Dealer: {}", observation.dealer_showing),
+                ])
+            }
+            _ => Renders::None,
+        }
+    }
+
+    fn close(&mut self) {}
+}
+
+impl EnvironmentId for BlackjackEnv {
+    const ID: &'static str = "Blackjack-v1";
+}
+
+impl EnvProperties for BlackjackEnv {
+    type Observation = BlackjackObservation;
+    type ActionSpace = Discrete;
+    type ObservationSpace = BoxR<BlackjackObservation>;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: None,
+            reward_threshold: None,
+            nondeterministic: false,
+        }
+    }
+
+    fn metadata(&self) -> &Metadata<Self> {
+        &self.metadata
+    }
+
+    fn rand_random(&self) -> &Pcg64 {
+        &self.rand_random
+    }
+
+    fn render_mode(&self) -> &RenderMode {
+        &self.render_mode
+    }
+
+    fn reward_range(&self) -> &RewardRange {
+        &self.reward_range
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        &self.action_space
+    }
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        hand_for, sum_hand, usable_ace, BlackjackAction, BlackjackEnv, BlackjackObservation,
+    };
+    use crate::{
+        core::{Env, EnvProperties},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_sum_and_ace_when_hand_built_then_hand_matches_observation() {
+        for sum in 4..=21 {
+            for ace in [false, true] {
+                if ace && sum < 12 {
+                    continue;
+                }
+                let hand = hand_for(sum, ace);
+                assert_eq!((sum_hand(&hand), usable_ace(&hand)), (sum, ace));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "usable ace must add up to between 12 and 21")]
+    fn given_usable_ace_with_sum_below_12_when_reset_to_state_then_panics() {
+        let mut env = BlackjackEnv::new(false, RenderMode::None);
+
+        env.reset_to_state(BlackjackObservation {
+            player_sum: 8,
+            dealer_showing: 5,
+            usable_ace: true,
+        });
+    }
+
+    #[test]
+    fn given_observation_space_bounds_when_reset_then_usable_ace_matches_player_sum() {
+        let mut env = BlackjackEnv::new(false, RenderMode::None);
+        let bounds = env.observation_space().clone();
+
+        for seed in 0..200 {
+            let (observation, _) = env.reset(Some(seed), false, Some(bounds.clone()));
+
+            assert_eq!(observation, env.observation());
+        }
+    }
+
+    #[test]
+    fn given_basic_strategy_when_many_episodes_played_then_average_return_is_near_theory() {
+        let mut env = BlackjackEnv::new(false, RenderMode::None);
+        env.reset(Some(0), false, None);
+
+        let episodes = 200_000;
+        let mut total_reward = 0.;
+        for _ in 0..episodes {
+            loop {
+                let observation = env.observation();
+                let (sum, dealer) = (observation.player_sum, observation.dealer_showing);
+                let weak_dealer = (2..=6).contains(&dealer);
+                let stick = if observation.usable_ace {
+                    sum >= 19 || (sum == 18 && (2..=8).contains(&dealer))
+                } else {
+                    sum >= 17
+                        || (sum >= 13 && weak_dealer)
+                        || (sum == 12 && (4..=6).contains(&dealer))
+                };
+                let action = if stick {
+                    BlackjackAction::Stick
+                } else {
+                    BlackjackAction::Hit
+                };
+
+                let action_reward = env.step(action);
+                if action_reward.done {
+                    total_reward += action_reward.reward.into_inner();
+                    break;
+                }
+            }
+            env.deal(None);
+        }
+
+        let average = total_reward / episodes as f64;
+        assert!(
+            (-0.08..=-0.02).contains(&average),
+            "average return {}",
+            average
+        );
+    }
+}
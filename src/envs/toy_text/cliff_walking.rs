@@ -0,0 +1,365 @@
+use std::fmt::Display;
+
+use derivative::Derivative;
+use ordered_float::OrderedFloat;
+use rand_pcg::Pcg64;
+use serde::Serialize;
+
+use crate::{
+    core::{ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId, RewardRange},
+    spaces::{BoxR, Discrete, DiscreteObservation, InvalidDiscreteValue, Space},
+    utils::{
+        custom::{structs::Metadata, traits::Sample},
+        renderer::{RenderMode, Renders},
+        seeding::rand_random,
+    },
+};
+
+/// The number of rows of the grid.
+const CLIFF_WALKING_NROW: usize = 4;
+/// The number of columns of the grid.
+const CLIFF_WALKING_NCOL: usize = 12;
+/// The index of the cell the agent starts on, being the bottom left corner.
+const CLIFF_WALKING_START: usize = (CLIFF_WALKING_NROW - 1) * CLIFF_WALKING_NCOL;
+/// The index of the goal, being the bottom right corner.
+const CLIFF_WALKING_GOAL: usize = CLIFF_WALKING_NROW * CLIFF_WALKING_NCOL - 1;
+
+const CLIFF_WALKING_RENDER_MODES: &[RenderMode] = &[RenderMode::Ansi, RenderMode::None];
+
+impl Default for Metadata<CliffWalkingEnv> {
+    fn default() -> Self {
+        Metadata::new(CLIFF_WALKING_RENDER_MODES, 4)
+    }
+}
+
+/// The directions the agent can move in, indexed in the same order as OpenAI's gym.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CliffWalkingAction {
+    /// Moves the agent one row up.
+    Up,
+    /// Moves the agent one column to the right.
+    Right,
+    /// Moves the agent one row down.
+    Down,
+    /// Moves the agent one column to the left.
+    Left,
+}
+
+impl From<CliffWalkingAction> for usize {
+    fn from(action: CliffWalkingAction) -> Self {
+        match action {
+            CliffWalkingAction::Up => 0,
+            CliffWalkingAction::Right => 1,
+            CliffWalkingAction::Down => 2,
+            CliffWalkingAction::Left => 3,
+        }
+    }
+}
+
+impl TryFrom<usize> for CliffWalkingAction {
+    type Error = InvalidDiscreteValue;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(CliffWalkingAction::Up),
+            1 => Ok(CliffWalkingAction::Right),
+            2 => Ok(CliffWalkingAction::Down),
+            3 => Ok(CliffWalkingAction::Left),
+            _ => Err(InvalidDiscreteValue { value, n: 4 }),
+        }
+    }
+}
+
+impl Space<CliffWalkingAction> for Discrete {
+    fn contains(&self, value: CliffWalkingAction) -> bool {
+        self.contains(usize::from(value))
+    }
+}
+
+impl Display for CliffWalkingAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliffWalkingAction::Up => write!(f, "Up"),
+            CliffWalkingAction::Right => write!(f, "Right"),
+            CliffWalkingAction::Down => write!(f, "Down"),
+            CliffWalkingAction::Left => write!(f, "Left"),
+        }
+    }
+}
+
+/// An implementation of the tabular reinforcement learning environment, cliff walking, as
+/// described in Sutton and Barto's book.
+///
+/// The agent walks along a 4x12 grid from the bottom left corner to the bottom right corner,
+/// with the cells between them forming a cliff. Every step is rewarded with `-1`, except for
+/// stepping off the cliff, which is rewarded with `-100` and returns the agent to the start. The
+/// observation is the index of the cell the agent stands on, counted row by row.
+///
+/// An episode ends when one of the following conditions occur:
+///     1. Termination: The agent reaches the goal.
+#[derive(Serialize, Derivative, Clone)]
+#[derivative(Debug)]
+pub struct CliffWalkingEnv {
+    /// The index of the cell the agent stands on.
+    pub state: usize,
+
+    /// The type of renders produced.
+    pub render_mode: RenderMode,
+
+    /// The set of actions which can be taken.
+    pub action_space: Discrete,
+    /// The set of cells which can be observed.
+    pub observation_space: Discrete,
+
+    /// Additional information provided by the environment.
+    pub metadata: Metadata<Self>,
+
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+
+    reward_range: RewardRange,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    rand_random: Pcg64,
+}
+
+impl CliffWalkingEnv {
+    /// Generates an instance of the cliff walking environment with the agent at the start.
+    pub fn new(render_mode: RenderMode) -> Self {
+        let (rand_random, _) = rand_random(None);
+
+        Self {
+            state: CLIFF_WALKING_START,
+            render_mode,
+            action_space: Discrete(4),
+            observation_space: Discrete(CLIFF_WALKING_NROW * CLIFF_WALKING_NCOL),
+            metadata: Metadata::default(),
+            step_count: 0,
+            reward_range: RewardRange::new(OrderedFloat(-100.), OrderedFloat(-1.)),
+            rand_random,
+        }
+    }
+
+    /// Checks whether the cell at the given index is part of the cliff.
+    pub fn is_cliff(state: usize) -> bool {
+        state > CLIFF_WALKING_START && state < CLIFF_WALKING_GOAL
+    }
+
+    /// Computes the index reached by moving in the given direction, staying in place at the edges.
+    fn moved(state: usize, action: CliffWalkingAction) -> usize {
+        let (mut row, mut col) = (state / CLIFF_WALKING_NCOL, state % CLIFF_WALKING_NCOL);
+        match action {
+            CliffWalkingAction::Up => row = row.saturating_sub(1),
+            CliffWalkingAction::Right => col = (col + 1).min(CLIFF_WALKING_NCOL - 1),
+            CliffWalkingAction::Down => row = (row + 1).min(CLIFF_WALKING_NROW - 1),
+            CliffWalkingAction::Left => col = col.saturating_sub(1),
+        }
+        row * CLIFF_WALKING_NCOL + col
+    }
+
+    /// Draws the grid as rows of text, where the agent is marked with an `A`, the cliff with `C`,
+    /// the goal with `G` and every other cell with `o`.
+    fn render_ansi(&self) -> Vec<String> {
+        (0..CLIFF_WALKING_NROW)
+            .map(|row| {
+                (0..CLIFF_WALKING_NCOL)
+                    .map(|col| {
+                        let state = row * CLIFF_WALKING_NCOL + col;
+                        if state == self.state {
+                            'A'
+                        } else if Self::is_cliff(state) {
+                            'C'
+                        } else if state == CLIFF_WALKING_GOAL {
+                            'G'
+                        } else {
+                            'o'
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Draws a random action from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> CliffWalkingAction {
+        CliffWalkingAction::try_from(self.action_space.sample(&mut self.rand_random))
+            .expect("The action space only holds valid actions.")
+    }
+}
+
+impl Env for CliffWalkingEnv {
+    type Action = CliffWalkingAction;
+    type Info = ();
+    type ResetInfo = ();
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        self.state = Self::moved(self.state, action);
+
+        let reward = if Self::is_cliff(self.state) {
+            self.state = CLIFF_WALKING_START;
+            OrderedFloat(-100.)
+        } else {
+            OrderedFloat(-1.)
+        };
+
+        self.step_count += 1;
+
+        ActionReward {
+            observation: DiscreteObservation(self.state),
+            reward,
+            done: self.state == CLIFF_WALKING_GOAL,
+            truncated: false,
+            info: None,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (rand_random, _) = rand_random(seed);
+        self.rand_random = rand_random;
+        self.step_count = 0;
+
+        self.state = match options {
+            Some(bounds) => {
+                DiscreteObservation::sample_between(&mut self.rand_random, Some(bounds)).0
+            }
+            None => CLIFF_WALKING_START,
+        };
+
+        let observation = DiscreteObservation(self.state);
+        if return_info {
+            (observation, Some(()))
+        } else {
+            (observation, None)
+        }
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.step_count = 0;
+        assert!(
+            self.observation_space.contains(state),
+            "{} does not index a cell of the grid.",
+            state.0
+        );
+        self.state = state.0;
+
+        state
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        assert!(self.metadata.render_modes.contains(&mode));
+
+        match mode {
+            RenderMode::Ansi => Renders::Ansi(self.render_ansi()),
+            _ => Renders::None,
+        }
+    }
+
+    fn close(&mut self) {}
+}
+
+impl EnvironmentId for CliffWalkingEnv {
+    const ID: &'static str = "CliffWalking-v0";
+}
+
+impl EnvProperties for CliffWalkingEnv {
+    type Observation = DiscreteObservation;
+    type ActionSpace = Discrete;
+    type ObservationSpace = Discrete;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: None,
+            reward_threshold: None,
+            nondeterministic: false,
+        }
+    }
+
+    fn metadata(&self) -> &Metadata<Self> {
+        &self.metadata
+    }
+
+    fn rand_random(&self) -> &Pcg64 {
+        &self.rand_random
+    }
+
+    fn render_mode(&self) -> &RenderMode {
+        &self.render_mode
+    }
+
+    fn reward_range(&self) -> &RewardRange {
+        &self.reward_range
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        &self.action_space
+    }
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::{CliffWalkingAction, CliffWalkingEnv, CLIFF_WALKING_START};
+    use crate::{
+        core::Env,
+        spaces::DiscreteObservation,
+        utils::renderer::{RenderMode, Renders},
+    };
+
+    #[test]
+    fn given_optimal_path_when_walked_then_total_reward_is_minus_thirteen() {
+        let mut env = CliffWalkingEnv::new(RenderMode::None);
+        env.reset(Some(0), false, None);
+
+        let mut trajectory = vec![CliffWalkingAction::Up];
+        trajectory.extend([CliffWalkingAction::Right; 11]);
+        trajectory.push(CliffWalkingAction::Down);
+
+        let mut total_reward = OrderedFloat(0.);
+        for (step, &action) in trajectory.iter().enumerate() {
+            let action_reward = env.step(action);
+            total_reward += action_reward.reward;
+            assert_eq!(action_reward.done, step == trajectory.len() - 1);
+        }
+
+        assert_eq!(total_reward, OrderedFloat(-13.));
+    }
+
+    #[test]
+    fn given_agent_beside_cliff_when_stepping_onto_it_then_agent_returns_to_start() {
+        let mut env = CliffWalkingEnv::new(RenderMode::Ansi);
+        env.reset(Some(0), false, None);
+
+        let action_reward = env.step(CliffWalkingAction::Right);
+
+        assert_eq!(action_reward.reward, OrderedFloat(-100.));
+        assert_eq!(
+            action_reward.observation,
+            DiscreteObservation(CLIFF_WALKING_START)
+        );
+        assert!(!action_reward.done);
+        assert_eq!(
+            env.render(RenderMode::Ansi),
+            Renders::Ansi(vec![
+                "oooooooooooo".to_string(),
+                "oooooooooooo".to_string(),
+                "oooooooooooo".to_string(),
+                "ACCCCCCCCCCG".to_string(),
+            ])
+        );
+    }
+}
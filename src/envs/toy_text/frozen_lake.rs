@@ -0,0 +1,476 @@
+use std::fmt::Display;
+
+use derivative::Derivative;
+use ordered_float::OrderedFloat;
+use rand::Rng;
+use rand_pcg::Pcg64;
+use serde::Serialize;
+
+use crate::{
+    core::{ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId, RewardRange},
+    spaces::{BoxR, Discrete, DiscreteObservation, InvalidDiscreteValue, Space},
+    utils::{
+        custom::{structs::Metadata, traits::Sample},
+        renderer::{RenderMode, Renders},
+        seeding::rand_random,
+    },
+};
+
+/// The default 4x4 map, where `S` marks the start, `F` frozen ice, `H` a hole and `G` the goal.
+pub const FROZEN_LAKE_MAP_4X4: [&str; 4] = ["SFFF", "FHFH", "FFFH", "HFFG"];
+
+/// The default 8x8 map, using the same symbols as [`FROZEN_LAKE_MAP_4X4`].
+pub const FROZEN_LAKE_MAP_8X8: [&str; 8] = [
+    "SFFFFFFF", "FFFFFFFF", "FFFHFFFF", "FFFFFHFF", "FFFHFFFF", "FHHFFFHF", "FHFFHFHF", "FFFHFFFG",
+];
+
+/// The number of steps after which an episode on a map of at most 16 cells is truncated.
+const FROZEN_LAKE_EPISODE_LENGTH: usize = 100;
+/// The number of steps after which an episode on a larger map is truncated.
+const FROZEN_LAKE_LARGE_EPISODE_LENGTH: usize = 200;
+
+const FROZEN_LAKE_RENDER_MODES: &[RenderMode] = &[RenderMode::Ansi, RenderMode::None];
+
+impl Default for Metadata<FrozenLakeEnv> {
+    fn default() -> Self {
+        Metadata::new(FROZEN_LAKE_RENDER_MODES, 4)
+    }
+}
+
+/// The kinds of tiles a frozen lake is made of.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    /// The tile the agent starts on, which is otherwise frozen.
+    Start,
+    /// A tile which can safely be walked on.
+    Frozen,
+    /// A tile which ends the episode without a reward.
+    Hole,
+    /// A tile which ends the episode with a reward of `1`.
+    Goal,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = String;
+
+    fn try_from(symbol: char) -> Result<Self, Self::Error> {
+        match symbol {
+            'S' => Ok(Cell::Start),
+            'F' => Ok(Cell::Frozen),
+            'H' => Ok(Cell::Hole),
+            'G' => Ok(Cell::Goal),
+            _ => Err(format!(
+                "'{}' does not describe a frozen lake cell.",
+                symbol
+            )),
+        }
+    }
+}
+
+impl From<Cell> for char {
+    fn from(cell: Cell) -> Self {
+        match cell {
+            Cell::Start => 'S',
+            Cell::Frozen => 'F',
+            Cell::Hole => 'H',
+            Cell::Goal => 'G',
+        }
+    }
+}
+
+/// The directions the agent can attempt to move in.
+///
+/// The indices follow OpenAI's gym, such that adjacent indices, modulo 4, are perpendicular.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FrozenLakeAction {
+    /// Moves the agent one column to the left.
+    Left,
+    /// Moves the agent one row down.
+    Down,
+    /// Moves the agent one column to the right.
+    Right,
+    /// Moves the agent one row up.
+    Up,
+}
+
+impl From<FrozenLakeAction> for usize {
+    fn from(action: FrozenLakeAction) -> Self {
+        match action {
+            FrozenLakeAction::Left => 0,
+            FrozenLakeAction::Down => 1,
+            FrozenLakeAction::Right => 2,
+            FrozenLakeAction::Up => 3,
+        }
+    }
+}
+
+impl TryFrom<usize> for FrozenLakeAction {
+    type Error = InvalidDiscreteValue;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(FrozenLakeAction::Left),
+            1 => Ok(FrozenLakeAction::Down),
+            2 => Ok(FrozenLakeAction::Right),
+            3 => Ok(FrozenLakeAction::Up),
+            _ => Err(InvalidDiscreteValue { value, n: 4 }),
+        }
+    }
+}
+
+impl Space<FrozenLakeAction> for Discrete {
+    fn contains(&self, value: FrozenLakeAction) -> bool {
+        self.contains(usize::from(value))
+    }
+}
+
+impl Display for FrozenLakeAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrozenLakeAction::Left => write!(f, "Left"),
+            FrozenLakeAction::Down => write!(f, "Down"),
+            FrozenLakeAction::Right => write!(f, "Right"),
+            FrozenLakeAction::Up => write!(f, "Up"),
+        }
+    }
+}
+
+/// An implementation of the tabular reinforcement learning environment, frozen lake.
+///
+/// The agent walks across a frozen lake from the start to the goal without falling into any of
+/// the holes. The observation is the index of the cell the agent stands on, counted row by row.
+/// When the ice is slippery, the agent moves in the intended direction with a probability of
+/// 1/3 and in either of the perpendicular directions otherwise. Reaching the goal is rewarded with
+/// `1`, every other step with `0`.
+///
+/// An episode ends when one of the following conditions occur:
+///     1. Termination: The agent falls into a hole or reaches the goal.
+///     2. Truncation: The episode exceeds 100 steps, or 200 steps on maps larger than 4x4.
+#[derive(Serialize, Derivative, Clone)]
+#[derivative(Debug)]
+pub struct FrozenLakeEnv {
+    /// The tiles of the lake, row by row.
+    pub map: Vec<Vec<Cell>>,
+    /// Whether the agent may slip into a perpendicular direction.
+    pub is_slippery: bool,
+    /// The index of the cell the agent stands on.
+    pub state: usize,
+
+    /// The type of renders produced.
+    pub render_mode: RenderMode,
+
+    /// The set of actions which can be taken.
+    pub action_space: Discrete,
+    /// The set of cells which can be observed.
+    pub observation_space: Discrete,
+
+    /// Additional information provided by the environment.
+    pub metadata: Metadata<Self>,
+
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+
+    reward_range: RewardRange,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    rand_random: Pcg64,
+}
+
+impl FrozenLakeEnv {
+    /// Generates a slippery frozen lake on the default 4x4 map.
+    pub fn new(render_mode: RenderMode) -> Self {
+        Self::from_map(&FROZEN_LAKE_MAP_4X4, true, render_mode)
+    }
+
+    /// Generates a frozen lake from rows of the symbols `S`, `F`, `H` and `G`.
+    ///
+    /// Panics if the rows differ in length, contain an unknown symbol or hold no start cell.
+    pub fn from_map(map: &[&str], is_slippery: bool, render_mode: RenderMode) -> Self {
+        let map: Vec<Vec<Cell>> = map
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .map(|symbol| Cell::try_from(symbol).unwrap_or_else(|err| panic!("{}", err)))
+                    .collect()
+            })
+            .collect();
+
+        let ncol = map.first().map_or(0, Vec::len);
+        assert!(
+            map.iter().all(|row| row.len() == ncol),
+            "Every row of the map must hold the same number of cells."
+        );
+
+        let (rand_random, _) = rand_random(None);
+        let n_cells = map.len() * ncol;
+
+        let mut env = Self {
+            map,
+            is_slippery,
+            state: 0,
+            render_mode,
+            action_space: Discrete(4),
+            observation_space: Discrete(n_cells),
+            metadata: Metadata::default(),
+            step_count: 0,
+            reward_range: RewardRange::new(OrderedFloat(0.), OrderedFloat(1.)),
+            rand_random,
+        };
+        env.state = env.start_state();
+        env
+    }
+
+    /// The number of columns of the map.
+    pub fn ncol(&self) -> usize {
+        self.map[0].len()
+    }
+
+    /// The number of rows of the map.
+    pub fn nrow(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Provides the tile found at the given index.
+    pub fn cell(&self, state: usize) -> Cell {
+        self.map[state / self.ncol()][state % self.ncol()]
+    }
+
+    fn start_state(&self) -> usize {
+        (0..self.observation_space.0)
+            .find(|&state| self.cell(state) == Cell::Start)
+            .expect("The map must hold a start cell.")
+    }
+
+    /// Computes the index reached by moving in the given direction, staying in place at the edges.
+    fn moved(&self, state: usize, action: FrozenLakeAction) -> usize {
+        let (mut row, mut col) = (state / self.ncol(), state % self.ncol());
+        match action {
+            FrozenLakeAction::Left => col = col.saturating_sub(1),
+            FrozenLakeAction::Down => row = (row + 1).min(self.nrow() - 1),
+            FrozenLakeAction::Right => col = (col + 1).min(self.ncol() - 1),
+            FrozenLakeAction::Up => row = row.saturating_sub(1),
+        }
+        row * self.ncol() + col
+    }
+
+    /// Draws the map as rows of text, marking the agent with an `A`.
+    fn render_ansi(&self) -> Vec<String> {
+        self.map
+            .iter()
+            .enumerate()
+            .map(|(row, cells)| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(|(col, &cell)| {
+                        if row * self.ncol() + col == self.state {
+                            'A'
+                        } else {
+                            char::from(cell)
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Draws a random action from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> FrozenLakeAction {
+        FrozenLakeAction::try_from(self.action_space.sample(&mut self.rand_random))
+            .expect("The action space only holds valid actions.")
+    }
+}
+
+impl Env for FrozenLakeEnv {
+    type Action = FrozenLakeAction;
+    type Info = ();
+    type ResetInfo = ();
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let direction = if self.is_slippery {
+            let offset = self.rand_random.gen_range(0..3) + 3;
+            FrozenLakeAction::try_from((usize::from(action) + offset) % 4)
+                .expect("The direction is reduced modulo the number of actions.")
+        } else {
+            action
+        };
+
+        self.state = self.moved(self.state, direction);
+        self.step_count += 1;
+
+        let cell = self.cell(self.state);
+        let terminated = matches!(cell, Cell::Hole | Cell::Goal);
+        let truncated = !terminated
+            && self
+                .episode_length()
+                .is_some_and(|episode_length| self.step_count >= episode_length);
+        let reward = if cell == Cell::Goal {
+            OrderedFloat(1.)
+        } else {
+            OrderedFloat(0.)
+        };
+
+        ActionReward {
+            observation: DiscreteObservation(self.state),
+            reward,
+            done: terminated || truncated,
+            truncated,
+            info: None,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (rand_random, _) = rand_random(seed);
+        self.rand_random = rand_random;
+
+        self.state = match options {
+            Some(bounds) => {
+                DiscreteObservation::sample_between(&mut self.rand_random, Some(bounds)).0
+            }
+            None => self.start_state(),
+        };
+        self.step_count = 0;
+
+        let observation = DiscreteObservation(self.state);
+        if return_info {
+            (observation, Some(()))
+        } else {
+            (observation, None)
+        }
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        assert!(
+            self.observation_space.contains(state),
+            "{} does not index a cell of the map.",
+            state.0
+        );
+        self.state = state.0;
+        self.step_count = 0;
+
+        state
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        assert!(self.metadata.render_modes.contains(&mode));
+
+        match mode {
+            RenderMode::Ansi => Renders::Ansi(self.render_ansi()),
+            _ => Renders::None,
+        }
+    }
+
+    fn close(&mut self) {}
+}
+
+impl EnvironmentId for FrozenLakeEnv {
+    const ID: &'static str = "FrozenLake-v1";
+}
+
+impl EnvProperties for FrozenLakeEnv {
+    type Observation = DiscreteObservation;
+    type ActionSpace = Discrete;
+    type ObservationSpace = Discrete;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: Some(FROZEN_LAKE_EPISODE_LENGTH),
+            reward_threshold: Some(0.70),
+            nondeterministic: false,
+        }
+    }
+
+    fn metadata(&self) -> &Metadata<Self> {
+        &self.metadata
+    }
+
+    fn rand_random(&self) -> &Pcg64 {
+        &self.rand_random
+    }
+
+    fn render_mode(&self) -> &RenderMode {
+        &self.render_mode
+    }
+
+    fn reward_range(&self) -> &RewardRange {
+        &self.reward_range
+    }
+
+    fn episode_length(&self) -> Option<usize> {
+        if self.observation_space.0 <= 16 {
+            Some(FROZEN_LAKE_EPISODE_LENGTH)
+        } else {
+            Some(FROZEN_LAKE_LARGE_EPISODE_LENGTH)
+        }
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        &self.action_space
+    }
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::{FrozenLakeAction, FrozenLakeEnv, FROZEN_LAKE_MAP_4X4};
+    use crate::{
+        core::Env,
+        spaces::DiscreteObservation,
+        utils::renderer::{RenderMode, Renders},
+    };
+
+    #[test]
+    fn given_frozen_ice_when_walking_to_goal_then_episode_ends_with_reward() {
+        let mut env = FrozenLakeEnv::from_map(&FROZEN_LAKE_MAP_4X4, false, RenderMode::None);
+        env.reset(Some(0), false, None);
+
+        use FrozenLakeAction::*;
+        for action in [Down, Down, Right, Right, Down] {
+            let action_reward = env.step(action);
+            assert!(!action_reward.done);
+            assert_eq!(action_reward.reward, OrderedFloat(0.));
+        }
+
+        let action_reward = env.step(Right);
+        assert!(action_reward.done);
+        assert!(!action_reward.truncated);
+        assert_eq!(action_reward.observation, DiscreteObservation(15));
+        assert_eq!(action_reward.reward, OrderedFloat(1.));
+    }
+
+    #[test]
+    fn given_agent_in_hole_when_rendered_as_ansi_then_agent_is_marked() {
+        let mut env = FrozenLakeEnv::from_map(&FROZEN_LAKE_MAP_4X4, false, RenderMode::Ansi);
+        env.reset(Some(0), false, None);
+
+        env.step(FrozenLakeAction::Right);
+        let action_reward = env.step(FrozenLakeAction::Down);
+        assert!(action_reward.done);
+        assert_eq!(action_reward.reward, OrderedFloat(0.));
+
+        assert_eq!(
+            env.render(RenderMode::Ansi),
+            Renders::Ansi(vec![
+                "SFFF".to_string(),
+                "FAFH".to_string(),
+                "FFFH".to_string(),
+                "HFFG".to_string(),
+            ])
+        );
+    }
+}
@@ -0,0 +1,8 @@
+/// Holds all structures related to the blackjack environment.
+pub mod blackjack;
+/// Holds all structures related to the cliff walking environment.
+pub mod cliff_walking;
+/// Holds all structures related to the frozen lake environment.
+pub mod frozen_lake;
+/// Holds all structures related to the taxi environment.
+pub mod taxi;
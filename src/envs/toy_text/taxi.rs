@@ -0,0 +1,493 @@
+use std::fmt::Display;
+
+use derivative::Derivative;
+use ordered_float::OrderedFloat;
+use rand::Rng;
+use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{ActionReward, Env, EnvProperties, EnvSpec, EnvironmentId, RewardRange},
+    spaces::{BoxR, Discrete, DiscreteObservation, InvalidDiscreteValue, Space},
+    utils::{
+        custom::{structs::Metadata, traits::Sample, types::O64},
+        renderer::{RenderMode, Renders},
+        seeding::rand_random,
+    },
+};
+
+/// The layout of the city, where `|` marks a wall and `:` a passable border between cells.
+const TAXI_MAP: [&str; 7] = [
+    "+---------+",
+    "|R: | : :G|",
+    "| : | : : |",
+    "| : : : : |",
+    "| | : | : |",
+    "|Y| : |B: |",
+    "+---------+",
+];
+
+/// The cells of the locations `R`, `G`, `Y` and `B`, in the order used to index them.
+const TAXI_LOCATIONS: [(u8, u8); 4] = [(0, 0), (0, 4), (4, 0), (4, 3)];
+
+/// The number of rows and columns of the grid.
+const TAXI_GRID_SIZE: u8 = 5;
+
+/// The index of the passenger location denoting that the passenger sits in the taxi.
+const TAXI_IN_TAXI: u8 = 4;
+
+/// The number of steps after which an episode is truncated.
+const TAXI_EPISODE_LENGTH: usize = 200;
+
+const TAXI_RENDER_MODES: &[RenderMode] = &[RenderMode::Ansi, RenderMode::None];
+
+impl Default for Metadata<TaxiEnv> {
+    fn default() -> Self {
+        Metadata::new(TAXI_RENDER_MODES, 4)
+    }
+}
+
+/// The actions the taxi driver can take.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaxiAction {
+    /// Moves the taxi one row down.
+    South,
+    /// Moves the taxi one row up.
+    North,
+    /// Moves the taxi one column to the right.
+    East,
+    /// Moves the taxi one column to the left.
+    West,
+    /// Picks up the passenger.
+    Pickup,
+    /// Drops off the passenger.
+    Dropoff,
+}
+
+impl From<TaxiAction> for usize {
+    fn from(action: TaxiAction) -> Self {
+        match action {
+            TaxiAction::South => 0,
+            TaxiAction::North => 1,
+            TaxiAction::East => 2,
+            TaxiAction::West => 3,
+            TaxiAction::Pickup => 4,
+            TaxiAction::Dropoff => 5,
+        }
+    }
+}
+
+impl TryFrom<usize> for TaxiAction {
+    type Error = InvalidDiscreteValue;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TaxiAction::South),
+            1 => Ok(TaxiAction::North),
+            2 => Ok(TaxiAction::East),
+            3 => Ok(TaxiAction::West),
+            4 => Ok(TaxiAction::Pickup),
+            5 => Ok(TaxiAction::Dropoff),
+            _ => Err(InvalidDiscreteValue { value, n: 6 }),
+        }
+    }
+}
+
+impl Space<TaxiAction> for Discrete {
+    fn contains(&self, value: TaxiAction) -> bool {
+        self.contains(usize::from(value))
+    }
+}
+
+impl Display for TaxiAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaxiAction::South => write!(f, "South"),
+            TaxiAction::North => write!(f, "North"),
+            TaxiAction::East => write!(f, "East"),
+            TaxiAction::West => write!(f, "West"),
+            TaxiAction::Pickup => write!(f, "Pickup"),
+            TaxiAction::Dropoff => write!(f, "Dropoff"),
+        }
+    }
+}
+
+/// The decoded state of the taxi environment.
+///
+/// Locations are indexed in the order `R`, `G`, `Y`, `B`, with a passenger location of `4`
+/// denoting that the passenger sits in the taxi.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaxiObservation {
+    /// The row the taxi is in.
+    pub taxi_row: u8,
+    /// The column the taxi is in.
+    pub taxi_col: u8,
+    /// The location of the passenger.
+    pub passenger: u8,
+    /// The location the passenger wants to be driven to.
+    pub destination: u8,
+}
+
+impl From<TaxiObservation> for DiscreteObservation {
+    fn from(o: TaxiObservation) -> Self {
+        let taxi = o.taxi_row as usize * TAXI_GRID_SIZE as usize + o.taxi_col as usize;
+        DiscreteObservation((taxi * 5 + o.passenger as usize) * 4 + o.destination as usize)
+    }
+}
+
+impl From<DiscreteObservation> for TaxiObservation {
+    fn from(observation: DiscreteObservation) -> Self {
+        let mut index = observation.0;
+        let destination = (index % 4) as u8;
+        index /= 4;
+        let passenger = (index % 5) as u8;
+        index /= 5;
+        let taxi_col = (index % TAXI_GRID_SIZE as usize) as u8;
+        index /= TAXI_GRID_SIZE as usize;
+
+        TaxiObservation {
+            taxi_row: index as u8,
+            taxi_col,
+            passenger,
+            destination,
+        }
+    }
+}
+
+/// An implementation of the tabular reinforcement learning environment, taxi.
+///
+/// A taxi drives around a 5x5 grid with four designated locations, picking up a passenger waiting
+/// at one of them and dropping them off at another. Every step is rewarded with `-1`, except for
+/// delivering the passenger, which is rewarded with `20`, and attempting to pick up or drop off
+/// the passenger where it is not possible, which is rewarded with `-10`. The observation is the
+/// index of the state, which can be decoded into a [`TaxiObservation`].
+///
+/// An episode ends when one of the following conditions occur:
+///     1. Termination: The passenger is dropped off at their destination.
+///     2. Truncation: The episode exceeds 200 steps.
+#[derive(Serialize, Derivative, Clone)]
+#[derivative(Debug)]
+pub struct TaxiEnv {
+    /// The state of the environment.
+    pub state: TaxiObservation,
+
+    /// The type of renders produced.
+    pub render_mode: RenderMode,
+
+    /// The set of actions which can be taken.
+    pub action_space: Discrete,
+    /// The set of encoded states which can be observed.
+    pub observation_space: Discrete,
+
+    /// Additional information provided by the environment.
+    pub metadata: Metadata<Self>,
+
+    /// The number of steps taken since the last reset.
+    pub step_count: usize,
+
+    reward_range: RewardRange,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    rand_random: Pcg64,
+}
+
+impl TaxiEnv {
+    /// Generates an instance of the taxi environment in a random state.
+    pub fn new(render_mode: RenderMode) -> Self {
+        let (mut rng, _) = rand_random(None);
+        let state = Self::sample_state(&mut rng);
+
+        Self {
+            state,
+            render_mode,
+            action_space: Discrete(6),
+            observation_space: Discrete(500),
+            metadata: Metadata::default(),
+            step_count: 0,
+            reward_range: RewardRange::new(OrderedFloat(-10.), OrderedFloat(20.)),
+            rand_random: rng,
+        }
+    }
+
+    /// Places the taxi on a random cell and the passenger at a location other than their
+    /// destination.
+    fn sample_state<R: Rng>(rng: &mut R) -> TaxiObservation {
+        let passenger = rng.gen_range(0..4);
+        let destination = (passenger + rng.gen_range(1..4)) % 4;
+
+        TaxiObservation {
+            taxi_row: rng.gen_range(0..TAXI_GRID_SIZE),
+            taxi_col: rng.gen_range(0..TAXI_GRID_SIZE),
+            passenger,
+            destination,
+        }
+    }
+
+    /// Checks whether the border on the given side of the given cell can be passed, where the
+    /// offset is `1` for the right side and `-1` for the left.
+    fn is_passable(row: u8, col: u8, offset: isize) -> bool {
+        let column = (2 * col as isize + 1 + offset) as usize;
+        TAXI_MAP[row as usize + 1].as_bytes()[column] == b':'
+    }
+
+    /// Draws the city as rows of text, where the taxi is highlighted in yellow when empty and in
+    /// green when carrying the passenger, the passenger's location in blue and the destination in
+    /// magenta.
+    fn render_ansi(&self) -> Vec<String> {
+        let mut grid: Vec<Vec<String>> = TAXI_MAP
+            .iter()
+            .map(|row| row.chars().map(String::from).collect())
+            .collect();
+        let cell = |row: u8, col: u8| (row as usize + 1, 2 * col as usize + 1);
+
+        if let Some(&(row, col)) = TAXI_LOCATIONS.get(self.state.passenger as usize) {
+            let (y, x) = cell(row, col);
+            grid[y][x] = format!("\x1b[1;34m{}\x1b[0m", grid[y][x]);
+        }
+
+        let (row, col) = TAXI_LOCATIONS[self.state.destination as usize];
+        let (y, x) = cell(row, col);
+        grid[y][x] = format!("\x1b[35m{}\x1b[0m", grid[y][x]);
+
+        let (y, x) = cell(self.state.taxi_row, self.state.taxi_col);
+        let background = if self.state.passenger == TAXI_IN_TAXI {
+            42
+        } else {
+            43
+        };
+        let symbol = TAXI_MAP[y].chars().nth(x).unwrap_or(' ');
+        grid[y][x] = format!("\x1b[{}m{}\x1b[0m", background, symbol);
+
+        grid.into_iter().map(|row| row.concat()).collect()
+    }
+
+    /// Draws a random action from the action space using the environment's generator.
+    pub fn sample_action(&mut self) -> TaxiAction {
+        TaxiAction::try_from(self.action_space.sample(&mut self.rand_random))
+            .expect("The action space only holds valid actions.")
+    }
+}
+
+impl Env for TaxiEnv {
+    type Action = TaxiAction;
+    type Info = ();
+    type ResetInfo = ();
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let TaxiObservation {
+            mut taxi_row,
+            mut taxi_col,
+            mut passenger,
+            destination,
+        } = self.state;
+        let taxi = (taxi_row, taxi_col);
+        let last = TAXI_GRID_SIZE - 1;
+
+        let mut reward: O64 = OrderedFloat(-1.);
+        let mut terminated = false;
+
+        match action {
+            TaxiAction::South => taxi_row = (taxi_row + 1).min(last),
+            TaxiAction::North => taxi_row = taxi_row.saturating_sub(1),
+            TaxiAction::East if Self::is_passable(taxi_row, taxi_col, 1) => {
+                taxi_col = (taxi_col + 1).min(last)
+            }
+            TaxiAction::West if Self::is_passable(taxi_row, taxi_col, -1) => {
+                taxi_col = taxi_col.saturating_sub(1)
+            }
+            TaxiAction::East | TaxiAction::West => {}
+            TaxiAction::Pickup => {
+                if TAXI_LOCATIONS.get(passenger as usize) == Some(&taxi) {
+                    passenger = TAXI_IN_TAXI;
+                } else {
+                    reward = OrderedFloat(-10.);
+                }
+            }
+            TaxiAction::Dropoff => {
+                let location = TAXI_LOCATIONS.iter().position(|&cell| cell == taxi);
+                match location {
+                    Some(location) if passenger == TAXI_IN_TAXI => {
+                        passenger = location as u8;
+                        if passenger == destination {
+                            terminated = true;
+                            reward = OrderedFloat(20.);
+                        }
+                    }
+                    _ => reward = OrderedFloat(-10.),
+                }
+            }
+        }
+
+        self.state = TaxiObservation {
+            taxi_row,
+            taxi_col,
+            passenger,
+            destination,
+        };
+        self.step_count += 1;
+
+        let truncated = !terminated
+            && self
+                .episode_length()
+                .is_some_and(|episode_length| self.step_count >= episode_length);
+
+        ActionReward {
+            observation: self.state.into(),
+            reward,
+            done: terminated || truncated,
+            truncated,
+            info: None,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (rand_random, _) = rand_random(seed);
+        self.rand_random = rand_random;
+
+        self.state = match options {
+            Some(bounds) => {
+                DiscreteObservation::sample_between(&mut self.rand_random, Some(bounds)).into()
+            }
+            None => Self::sample_state(&mut self.rand_random),
+        };
+        self.step_count = 0;
+
+        if return_info {
+            (self.state.into(), Some(()))
+        } else {
+            (self.state.into(), None)
+        }
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        assert!(
+            self.observation_space.contains(state),
+            "{} does not encode a state of the taxi environment.",
+            state.0
+        );
+        self.state = state.into();
+        self.step_count = 0;
+
+        state
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        assert!(self.metadata.render_modes.contains(&mode));
+
+        match mode {
+            RenderMode::Ansi => Renders::Ansi(self.render_ansi()),
+            _ => Renders::None,
+        }
+    }
+
+    fn close(&mut self) {}
+}
+
+impl EnvironmentId for TaxiEnv {
+    const ID: &'static str = "Taxi-v3";
+}
+
+impl EnvProperties for TaxiEnv {
+    type Observation = DiscreteObservation;
+    type ActionSpace = Discrete;
+    type ObservationSpace = Discrete;
+
+    fn spec() -> EnvSpec {
+        EnvSpec {
+            id: Self::ID,
+            max_episode_steps: Some(TAXI_EPISODE_LENGTH),
+            reward_threshold: Some(8.),
+            nondeterministic: false,
+        }
+    }
+
+    fn metadata(&self) -> &Metadata<Self> {
+        &self.metadata
+    }
+
+    fn rand_random(&self) -> &Pcg64 {
+        &self.rand_random
+    }
+
+    fn render_mode(&self) -> &RenderMode {
+        &self.render_mode
+    }
+
+    fn reward_range(&self) -> &RewardRange {
+        &self.reward_range
+    }
+
+    fn episode_length(&self) -> Option<usize> {
+        Some(TAXI_EPISODE_LENGTH)
+    }
+
+    fn step_count(&self) -> usize {
+        self.step_count
+    }
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        &self.action_space
+    }
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::{TaxiAction, TaxiEnv, TaxiObservation};
+    use crate::{core::Env, spaces::DiscreteObservation, utils::renderer::RenderMode};
+
+    #[test]
+    fn given_optimal_trajectory_when_stepped_through_then_total_reward_is_ten() {
+        let mut env = TaxiEnv::new(RenderMode::None);
+        let state = TaxiObservation {
+            taxi_row: 0,
+            taxi_col: 1,
+            passenger: 0,
+            destination: 1,
+        };
+        let index = DiscreteObservation::from(state);
+        assert_eq!(TaxiObservation::from(index), state);
+        env.reset_to_state(index);
+
+        use TaxiAction::*;
+        let trajectory = [
+            West, Pickup, South, South, East, East, East, East, North, North, Dropoff,
+        ];
+
+        let mut total_reward = OrderedFloat(0.);
+        for (step, action) in trajectory.into_iter().enumerate() {
+            let action_reward = env.step(action);
+            total_reward += action_reward.reward;
+            assert_eq!(action_reward.done, step == trajectory.len() - 1);
+        }
+
+        assert_eq!(total_reward, OrderedFloat(10.));
+        assert_eq!(env.state.passenger, env.state.destination);
+    }
+
+    #[test]
+    fn given_passenger_elsewhere_when_picking_up_then_reward_is_penalized() {
+        let mut env = TaxiEnv::new(RenderMode::None);
+        env.reset_to_state(DiscreteObservation::from(TaxiObservation {
+            taxi_row: 2,
+            taxi_col: 2,
+            passenger: 0,
+            destination: 1,
+        }));
+
+        let action_reward = env.step(TaxiAction::Pickup);
+
+        assert_eq!(action_reward.reward, OrderedFloat(-10.));
+        assert!(!action_reward.done);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::core::{ActionReward, Env};
+
+/// Steps through several instances of an environment one after another, exposing a batched API.
+#[derive(Debug, Clone)]
+pub struct SyncVectorEnv<E> {
+    /// The environments being stepped through.
+    pub envs: Vec<E>,
+}
+
+impl<E: Env> SyncVectorEnv<E> {
+    /// Batches the given environments.
+    pub fn new(envs: Vec<E>) -> Self {
+        Self { envs }
+    }
+
+    /// Batches `n` environments, each created by calling `f` with its index.
+    pub fn from_fn<F: Fn(usize) -> E>(n: usize, f: F) -> Self {
+        Self::new((0..n).map(f).collect())
+    }
+
+    /// The number of environments in the batch.
+    pub fn num_envs(&self) -> usize {
+        self.envs.len()
+    }
+
+    /// Applies each action to the environment with the same index.
+    ///
+    /// Panics if the number of actions differs from the number of environments.
+    pub fn step_all(
+        &mut self,
+        actions: Vec<E::Action>,
+    ) -> Vec<ActionReward<E::Observation, E::Info>> {
+        assert_eq!(
+            actions.len(),
+            self.num_envs(),
+            "Expected one action per environment."
+        );
+
+        self.envs
+            .iter_mut()
+            .zip(actions)
+            .map(|(env, action)| env.step(action))
+            .collect()
+    }
+
+    /// Resets every environment, where the environment at index `i` is seeded with `seed + i`,
+    /// wrapping around at `u64::MAX`.
+    pub fn reset_all(&mut self, seed: Option<u64>) -> Vec<E::Observation> {
+        self.envs
+            .iter_mut()
+            .enumerate()
+            .map(|(i, env)| {
+                env.reset(seed.map(|seed| seed.wrapping_add(i as u64)), false, None)
+                    .0
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SyncVectorEnv;
+    use crate::{
+        core::Env,
+        envs::classical_control::mountain_car::{MountainCarAction, MountainCarEnv},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_n_actions_when_step_all_called_then_each_result_matches_its_environment() {
+        let mut vec_env = SyncVectorEnv::from_fn(3, |_| MountainCarEnv::new(RenderMode::None));
+        let observations = vec_env.reset_all(Some(0));
+        let actions = vec![
+            MountainCarAction::Backward,
+            MountainCarAction::Neutral,
+            MountainCarAction::Forward,
+        ];
+
+        let mut expected = MountainCarEnv::new(RenderMode::None);
+        let results = vec_env.step_all(actions.clone());
+
+        assert_eq!(results.len(), vec_env.num_envs());
+        for (i, (result, action)) in results.iter().zip(actions).enumerate() {
+            let (observation, _) = expected.reset(Some(i as u64), false, None);
+            assert_eq!(observation, observations[i]);
+            assert_eq!(result.observation, expected.step(action).observation);
+        }
+    }
+
+    #[test]
+    fn given_largest_seed_when_reset_all_called_then_seeds_wrap_around() {
+        let mut vec_env = SyncVectorEnv::from_fn(2, |_| MountainCarEnv::new(RenderMode::None));
+        let observations = vec_env.reset_all(Some(u64::MAX));
+
+        let mut expected = MountainCarEnv::new(RenderMode::None);
+        assert_eq!(
+            expected.reset(Some(u64::MAX), false, None).0,
+            observations[0]
+        );
+        assert_eq!(expected.reset(Some(0), false, None).0, observations[1]);
+    }
+}
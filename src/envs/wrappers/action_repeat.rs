@@ -0,0 +1,131 @@
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::BoxR,
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// A wrapper which applies each action `n` times, returning the last step with the summed
+/// reward, also known as frame skipping.
+///
+/// Repetition stops early once the episode is done. The episode length and step count are those
+/// of the wrapped environment, counting every repetition.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionRepeat<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// The number of times each action is applied.
+    pub n: usize,
+    metadata: Metadata<Self>,
+}
+
+impl<E: Env> ActionRepeat<E> {
+    /// Wraps the given environment, applying each action `n` times.
+    pub fn new(env: E, n: usize) -> Self {
+        assert!(n > 0, "Each action must be applied at least once.");
+
+        let metadata = env.metadata().cast();
+
+        Self { env, n, metadata }
+    }
+}
+
+impl<E: Env> Env for ActionRepeat<E>
+where
+    E::Action: Clone,
+{
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let mut action_reward = self.env.step(action.clone());
+        let mut reward = action_reward.reward;
+
+        for _ in 1..self.n {
+            if action_reward.done {
+                break;
+            }
+            action_reward = self.env.step(action.clone());
+            reward += action_reward.reward;
+        }
+
+        ActionReward {
+            reward,
+            ..action_reward
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        self.env.reset(seed, return_info, options)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.env.reset_to_state(state)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E: Env> EnvProperties for ActionRepeat<E> {
+    type Observation = E::Observation;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(E);
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::ActionRepeat;
+    use crate::{
+        core::{Env, EnvProperties},
+        envs::{
+            classical_control::cartpole::{CartPoleAction, CartPoleEnv},
+            wrappers::time_limit::TimeLimit,
+        },
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_two_repeats_when_stepping_then_reward_of_both_steps_is_summed() {
+        let mut env = ActionRepeat::new(CartPoleEnv::new(RenderMode::None), 2);
+        env.reset(Some(0), false, None);
+
+        let action_reward = env.step(CartPoleAction::Left);
+
+        assert_eq!(action_reward.reward, OrderedFloat(2.));
+        assert_eq!(env.step_count(), 2);
+    }
+
+    #[test]
+    fn given_episode_ending_between_repeats_when_stepping_then_repetition_stops() {
+        let mut env = ActionRepeat::new(TimeLimit::new(CartPoleEnv::new(RenderMode::None), 3), 2);
+        env.reset(Some(0), false, None);
+        env.step(CartPoleAction::Left);
+
+        let action_reward = env.step(CartPoleAction::Right);
+
+        assert!(action_reward.done);
+        assert_eq!(action_reward.reward, OrderedFloat(1.));
+        assert_eq!(env.env.current_steps, 3);
+    }
+}
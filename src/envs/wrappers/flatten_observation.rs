@@ -0,0 +1,142 @@
+use std::fmt::Debug;
+
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::{BoundedObservation, BoxR},
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// A wrapper which converts structured observations into flat vectors of values.
+#[derive(Debug, Clone, Serialize)]
+pub struct FlattenObservation<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    observation_space: BoxR<Vec<f64>>,
+    metadata: Metadata<Self>,
+}
+
+impl<E> FlattenObservation<E>
+where
+    E: Env + EnvProperties<ObservationSpace = BoxR<<E as EnvProperties>::Observation>>,
+    E::Observation: From<Vec<f64>> + Clone,
+{
+    /// Wraps the given environment, flattening its observations and observation space.
+    pub fn new(env: E) -> Self {
+        let BoxR { low, high } = env.observation_space().clone();
+        let observation_space = BoxR::new(low.into(), high.into());
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            observation_space,
+            metadata,
+        }
+    }
+}
+
+impl<E> Env for FlattenObservation<E>
+where
+    E: Env + EnvProperties<ObservationSpace = BoxR<<E as EnvProperties>::Observation>>,
+    E::Observation: From<Vec<f64>> + Clone + BoundedObservation + Debug,
+{
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let ActionReward {
+            observation,
+            reward,
+            done,
+            truncated,
+            info,
+        } = self.env.step(action);
+
+        ActionReward {
+            observation: observation.into(),
+            reward,
+            done,
+            truncated,
+            info,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let options = options.map(|BoxR { low, high }| BoxR::new(low.into(), high.into()));
+        let (observation, info) = self.env.reset(seed, return_info, options);
+        (observation.into(), info)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.env.reset_to_state(state.into()).into()
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E> EnvProperties for FlattenObservation<E>
+where
+    E: Env + EnvProperties<ObservationSpace = BoxR<<E as EnvProperties>::Observation>>,
+    E::Observation: From<Vec<f64>> + Clone,
+{
+    type Observation = Vec<f64>;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = BoxR<Vec<f64>>;
+
+    forward_env_properties!(
+        E;
+        spec,
+        metadata,
+        rand_random,
+        render_mode,
+        reward_range,
+        episode_length,
+        is_terminated,
+        is_truncated,
+        step_count,
+        action_space
+    );
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlattenObservation;
+    use crate::{
+        core::{Env, EnvProperties},
+        envs::classical_control::mountain_car::MountainCarEnv,
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_structured_observation_when_reset_called_then_values_are_flattened_in_order() {
+        let mut env = FlattenObservation::new(MountainCarEnv::new(RenderMode::None));
+
+        let (observation, _) = env.reset(Some(0), false, None);
+
+        assert_eq!(observation.len(), 2);
+        assert_eq!(observation[0], env.env.state.position.0);
+        assert_eq!(observation[1], env.env.state.velocity.0);
+        assert_eq!(env.observation_space().low, vec![-1.2, -0.07]);
+    }
+}
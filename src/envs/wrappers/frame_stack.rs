@@ -0,0 +1,215 @@
+use std::{collections::VecDeque, fmt::Debug};
+
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::{BoundedObservation, BoxR},
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// A wrapper which observes the last `n` observations of the environment at once, concatenated
+/// from oldest to newest.
+///
+/// After a reset, the initial observation fills every slot of the stack. Stepping before the
+/// first reset panics.
+#[derive(Debug, Clone, Serialize)]
+pub struct FrameStack<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// The number of observations being stacked.
+    pub n: usize,
+    frames: VecDeque<Vec<f64>>,
+    observation_space: BoxR<Vec<f64>>,
+    metadata: Metadata<Self>,
+}
+
+impl<E> FrameStack<E>
+where
+    E: Env + EnvProperties<ObservationSpace = BoxR<<E as EnvProperties>::Observation>>,
+    E::Observation: From<Vec<f64>> + Clone,
+{
+    /// Wraps the given environment, stacking its last `n` observations.
+    pub fn new(env: E, n: usize) -> Self {
+        assert!(n > 0, "At least one observation must be stacked.");
+
+        let BoxR { low, high } = env.observation_space().clone();
+        let (low, high): (Vec<f64>, Vec<f64>) = (low.into(), high.into());
+        let observation_space = BoxR::new(low.repeat(n), high.repeat(n));
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            n,
+            frames: VecDeque::with_capacity(n),
+            observation_space,
+            metadata,
+        }
+    }
+
+    /// The observations currently held, ordered from oldest to newest.
+    pub fn frames(&self) -> &VecDeque<Vec<f64>> {
+        &self.frames
+    }
+
+    fn fill(&mut self, observation: Vec<f64>) {
+        self.frames.clear();
+        self.frames.extend(std::iter::repeat_n(observation, self.n));
+    }
+
+    fn stacked(&self) -> Vec<f64> {
+        self.frames.iter().flatten().copied().collect()
+    }
+}
+
+impl<E> Env for FrameStack<E>
+where
+    E: Env + EnvProperties<ObservationSpace = BoxR<<E as EnvProperties>::Observation>>,
+    E::Observation: From<Vec<f64>> + Clone + BoundedObservation + Debug,
+{
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        assert_eq!(
+            self.frames.len(),
+            self.n,
+            "The stack is empty, call reset() first."
+        );
+
+        let ActionReward {
+            observation,
+            reward,
+            done,
+            truncated,
+            info,
+        } = self.env.step(action);
+
+        self.frames.pop_front();
+        self.frames.push_back(observation.into());
+
+        ActionReward {
+            observation: self.stacked(),
+            reward,
+            done,
+            truncated,
+            info,
+        }
+    }
+
+    /// Resets the wrapped environment, where the bounds of the newest frame within `options`
+    /// are used to sample its initial state.
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let options = options.map(|BoxR { low, high }| {
+            let width = low.len() / self.n;
+            BoxR::new(
+                low[low.len() - width..].to_vec().into(),
+                high[high.len() - width..].to_vec().into(),
+            )
+        });
+
+        let (observation, info) = self.env.reset(seed, return_info, options);
+        self.fill(observation.into());
+
+        (self.stacked(), info)
+    }
+
+    /// Resets the wrapped environment to the newest frame within `state`, which then fills every
+    /// slot of the stack.
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        let width = state.len() / self.n;
+        let observation = self
+            .env
+            .reset_to_state(state[state.len() - width..].to_vec().into());
+        self.fill(observation.into());
+
+        self.stacked()
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E> EnvProperties for FrameStack<E>
+where
+    E: Env + EnvProperties<ObservationSpace = BoxR<<E as EnvProperties>::Observation>>,
+    E::Observation: From<Vec<f64>> + Clone,
+{
+    type Observation = Vec<f64>;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = BoxR<Vec<f64>>;
+
+    forward_env_properties!(
+        E;
+        spec,
+        metadata,
+        rand_random,
+        render_mode,
+        reward_range,
+        episode_length,
+        is_terminated,
+        is_truncated,
+        step_count,
+        action_space
+    );
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrameStack;
+    use crate::{
+        core::{Env, EnvProperties},
+        envs::classical_control::mountain_car::{MountainCarAction, MountainCarEnv},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_reset_environment_when_observed_then_initial_observation_is_repeated() {
+        let mut env = FrameStack::new(MountainCarEnv::new(RenderMode::None), 3);
+
+        let (observation, _) = env.reset(Some(0), false, None);
+
+        assert_eq!(observation.len(), 6);
+        assert_eq!(env.observation_space().low.len(), 6);
+        assert_eq!(observation[0..2], observation[4..6]);
+    }
+
+    #[test]
+    fn given_stepped_environment_when_observed_then_newest_observation_is_last() {
+        let mut env = FrameStack::new(MountainCarEnv::new(RenderMode::None), 3);
+        let (initial, _) = env.reset(Some(0), false, None);
+
+        let observation = env.step(MountainCarAction::Forward).observation;
+
+        assert_eq!(observation[0..4], initial[0..4]);
+        assert_eq!(observation[4..6], env.frames()[2][..]);
+        assert_ne!(observation[4..6], initial[4..6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "call reset() first")]
+    fn given_environment_never_reset_when_stepping_then_panics() {
+        let mut env = FrameStack::new(MountainCarEnv::new(RenderMode::None), 3);
+
+        env.step(MountainCarAction::Forward);
+    }
+}
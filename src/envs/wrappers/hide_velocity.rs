@@ -0,0 +1,184 @@
+use ordered_float::OrderedFloat;
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    envs::classical_control::cartpole::CartPoleObservation,
+    spaces::BoxR,
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// The bound on the velocities of the initial state when resetting within given bounds, matching
+/// the bound used by a cart pole environment reset without any.
+const INITIAL_VELOCITY_BOUND: f64 = 0.05;
+
+/// A wrapper which hides the velocities of a cart pole environment, observing only the position
+/// of the cart and the angle of the pole as `[x, theta]`.
+///
+/// The full state, including both velocities, still drives the physics of the wrapped
+/// environment, making the task partially observable as on hardware which cannot measure
+/// velocities directly.
+#[derive(Debug, Clone, Serialize)]
+pub struct HideVelocity<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    observation_space: BoxR<Vec<f64>>,
+    metadata: Metadata<Self>,
+}
+
+impl<E> HideVelocity<E>
+where
+    E: Env
+        + EnvProperties<
+            Observation = CartPoleObservation,
+            ObservationSpace = BoxR<CartPoleObservation>,
+        >,
+{
+    /// Wraps the given environment, hiding the velocities from its observations and observation
+    /// space.
+    pub fn new(env: E) -> Self {
+        let BoxR { low, high } = env.observation_space();
+        let observation_space = BoxR::new(Self::hide(low), Self::hide(high));
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            observation_space,
+            metadata,
+        }
+    }
+
+    fn hide(observation: &CartPoleObservation) -> Vec<f64> {
+        vec![observation.x(), observation.theta()]
+    }
+
+    fn reveal(observation: &[f64], velocity: f64) -> CartPoleObservation {
+        CartPoleObservation::new(
+            OrderedFloat(observation[0]),
+            OrderedFloat(velocity),
+            OrderedFloat(observation[1]),
+            OrderedFloat(velocity),
+        )
+    }
+}
+
+impl<E> Env for HideVelocity<E>
+where
+    E: Env
+        + EnvProperties<
+            Observation = CartPoleObservation,
+            ObservationSpace = BoxR<CartPoleObservation>,
+        >,
+{
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        self.env
+            .step(action)
+            .map_observation(|observation| Self::hide(&observation))
+    }
+
+    /// Resets the wrapped environment, sampling the position and angle within the bounds given by
+    /// `options`, and both velocities within `±0.05`.
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let options = options.map(|BoxR { low, high }| {
+            BoxR::new(
+                Self::reveal(&low, -INITIAL_VELOCITY_BOUND),
+                Self::reveal(&high, INITIAL_VELOCITY_BOUND),
+            )
+        });
+
+        let (observation, info) = self.env.reset(seed, return_info, options);
+        (Self::hide(&observation), info)
+    }
+
+    /// Resets the wrapped environment to the given position and angle, with both velocities at
+    /// rest.
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        Self::hide(&self.env.reset_to_state(Self::reveal(&state, 0.)))
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E> EnvProperties for HideVelocity<E>
+where
+    E: Env
+        + EnvProperties<
+            Observation = CartPoleObservation,
+            ObservationSpace = BoxR<CartPoleObservation>,
+        >,
+{
+    type Observation = Vec<f64>;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = BoxR<Vec<f64>>;
+
+    forward_env_properties!(
+        E;
+        spec,
+        metadata,
+        rand_random,
+        render_mode,
+        reward_range,
+        episode_length,
+        is_terminated,
+        is_truncated,
+        step_count,
+        action_space
+    );
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HideVelocity;
+    use crate::{
+        core::{Env, EnvProperties},
+        envs::classical_control::cartpole::{CartPoleAction, CartPoleEnv},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_hidden_velocities_when_stepping_then_observations_follow_full_physics() {
+        let mut env = HideVelocity::new(CartPoleEnv::new(RenderMode::None));
+        let mut full = CartPoleEnv::new(RenderMode::None);
+
+        let (observation, _) = env.reset(Some(0), false, None);
+        let (state, _) = full.reset(Some(0), false, None);
+        assert_eq!(observation, vec![state.x(), state.theta()]);
+        assert_eq!(env.observation_space().low.len(), 2);
+
+        for action in [
+            CartPoleAction::Right,
+            CartPoleAction::Right,
+            CartPoleAction::Left,
+        ] {
+            let observation = env.step(action).observation;
+            let state = full.step(action).observation;
+
+            assert_eq!(observation.len(), 2);
+            assert_eq!(observation, vec![state.x(), state.theta()]);
+            assert_eq!(env.env.state, state);
+        }
+    }
+}
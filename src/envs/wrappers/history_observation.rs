@@ -0,0 +1,126 @@
+use std::collections::VecDeque;
+
+use serde::Serialize;
+
+use super::{forward_env_properties, frame_stack::FrameStack};
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::BoxR,
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// A wrapper which observes the last `n` observations of an environment already observed as
+/// vectors, concatenated from oldest to newest.
+///
+/// Unlike [`FrameStack`], to which the bookkeeping is delegated, only environments observed as
+/// `Vec<f64>` can be wrapped, so no conversions take place. After a reset, the initial
+/// observation fills the whole history.
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryObservation<E> {
+    /// The frame stack wrapping the environment, which holds the history.
+    pub env: FrameStack<E>,
+    metadata: Metadata<Self>,
+}
+
+impl<E> HistoryObservation<E>
+where
+    E: Env + EnvProperties<Observation = Vec<f64>, ObservationSpace = BoxR<Vec<f64>>>,
+{
+    /// Wraps the given environment, observing its last `n` observations.
+    pub fn new(env: E, n: usize) -> Self {
+        let env = FrameStack::new(env, n);
+        let metadata = env.metadata().cast();
+
+        Self { env, metadata }
+    }
+
+    /// The number of observations held in the history.
+    pub fn n(&self) -> usize {
+        self.env.n
+    }
+
+    /// The observations currently held, ordered from oldest to newest.
+    pub fn history(&self) -> &VecDeque<Vec<f64>> {
+        self.env.frames()
+    }
+}
+
+impl<E> Env for HistoryObservation<E>
+where
+    E: Env + EnvProperties<Observation = Vec<f64>, ObservationSpace = BoxR<Vec<f64>>>,
+{
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        self.env.step(action)
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        self.env.reset(seed, return_info, options)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.env.reset_to_state(state)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E> EnvProperties for HistoryObservation<E>
+where
+    E: Env + EnvProperties<Observation = Vec<f64>, ObservationSpace = BoxR<Vec<f64>>>,
+{
+    type Observation = Vec<f64>;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = BoxR<Vec<f64>>;
+
+    forward_env_properties!(FrameStack<E>);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HistoryObservation;
+    use crate::{
+        core::{Env, EnvProperties},
+        envs::{
+            classical_control::mountain_car::{MountainCarAction, MountainCarEnv},
+            wrappers::flatten_observation::FlattenObservation,
+        },
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_history_of_three_when_stepping_then_observation_is_three_times_as_long() {
+        let mut env = HistoryObservation::new(
+            FlattenObservation::new(MountainCarEnv::new(RenderMode::None)),
+            3,
+        );
+        let (initial, _) = env.reset(Some(0), false, None);
+        assert_eq!(initial.len(), 6);
+        assert_eq!(initial[0..2], initial[4..6]);
+
+        let observation = env.step(MountainCarAction::Forward).observation;
+
+        assert_eq!(observation.len(), 6);
+        assert_eq!(env.observation_space().high.len(), 6);
+        assert_eq!(observation[0..4], initial[0..4]);
+        assert_eq!(env.history().len(), env.n());
+        assert_eq!(observation[4..6], env.history()[2][..]);
+    }
+}
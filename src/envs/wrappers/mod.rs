@@ -0,0 +1,116 @@
+/// Holds the wrapper responsible for applying each action several times.
+pub mod action_repeat;
+/// Holds the wrapper responsible for converting structured observations into vectors.
+pub mod flatten_observation;
+/// Holds the wrapper responsible for concatenating the most recent observations.
+pub mod frame_stack;
+/// Holds the wrapper responsible for hiding the velocities of cart pole observations.
+pub mod hide_velocity;
+/// Holds the wrapper responsible for concatenating the most recent vector observations.
+pub mod history_observation;
+/// Holds the wrapper responsible for logging the statistics of each episode to a CSV file.
+pub mod monitor;
+/// Holds the wrapper responsible for normalizing observations using running statistics.
+pub mod normalize_observation;
+/// Holds the wrapper responsible for normalizing rewards by the spread of the discounted return.
+pub mod normalize_reward;
+/// Holds the wrapper responsible for requiring a reset before each episode.
+pub mod order_enforcing;
+/// Holds the wrapper responsible for checking that an environment honours its specification.
+pub mod passive_env_checker;
+/// Holds the wrapper responsible for accumulating the reward and length of each episode.
+pub mod record_episode_statistics;
+/// Holds the wrapper responsible for rescaling actions onto the range of the environment.
+pub mod rescale_action;
+/// Holds the wrapper responsible for randomly repeating the previous action.
+pub mod sticky_action;
+/// Holds the wrapper responsible for truncating episodes after a number of steps.
+pub mod time_limit;
+/// Holds the wrapper responsible for applying a function to each observation.
+pub mod transform_observation;
+/// Holds the wrapper responsible for applying a function to each reward.
+pub mod transform_reward;
+
+/// Implements the methods of [`EnvProperties`](crate::core::EnvProperties) for a wrapper by
+/// forwarding them to the environment it holds in its `env` field, of the given type.
+///
+/// The metadata is read from the wrapper's own `metadata` field, as it is cast to the wrapper's
+/// type. Without a list of methods, every method is implemented; otherwise only the listed ones
+/// are, leaving the remaining ones to be implemented by hand.
+macro_rules! forward_env_properties {
+    ($inner:ty) => {
+        $crate::envs::wrappers::forward_env_properties!(
+            $inner;
+            spec,
+            metadata,
+            rand_random,
+            render_mode,
+            reward_range,
+            episode_length,
+            is_terminated,
+            is_truncated,
+            step_count,
+            action_space,
+            observation_space
+        );
+    };
+    ($inner:ty; $($method:ident),+ $(,)?) => {
+        $($crate::envs::wrappers::forward_env_properties!(@ $inner, $method);)+
+    };
+    (@ $inner:ty, spec) => {
+        fn spec() -> $crate::core::EnvSpec {
+            <$inner as $crate::core::EnvProperties>::spec()
+        }
+    };
+    (@ $inner:ty, metadata) => {
+        fn metadata(&self) -> &$crate::utils::custom::structs::Metadata<Self> {
+            &self.metadata
+        }
+    };
+    (@ $inner:ty, rand_random) => {
+        fn rand_random(&self) -> &rand_pcg::Pcg64 {
+            self.env.rand_random()
+        }
+    };
+    (@ $inner:ty, render_mode) => {
+        fn render_mode(&self) -> &$crate::utils::renderer::RenderMode {
+            self.env.render_mode()
+        }
+    };
+    (@ $inner:ty, reward_range) => {
+        fn reward_range(&self) -> &$crate::core::RewardRange {
+            self.env.reward_range()
+        }
+    };
+    (@ $inner:ty, episode_length) => {
+        fn episode_length(&self) -> Option<usize> {
+            self.env.episode_length()
+        }
+    };
+    (@ $inner:ty, is_terminated) => {
+        fn is_terminated(&self) -> bool {
+            self.env.is_terminated()
+        }
+    };
+    (@ $inner:ty, is_truncated) => {
+        fn is_truncated(&self) -> bool {
+            self.env.is_truncated()
+        }
+    };
+    (@ $inner:ty, step_count) => {
+        fn step_count(&self) -> usize {
+            self.env.step_count()
+        }
+    };
+    (@ $inner:ty, action_space) => {
+        fn action_space(&self) -> &Self::ActionSpace {
+            self.env.action_space()
+        }
+    };
+    (@ $inner:ty, observation_space) => {
+        fn observation_space(&self) -> &Self::ObservationSpace {
+            self.env.observation_space()
+        }
+    };
+}
+pub(crate) use forward_env_properties;
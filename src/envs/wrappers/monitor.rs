@@ -0,0 +1,245 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use derivative::Derivative;
+use log::warn;
+use ordered_float::OrderedFloat;
+use serde::Serialize;
+
+use super::{forward_env_properties, record_episode_statistics::EpisodeStats};
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::BoxR,
+    utils::{
+        custom::{structs::Metadata, types::O64},
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// The header row of the files written by [`Monitor`].
+const MONITOR_HEADER: &str = "episode,total_reward,episode_length,timestamp_ms";
+
+/// A wrapper which logs the statistics of every completed episode to a CSV file.
+///
+/// Each row holds the number of the episode, counting from 1 or continuing from the last episode
+/// of the file appended to, its total reward, its length and the time it completed at in
+/// milliseconds since the Unix epoch. Rows are buffered and flushed
+/// once the wrapper is dropped. Clones of the wrapper write to the same file.
+#[derive(Derivative, Serialize)]
+#[derivative(Debug, Clone(bound = "E: Clone"))]
+pub struct Monitor<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    episode_return: O64,
+    episode_length: usize,
+    stats: Vec<EpisodeStats>,
+    /// The number of the last episode already recorded in the file before wrapping.
+    previous_episodes: usize,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    writer: Arc<Mutex<BufWriter<File>>>,
+    metadata: Metadata<Self>,
+}
+
+impl<E: Env> Monitor<E> {
+    /// Wraps the given environment, logging its episodes to the file at the given path.
+    ///
+    /// Unless `append` is set, the file is truncated. Otherwise, episodes are numbered following
+    /// the last episode recorded in the file. A header row is written whenever the file starts
+    /// out empty.
+    pub fn new(env: E, path: &Path, append: bool) -> Result<Self, io::Error> {
+        let previous_episodes = if append && path.exists() {
+            Self::last_episode(&fs::read_to_string(path)?)
+        } else {
+            0
+        };
+
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(path)?;
+        let is_empty = file.metadata()?.len() == 0;
+
+        let mut writer = BufWriter::new(file);
+        if is_empty {
+            writeln!(writer, "{}", MONITOR_HEADER)?;
+        }
+
+        let metadata = env.metadata().cast();
+
+        Ok(Self {
+            env,
+            episode_return: OrderedFloat(0.),
+            episode_length: 0,
+            stats: Vec::new(),
+            previous_episodes,
+            writer: Arc::new(Mutex::new(writer)),
+            metadata,
+        })
+    }
+
+    /// Provides the statistics of every episode completed by this wrapper, in chronological order.
+    pub fn stats(&self) -> Vec<EpisodeStats> {
+        self.stats.clone()
+    }
+
+    /// Finds the number of the last episode recorded in the given contents of a file, being `0`
+    /// when no episode was recorded.
+    fn last_episode(contents: &str) -> usize {
+        contents
+            .lines()
+            .rev()
+            .find_map(|line| line.split(',').next()?.parse().ok())
+            .unwrap_or(0)
+    }
+
+    fn log(&self, stats: EpisodeStats) -> Result<(), io::Error> {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis());
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|_| io::Error::other("The writer of the monitor was poisoned."))?;
+
+        writeln!(
+            writer,
+            "{},{},{},{}",
+            self.previous_episodes + self.stats.len(),
+            stats.total_reward,
+            stats.length,
+            timestamp_ms
+        )
+    }
+}
+
+impl<E> Drop for Monitor<E> {
+    fn drop(&mut self) {
+        if let Ok(mut writer) = self.writer.lock() {
+            if let Err(err) = writer.flush() {
+                warn!(
+                    "Failed to flush the episodes logged by the monitor: {}",
+                    err
+                );
+            }
+        }
+    }
+}
+
+impl<E: Env> Env for Monitor<E> {
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let action_reward = self.env.step(action);
+
+        self.episode_return += action_reward.reward;
+        self.episode_length += 1;
+
+        if action_reward.done {
+            let stats = EpisodeStats {
+                total_reward: self.episode_return,
+                length: self.episode_length,
+                terminated: !action_reward.truncated,
+                truncated: action_reward.truncated,
+            };
+            self.stats.push(stats);
+            if let Err(err) = self.log(stats) {
+                warn!("Failed to log an episode to the monitor: {}", err);
+            }
+            self.episode_return = OrderedFloat(0.);
+            self.episode_length = 0;
+        }
+
+        action_reward
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        self.episode_return = OrderedFloat(0.);
+        self.episode_length = 0;
+        self.env.reset(seed, return_info, options)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.episode_return = OrderedFloat(0.);
+        self.episode_length = 0;
+        self.env.reset_to_state(state)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E: Env> EnvProperties for Monitor<E> {
+    type Observation = E::Observation;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(E);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{Monitor, MONITOR_HEADER};
+    use crate::{
+        core::Env,
+        envs::{
+            classical_control::mountain_car::{MountainCarAction, MountainCarEnv},
+            wrappers::time_limit::TimeLimit,
+        },
+        utils::renderer::RenderMode,
+    };
+
+    fn run_episodes(path: &std::path::Path, append: bool, episodes: usize) {
+        let env = TimeLimit::new(MountainCarEnv::new(RenderMode::None), 3);
+        let mut env = Monitor::new(env, path, append).unwrap();
+
+        for _ in 0..episodes {
+            env.reset(Some(0), false, None);
+            while !env.step(MountainCarAction::Neutral).done {}
+        }
+
+        assert_eq!(env.stats().len(), episodes);
+    }
+
+    #[test]
+    fn given_completed_episodes_when_monitor_dropped_then_csv_holds_a_row_per_episode() {
+        let path = std::env::temp_dir().join("gym_rs_monitor.csv");
+
+        run_episodes(&path, false, 2);
+        run_episodes(&path, true, 1);
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let rows: Vec<Vec<&str>> = contents
+            .lines()
+            .map(|line| line.split(',').collect())
+            .collect();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0].join(","), MONITOR_HEADER);
+        for (row, episode) in rows[1..].iter().zip(["1", "2", "3"]) {
+            assert_eq!(&row[..3], &[episode, "-3", "3"]);
+            assert!(row[3].parse::<u128>().unwrap() > 0);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::BoxR,
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+        running_mean_std::RunningMeanStd,
+    },
+};
+
+/// A wrapper which normalizes each element of the observations to have zero mean and unit
+/// variance, using statistics computed over all observations seen so far.
+#[derive(Debug, Clone, Serialize)]
+pub struct NormalizeObservation<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// The running statistics of the observations.
+    pub stats: RunningMeanStd,
+    /// Indicates whether new observations should update the running statistics.
+    ///
+    /// Disabling this freezes the statistics, e.g. after a warm-up period.
+    pub update: bool,
+    /// A small value added to the standard deviation to avoid dividing by zero.
+    pub epsilon: f64,
+    metadata: Metadata<Self>,
+}
+
+impl<E: Env> NormalizeObservation<E>
+where
+    E::Observation: From<Vec<f64>>,
+{
+    /// Wraps the given environment, normalizing observations holding `shape` elements.
+    pub fn new(env: E, shape: usize) -> Self {
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            stats: RunningMeanStd::new(shape),
+            update: true,
+            epsilon: 1e-8,
+            metadata,
+        }
+    }
+
+    fn normalize(&mut self, observation: E::Observation) -> E::Observation {
+        let values: Vec<f64> = observation.into();
+        if self.update {
+            self.stats.update(&values);
+        }
+
+        values
+            .iter()
+            .zip(self.stats.mean())
+            .zip(self.stats.std())
+            .map(|((value, mean), std)| (value - mean) / (std + self.epsilon))
+            .collect::<Vec<f64>>()
+            .into()
+    }
+}
+
+impl<E: Env> Env for NormalizeObservation<E>
+where
+    E::Observation: From<Vec<f64>>,
+{
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let ActionReward {
+            observation,
+            reward,
+            done,
+            truncated,
+            info,
+        } = self.env.step(action);
+
+        ActionReward {
+            observation: self.normalize(observation),
+            reward,
+            done,
+            truncated,
+            info,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (observation, info) = self.env.reset(seed, return_info, options);
+        (self.normalize(observation), info)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        let observation = self.env.reset_to_state(state);
+        self.normalize(observation)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E: Env> EnvProperties for NormalizeObservation<E>
+where
+    E::Observation: From<Vec<f64>>,
+{
+    type Observation = E::Observation;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(E);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NormalizeObservation;
+    use crate::{
+        core::Env,
+        envs::classical_control::cartpole::{CartPoleAction, CartPoleEnv},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_first_observation_when_reset_called_then_normalized_observation_is_zero() {
+        let mut env = NormalizeObservation::new(CartPoleEnv::new(RenderMode::None), 4);
+
+        let (observation, _) = env.reset(Some(0), false, None);
+
+        assert_eq!(Vec::from(observation), vec![0.; 4]);
+        assert_eq!(env.stats.count(), 1);
+    }
+
+    #[test]
+    fn given_frozen_statistics_when_stepping_then_statistics_are_not_updated() {
+        let mut env = NormalizeObservation::new(CartPoleEnv::new(RenderMode::None), 4);
+        env.reset(Some(0), false, None);
+        env.step(CartPoleAction::Left);
+        env.update = false;
+
+        env.step(CartPoleAction::Right);
+
+        assert_eq!(env.stats.count(), 2);
+    }
+}
@@ -0,0 +1,132 @@
+use ordered_float::OrderedFloat;
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::BoxR,
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+        running_mean_std::RunningMeanStdScalar,
+    },
+};
+
+/// A wrapper which scales rewards by a running estimate of the standard deviation of the
+/// discounted return.
+///
+/// The discounted return is accumulated as `G = gamma * G + reward` and restarted on each reset,
+/// while the returned reward is `reward / sqrt(var(G) + epsilon)`.
+#[derive(Debug, Clone, Serialize)]
+pub struct NormalizeReward<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// The discount factor applied when accumulating the return.
+    pub gamma: f64,
+    /// A small value added to the variance to avoid dividing by zero.
+    pub epsilon: f64,
+    discounted_return: f64,
+    stats: RunningMeanStdScalar,
+    metadata: Metadata<Self>,
+}
+
+impl<E: Env> NormalizeReward<E> {
+    /// Wraps the given environment, discounting returns by `gamma`.
+    pub fn new(env: E, gamma: f64, epsilon: f64) -> Self {
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            gamma,
+            epsilon,
+            discounted_return: 0.,
+            stats: RunningMeanStdScalar::new(),
+            metadata,
+        }
+    }
+
+    /// The running statistics of the discounted return.
+    pub fn stats(&self) -> &RunningMeanStdScalar {
+        &self.stats
+    }
+}
+
+impl<E: Env> Env for NormalizeReward<E> {
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let action_reward = self.env.step(action);
+
+        self.discounted_return = self.gamma * self.discounted_return + action_reward.reward.0;
+        self.stats.update(self.discounted_return);
+
+        let reward = action_reward.reward.0 / (self.stats.var() + self.epsilon).sqrt();
+
+        ActionReward {
+            reward: OrderedFloat(reward),
+            ..action_reward
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        self.discounted_return = 0.;
+        self.env.reset(seed, return_info, options)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.discounted_return = 0.;
+        self.env.reset_to_state(state)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E: Env> EnvProperties for NormalizeReward<E> {
+    type Observation = E::Observation;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(E);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NormalizeReward;
+    use crate::{
+        core::Env,
+        envs::classical_control::mountain_car::{MountainCarAction, MountainCarEnv},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_constant_reward_when_stepping_many_episodes_then_normalized_reward_converges() {
+        let mut env = NormalizeReward::new(MountainCarEnv::new(RenderMode::None), 0.99, 1e-8);
+
+        let mut rewards = vec![];
+        for _ in 0..200 {
+            env.reset(Some(0), false, None);
+            for _ in 0..50 {
+                env.step(MountainCarAction::Neutral);
+            }
+            rewards.push(env.step(MountainCarAction::Neutral).reward.0);
+        }
+
+        let last = rewards[rewards.len() - 1];
+        assert!(last < 0.);
+        assert!((last - rewards[rewards.len() - 2]).abs() < 1e-3);
+        assert_eq!(env.stats().count(), 200 * 51);
+    }
+}
@@ -0,0 +1,117 @@
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::BoxR,
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// A wrapper which enforces that the environment is reset before each episode is stepped through.
+///
+/// Stepping before the first reset, or after an episode has ended without resetting, panics.
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderEnforcing<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// Indicates whether the environment has been reset since the last episode ended.
+    pub has_reset: bool,
+    metadata: Metadata<Self>,
+}
+
+impl<E: Env> OrderEnforcing<E> {
+    /// Wraps the given environment, which must be reset before it can be stepped.
+    pub fn new(env: E) -> Self {
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            has_reset: false,
+            metadata,
+        }
+    }
+}
+
+impl<E: Env> Env for OrderEnforcing<E> {
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        assert!(self.has_reset, "Cannot call step() before reset()");
+
+        let action_reward = self.env.step(action);
+        if action_reward.done {
+            self.has_reset = false;
+        }
+
+        action_reward
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        self.has_reset = true;
+        self.env.reset(seed, return_info, options)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.has_reset = true;
+        self.env.reset_to_state(state)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E: Env> EnvProperties for OrderEnforcing<E> {
+    type Observation = E::Observation;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(E);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OrderEnforcing;
+    use crate::{
+        core::Env,
+        envs::{
+            classical_control::mountain_car::{MountainCarAction, MountainCarEnv},
+            wrappers::time_limit::TimeLimit,
+        },
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_reset_environment_when_stepping_until_done_then_reset_is_required_again() {
+        let mut env = OrderEnforcing::new(TimeLimit::new(MountainCarEnv::new(RenderMode::None), 2));
+        env.reset(Some(0), false, None);
+
+        env.step(MountainCarAction::Neutral);
+        assert!(env.has_reset);
+        env.step(MountainCarAction::Neutral);
+
+        assert!(!env.has_reset);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot call step() before reset()")]
+    fn given_environment_not_reset_when_stepping_then_panics() {
+        let mut env = OrderEnforcing::new(MountainCarEnv::new(RenderMode::None));
+
+        env.step(MountainCarAction::Neutral);
+    }
+}
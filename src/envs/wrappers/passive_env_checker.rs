@@ -0,0 +1,208 @@
+use log::warn;
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::{BoxR, Space},
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// A wrapper which checks that the wrapped environment honours its own specification on every
+/// step, without altering its behaviour.
+///
+/// The following are checked:
+///     1. The reward lies within the reward range of the environment.
+///     2. The observation lies within the observation space of the environment.
+///     3. A truncated episode is also reported as done.
+///     4. The environment is not stepped after an episode ended without being reset.
+///
+/// Violations are logged as warnings and recorded, or panic if the checker is strict.
+#[derive(Debug, Clone, Serialize)]
+pub struct PassiveEnvChecker<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// Indicates whether violations panic rather than being logged.
+    pub strict: bool,
+    /// The description of every violation found so far.
+    pub violations: Vec<String>,
+    episode_ended: bool,
+    metadata: Metadata<Self>,
+}
+
+impl<E: Env> PassiveEnvChecker<E> {
+    /// Wraps the given environment, logging violations if `strict` is unset and panicking on them
+    /// otherwise.
+    pub fn new(env: E, strict: bool) -> Self {
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            strict,
+            violations: Vec::new(),
+            episode_ended: false,
+            metadata,
+        }
+    }
+
+    fn report(&mut self, violation: String) {
+        if self.strict {
+            panic!("{}", violation);
+        }
+
+        warn!("{}", violation);
+        self.violations.push(violation);
+    }
+}
+
+impl<E: Env> Env for PassiveEnvChecker<E>
+where
+    E::Observation: PartialOrd + Clone,
+    E::ObservationSpace: Space<E::Observation>,
+{
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        if self.episode_ended {
+            self.report(format!(
+                "{} was stepped after its episode ended without being reset.",
+                E::spec().id
+            ));
+        }
+
+        let action_reward = self.env.step(action);
+
+        let reward_range = self.env.reward_range();
+        if action_reward.reward < reward_range.lower_bound()
+            || action_reward.reward > reward_range.upper_bound()
+        {
+            let violation = format!(
+                "{} produced the reward {}, which lies outside its reward range [{}, {}].",
+                E::spec().id,
+                action_reward.reward,
+                reward_range.lower_bound(),
+                reward_range.upper_bound()
+            );
+            self.report(violation);
+        }
+        if !self
+            .env
+            .observation_space_contains(&action_reward.observation)
+        {
+            self.report(format!(
+                "{} produced an observation outside its observation space.",
+                E::spec().id
+            ));
+        }
+        if action_reward.truncated && !action_reward.done {
+            self.report(format!(
+                "{} truncated an episode without reporting it as done.",
+                E::spec().id
+            ));
+        }
+
+        self.episode_ended = action_reward.done;
+
+        action_reward
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        self.episode_ended = false;
+        let (observation, info) = self.env.reset(seed, return_info, options);
+
+        if !self.env.observation_space_contains(&observation) {
+            self.report(format!(
+                "{} was reset to an observation outside its observation space.",
+                E::spec().id
+            ));
+        }
+
+        (observation, info)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.episode_ended = false;
+        self.env.reset_to_state(state)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E: Env> EnvProperties for PassiveEnvChecker<E> {
+    type Observation = E::Observation;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(E);
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::PassiveEnvChecker;
+    use crate::{
+        core::Env,
+        envs::classical_control::cartpole::{CartPoleAction, CartPoleEnv, CartPoleObservation},
+        utils::renderer::RenderMode,
+    };
+
+    fn displaced_cart() -> CartPoleObservation {
+        CartPoleObservation::new(
+            OrderedFloat(100.),
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+            OrderedFloat(0.),
+        )
+    }
+
+    #[test]
+    fn given_well_behaved_episode_when_stepped_then_no_violations_are_recorded() {
+        let mut env = PassiveEnvChecker::new(CartPoleEnv::new(RenderMode::None), false);
+        env.reset(Some(0), false, None);
+
+        while !env.step(CartPoleAction::Right).done {}
+
+        assert!(env.violations.is_empty());
+    }
+
+    #[test]
+    fn given_out_of_space_state_when_stepped_twice_then_violations_are_recorded() {
+        let mut env = PassiveEnvChecker::new(CartPoleEnv::new(RenderMode::None), false);
+        env.reset(Some(0), false, None);
+        env.env.state = displaced_cart();
+
+        assert!(env.step(CartPoleAction::Right).done);
+        env.step(CartPoleAction::Right);
+
+        assert_eq!(env.violations.len(), 3);
+        assert!(env.violations[0].contains("outside its observation space"));
+        assert!(env.violations[1].contains("after its episode ended"));
+    }
+
+    #[test]
+    #[should_panic(expected = "outside its observation space")]
+    fn given_strict_checker_when_violation_occurs_then_panics() {
+        let mut env = PassiveEnvChecker::new(CartPoleEnv::new(RenderMode::None), true);
+        env.reset(Some(0), false, None);
+        env.env.state = displaced_cart();
+
+        env.step(CartPoleAction::Right);
+    }
+}
@@ -0,0 +1,216 @@
+use ordered_float::OrderedFloat;
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::BoxR,
+    utils::{
+        custom::{structs::Metadata, types::O64},
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// Describes the outcome of a completed episode.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EpisodeStats {
+    /// The sum of all rewards produced during the episode.
+    pub total_reward: O64,
+    /// The number of steps taken during the episode.
+    pub length: usize,
+    /// Whether the episode ended because a terminal state was reached.
+    pub terminated: bool,
+    /// Whether the episode ended because it reached its step limit.
+    pub truncated: bool,
+}
+
+/// The info produced by [`RecordEpisodeStatistics`] on every step.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct EpisodeStatisticsInfo<I> {
+    /// The info produced by the wrapped environment.
+    pub info: Option<I>,
+    /// The statistics of the episode which completed during this step, if any.
+    pub episode: Option<EpisodeStats>,
+}
+
+/// A wrapper which keeps track of the cumulative reward and length of every completed episode.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordEpisodeStatistics<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// The sum of rewards produced since the start of the current episode.
+    pub episode_return: O64,
+    /// The number of steps taken since the start of the current episode.
+    pub episode_length: usize,
+    /// The statistics of every completed episode, in chronological order.
+    pub history: Vec<EpisodeStats>,
+    metadata: Metadata<Self>,
+}
+
+impl<E: Env> RecordEpisodeStatistics<E> {
+    /// Wraps the given environment, recording statistics about each of its episodes.
+    pub fn new(env: E) -> Self {
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            episode_return: OrderedFloat(0.),
+            episode_length: 0,
+            history: Vec::new(),
+            metadata,
+        }
+    }
+
+    /// Provides the number of completed episodes.
+    pub fn episode_count(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Provides the mean total reward over all completed episodes, if any.
+    pub fn mean_reward(&self) -> Option<f64> {
+        self.mean_of(|stats| stats.total_reward.into_inner())
+    }
+
+    /// Provides the mean length over all completed episodes, if any.
+    pub fn mean_length(&self) -> Option<f64> {
+        self.mean_of(|stats| stats.length as f64)
+    }
+
+    fn mean_of(&self, value: impl Fn(&EpisodeStats) -> f64) -> Option<f64> {
+        if self.history.is_empty() {
+            None
+        } else {
+            Some(self.history.iter().map(value).sum::<f64>() / self.history.len() as f64)
+        }
+    }
+}
+
+impl<E: Env> Env for RecordEpisodeStatistics<E> {
+    type Action = E::Action;
+    type Info = EpisodeStatisticsInfo<E::Info>;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let ActionReward {
+            observation,
+            reward,
+            done,
+            truncated,
+            info,
+        } = self.env.step(action);
+
+        self.episode_return += reward;
+        self.episode_length += 1;
+
+        let episode = if done {
+            let stats = EpisodeStats {
+                total_reward: self.episode_return,
+                length: self.episode_length,
+                terminated: !truncated,
+                truncated,
+            };
+            self.history.push(stats);
+            self.episode_return = OrderedFloat(0.);
+            self.episode_length = 0;
+            Some(stats)
+        } else {
+            None
+        };
+
+        ActionReward {
+            observation,
+            reward,
+            done,
+            truncated,
+            info: Some(EpisodeStatisticsInfo { info, episode }),
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        self.episode_return = OrderedFloat(0.);
+        self.episode_length = 0;
+        self.env.reset(seed, return_info, options)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.episode_return = OrderedFloat(0.);
+        self.episode_length = 0;
+        self.env.reset_to_state(state)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E: Env> EnvProperties for RecordEpisodeStatistics<E> {
+    type Observation = E::Observation;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(E);
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::{EpisodeStats, RecordEpisodeStatistics};
+    use crate::{
+        core::Env,
+        envs::{
+            classical_control::mountain_car::{MountainCarAction, MountainCarEnv},
+            wrappers::time_limit::TimeLimit,
+        },
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_completed_episodes_when_stepping_then_statistics_are_recorded() {
+        let mut env =
+            RecordEpisodeStatistics::new(TimeLimit::new(MountainCarEnv::new(RenderMode::None), 3));
+
+        for _ in 0..2 {
+            env.reset(Some(0), false, None);
+            for _ in 0..2 {
+                let info = env.step(MountainCarAction::Neutral).info.unwrap();
+                assert_eq!(info.episode, None);
+            }
+
+            let info = env.step(MountainCarAction::Neutral).info.unwrap();
+            assert_eq!(
+                info.episode,
+                Some(EpisodeStats {
+                    total_reward: OrderedFloat(-3.),
+                    length: 3,
+                    terminated: false,
+                    truncated: true,
+                })
+            );
+        }
+
+        assert_eq!(env.episode_count(), 2);
+        assert_eq!(env.mean_reward(), Some(-3.));
+        assert_eq!(env.mean_length(), Some(3.));
+    }
+
+    #[test]
+    fn given_no_completed_episodes_when_means_requested_then_none_returned() {
+        let mut env = RecordEpisodeStatistics::new(MountainCarEnv::new(RenderMode::None));
+        env.reset(Some(0), false, None);
+        env.step(MountainCarAction::Neutral);
+
+        assert_eq!(env.episode_count(), 0);
+        assert_eq!(env.mean_reward(), None);
+        assert_eq!(env.mean_length(), None);
+    }
+}
@@ -0,0 +1,151 @@
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::BoxR,
+    utils::{
+        custom::{structs::Metadata, util_fns::scale},
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// A wrapper which accepts continuous actions within `[min_action, max_action]` and linearly
+/// rescales them onto the action space of the wrapped environment.
+///
+/// This allows policies with bounded outputs, e.g. within `[-1, 1]`, to act on environments
+/// expecting a different range of forces.
+#[derive(Debug, Clone, Serialize)]
+pub struct RescaleAction<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// The smallest action accepted by the wrapper.
+    pub min_action: f64,
+    /// The largest action accepted by the wrapper.
+    pub max_action: f64,
+    action_space: BoxR<f64>,
+    metadata: Metadata<Self>,
+}
+
+impl<E> RescaleAction<E>
+where
+    E: Env<Action = f64> + EnvProperties<ActionSpace = BoxR<f64>>,
+{
+    /// Wraps the given environment, accepting actions within `[min_action, max_action]`.
+    pub fn new(env: E, min_action: f64, max_action: f64) -> Self {
+        assert!(
+            min_action < max_action,
+            "The smallest action ({min_action}) must be less than the largest ({max_action})."
+        );
+
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            min_action,
+            max_action,
+            action_space: BoxR::new(min_action, max_action),
+            metadata,
+        }
+    }
+
+    /// Maps an action of the wrapper onto the action space of the wrapped environment.
+    pub fn rescale(&self, action: f64) -> f64 {
+        let BoxR { low, high } = *self.env.action_space();
+        scale(action, self.min_action, self.max_action, low, high)
+    }
+}
+
+impl<E> Env for RescaleAction<E>
+where
+    E: Env<Action = f64> + EnvProperties<ActionSpace = BoxR<f64>>,
+{
+    type Action = f64;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        debug_assert!(
+            (self.min_action..=self.max_action).contains(&action),
+            "The action {action} lies outside [{}, {}].",
+            self.min_action,
+            self.max_action
+        );
+
+        let action = self.rescale(action);
+        self.env.step(action)
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        self.env.reset(seed, return_info, options)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.env.reset_to_state(state)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E> EnvProperties for RescaleAction<E>
+where
+    E: Env<Action = f64> + EnvProperties<ActionSpace = BoxR<f64>>,
+{
+    type Observation = E::Observation;
+    type ActionSpace = BoxR<f64>;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(
+        E;
+        spec,
+        metadata,
+        rand_random,
+        render_mode,
+        reward_range,
+        episode_length,
+        is_terminated,
+        is_truncated,
+        step_count,
+        observation_space
+    );
+
+    fn action_space(&self) -> &Self::ActionSpace {
+        &self.action_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RescaleAction;
+    use crate::{envs::classical_control::pendulum::PendulumEnv, utils::renderer::RenderMode};
+
+    #[test]
+    fn given_normalized_actions_when_rescaled_then_bounds_and_midpoint_map_onto_torque_range() {
+        let env = RescaleAction::new(PendulumEnv::new(RenderMode::None), -1., 1.);
+
+        assert_eq!(env.rescale(-1.), -2.);
+        assert_eq!(env.rescale(0.), 0.);
+        assert_eq!(env.rescale(1.), 2.);
+        assert_eq!(env.rescale(0.5), 1.);
+    }
+
+    #[test]
+    fn given_unit_interval_when_rescaled_then_lower_bound_maps_onto_smallest_torque() {
+        let env = RescaleAction::new(PendulumEnv::new(RenderMode::None), 0., 1.);
+
+        assert_eq!(env.rescale(0.), -2.);
+        assert_eq!(env.rescale(0.5), 0.);
+        assert_eq!(env.rescale(1.), 2.);
+    }
+}
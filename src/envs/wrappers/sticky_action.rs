@@ -0,0 +1,177 @@
+use derivative::Derivative;
+use rand::Rng;
+use rand_pcg::Pcg64;
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::BoxR,
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+        seeding::rand_random,
+    },
+};
+
+/// A wrapper which, with probability `p`, repeats the previously applied action instead of the
+/// one requested by the agent.
+///
+/// This follows the sticky actions evaluation protocol popularized by the Arcade Learning
+/// Environment, which prevents agents from exploiting deterministic action-response timing.
+#[derive(Derivative, Clone, Serialize)]
+#[derivative(Debug)]
+#[serde(bound = "E: Serialize")]
+pub struct StickyAction<E: Env> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// The probability of repeating the previous action.
+    pub p: f64,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    last_action: Option<E::Action>,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    rand_random: Pcg64,
+    metadata: Metadata<Self>,
+}
+
+impl<E: Env> StickyAction<E>
+where
+    E::Action: Clone,
+{
+    /// Wraps the given environment, repeating actions with probability `p`.
+    ///
+    /// The seed determines the generator deciding when actions are repeated, and is independent
+    /// from the one held by the wrapped environment.
+    pub fn new(env: E, p: f64, seed: Option<u64>) -> Self {
+        assert!(
+            (0. ..=1.).contains(&p),
+            "The probability of repeating an action must lie within [0, 1], got {p}."
+        );
+
+        let (rand_random, _) = rand_random(seed);
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            p,
+            last_action: None,
+            rand_random,
+            metadata,
+        }
+    }
+
+    /// The action most recently applied to the wrapped environment, if any since the last reset.
+    pub fn last_action(&self) -> Option<&E::Action> {
+        self.last_action.as_ref()
+    }
+}
+
+impl<E: Env> Env for StickyAction<E>
+where
+    E::Action: Clone,
+{
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let action = match self.last_action.take() {
+            Some(last_action) if self.rand_random.gen::<f64>() < self.p => last_action,
+            _ => action,
+        };
+
+        self.last_action = Some(action.clone());
+        self.env.step(action)
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        if seed.is_some() {
+            let (rand_random, _) = rand_random(seed);
+            self.rand_random = rand_random;
+        }
+        self.last_action = None;
+
+        self.env.reset(seed, return_info, options)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.last_action = None;
+        self.env.reset_to_state(state)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E: Env> EnvProperties for StickyAction<E>
+where
+    E::Action: Clone,
+{
+    type Observation = E::Observation;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(E);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StickyAction;
+    use crate::{
+        core::Env,
+        envs::classical_control::mountain_car::{MountainCarAction, MountainCarEnv},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_actions_differing_from_previous_when_stepping_then_substitution_rate_is_close_to_p() {
+        let mut env = StickyAction::new(MountainCarEnv::new(RenderMode::None), 0.25, Some(0));
+        env.reset(Some(0), false, None);
+
+        let steps = 10_000;
+        let mut substitutions = 0;
+        let mut previous_action = MountainCarAction::Backward;
+        for _ in 0..steps {
+            let action =
+                MountainCarAction::try_from((usize::from(previous_action) + 1) % 3).unwrap();
+            env.step(action);
+            previous_action = *env.last_action().unwrap();
+            if previous_action != action {
+                substitutions += 1;
+            }
+        }
+
+        let rate = substitutions as f64 / steps as f64;
+        assert!((rate - 0.25).abs() < 0.02, "rate was {rate}");
+    }
+
+    #[test]
+    fn given_reset_environment_when_stepping_then_requested_action_is_applied() {
+        let mut env = StickyAction::new(MountainCarEnv::new(RenderMode::None), 1., Some(0));
+        env.reset(Some(0), false, None);
+        env.step(MountainCarAction::Backward);
+
+        env.reset(Some(0), false, None);
+        env.step(MountainCarAction::Forward);
+
+        assert_eq!(env.last_action(), Some(&MountainCarAction::Forward));
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_probability_outside_unit_interval_when_constructed_then_panics() {
+        StickyAction::new(MountainCarEnv::new(RenderMode::None), 1.5, None);
+    }
+}
@@ -0,0 +1,193 @@
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties, EnvSpec},
+    spaces::BoxR,
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// A wrapper which truncates episodes once a given number of steps have been taken, regardless of
+/// the termination logic of the wrapped environment.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeLimit<E> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// The number of steps after which an episode is truncated.
+    pub max_steps: usize,
+    /// The number of steps taken since the last reset.
+    pub current_steps: usize,
+    metadata: Metadata<Self>,
+}
+
+impl<E: Env> TimeLimit<E> {
+    /// Wraps the given environment, truncating its episodes after `max_steps` steps.
+    pub fn new(env: E, max_steps: usize) -> Self {
+        let mut metadata: Metadata<Self> = env.metadata().cast();
+        metadata.max_episode_steps = Some(
+            metadata
+                .max_episode_steps
+                .map_or(max_steps, |length| length.min(max_steps)),
+        );
+
+        Self {
+            env,
+            max_steps,
+            current_steps: 0,
+            metadata,
+        }
+    }
+}
+
+impl<E: Env> Env for TimeLimit<E> {
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let mut action_reward = self.env.step(action);
+        self.current_steps += 1;
+
+        if self.current_steps >= self.max_steps && !self.env.is_terminated() {
+            action_reward.truncated = true;
+            action_reward.done = true;
+        }
+
+        action_reward
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        self.current_steps = 0;
+        self.env.reset(seed, return_info, options)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.current_steps = 0;
+        self.env.reset_to_state(state)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E: Env> EnvProperties for TimeLimit<E> {
+    type Observation = E::Observation;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(
+        E;
+        metadata,
+        rand_random,
+        render_mode,
+        reward_range,
+        is_terminated,
+        step_count,
+        action_space,
+        observation_space
+    );
+
+    /// Describes the unwrapped environment as registered, so its `max_episode_steps` ignores
+    /// `max_steps`. The limit in effect is given by [`EnvProperties::episode_length`] and by
+    /// `metadata().max_episode_steps`.
+    fn spec() -> EnvSpec {
+        E::spec()
+    }
+
+    fn episode_length(&self) -> Option<usize> {
+        Some(
+            self.env
+                .episode_length()
+                .map_or(self.max_steps, |length| length.min(self.max_steps)),
+        )
+    }
+
+    fn is_truncated(&self) -> bool {
+        !self.env.is_terminated()
+            && (self.env.is_truncated() || self.current_steps >= self.max_steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::TimeLimit;
+    use crate::{
+        core::{Env, EnvProperties},
+        envs::classical_control::mountain_car::{
+            MountainCarAction, MountainCarEnv, MountainCarObservation,
+        },
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_max_steps_when_stepping_then_truncation_occurs_exactly_at_max_steps() {
+        let mut env = TimeLimit::new(MountainCarEnv::new(RenderMode::None), 5);
+        env.reset(Some(0), false, None);
+
+        for _ in 0..4 {
+            let action_reward = env.step(MountainCarAction::Neutral);
+            assert!(!action_reward.truncated);
+            assert!(!action_reward.done);
+        }
+
+        let action_reward = env.step(MountainCarAction::Neutral);
+        assert!(action_reward.truncated);
+        assert!(action_reward.done);
+    }
+
+    #[test]
+    fn given_steps_taken_when_reset_called_then_step_counter_is_zero() {
+        let mut env = TimeLimit::new(MountainCarEnv::new(RenderMode::None), 5);
+        env.reset(Some(0), false, None);
+
+        for _ in 0..3 {
+            env.step(MountainCarAction::Neutral);
+        }
+        assert_eq!(env.current_steps, 3);
+
+        env.reset(Some(0), false, None);
+        assert_eq!(env.current_steps, 0);
+    }
+
+    #[test]
+    fn given_goal_reached_on_last_step_when_stepping_then_episode_is_terminated_not_truncated() {
+        let mut env = TimeLimit::new(MountainCarEnv::new(RenderMode::None), 1);
+        env.reset_to_state(MountainCarObservation::new(
+            OrderedFloat(0.49),
+            OrderedFloat(0.02),
+        ));
+
+        let action_reward = env.step(MountainCarAction::Forward);
+
+        assert!(action_reward.done);
+        assert!(!action_reward.truncated);
+        assert!(env.is_terminated());
+        assert!(!env.is_truncated());
+    }
+
+    #[test]
+    fn given_inner_episode_limit_when_reading_metadata_then_shorter_limit_is_reported() {
+        let env = TimeLimit::new(MountainCarEnv::new(RenderMode::None), 5);
+        assert_eq!(env.metadata().max_episode_steps, Some(5));
+        assert_eq!(env.metadata().max_episode_steps, env.episode_length());
+
+        let env = TimeLimit::new(MountainCarEnv::new(RenderMode::None), 1000);
+        assert_eq!(env.metadata().max_episode_steps, Some(200));
+        assert_eq!(env.metadata().max_episode_steps, env.episode_length());
+    }
+}
@@ -0,0 +1,213 @@
+use derivative::Derivative;
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties},
+    spaces::BoxR,
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// A wrapper which applies a user-provided function to every observation produced by the
+/// environment, turning it into a vector.
+///
+/// Since the function cannot be inverted, resetting with bounds or to a given state is not
+/// supported.
+#[derive(Derivative, Clone, Serialize)]
+#[derivative(Debug)]
+#[serde(bound = "E: Serialize")]
+pub struct TransformObservation<E, F> {
+    /// The environment being wrapped.
+    pub env: E,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    f: F,
+    observation_space: BoxR<Vec<f64>>,
+    metadata: Metadata<Self>,
+}
+
+impl<E, F> TransformObservation<E, F>
+where
+    E: Env + EnvProperties<ObservationSpace = BoxR<<E as EnvProperties>::Observation>>,
+    E::Observation: Clone,
+    F: Fn(E::Observation) -> Vec<f64>,
+{
+    /// Wraps the given environment, transforming its observations with `f`.
+    ///
+    /// As the range of `f` cannot be derived in general, `observation_space` must hold the bounds
+    /// of every transformed observation.
+    pub fn new(env: E, f: F, observation_space: BoxR<Vec<f64>>) -> Self {
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            f,
+            observation_space,
+            metadata,
+        }
+    }
+}
+
+impl<E> TransformObservation<E, fn(E::Observation) -> Vec<f64>>
+where
+    E: Env + EnvProperties<ObservationSpace = BoxR<<E as EnvProperties>::Observation>>,
+    E::Observation: Clone,
+{
+    /// Wraps the given environment, multiplying each element of its observations by `factor`.
+    ///
+    /// The bounds of the observation space are scaled alike, being swapped when `factor` is
+    /// negative.
+    pub fn scale(
+        env: E,
+        factor: f64,
+    ) -> TransformObservation<E, impl Fn(E::Observation) -> Vec<f64> + Clone> {
+        let f = move |observation: E::Observation| -> Vec<f64> {
+            observation
+                .into()
+                .into_iter()
+                .map(|value| value * factor)
+                .collect()
+        };
+
+        let BoxR { low, high } = env.observation_space().clone();
+        let (low, high) = (f(low), f(high));
+        let observation_space = if factor < 0. {
+            BoxR::from_bounds(high, low)
+        } else {
+            BoxR::from_bounds(low, high)
+        };
+
+        TransformObservation::new(env, f, observation_space)
+    }
+}
+
+impl<E, F> Env for TransformObservation<E, F>
+where
+    E: Env + EnvProperties<ObservationSpace = BoxR<<E as EnvProperties>::Observation>>,
+    E::Observation: Clone,
+    F: Fn(E::Observation) -> Vec<f64> + Clone,
+{
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let ActionReward {
+            observation,
+            reward,
+            done,
+            truncated,
+            info,
+        } = self.env.step(action);
+
+        ActionReward {
+            observation: (self.f)(observation),
+            reward,
+            done,
+            truncated,
+            info,
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        assert!(
+            options.is_none(),
+            "Bounds cannot be mapped back through the observation transform."
+        );
+
+        let (observation, info) = self.env.reset(seed, return_info, None);
+        ((self.f)(observation), info)
+    }
+
+    fn reset_to_state(&mut self, _state: Self::Observation) -> Self::Observation {
+        panic!("States cannot be mapped back through the observation transform.")
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E, F> EnvProperties for TransformObservation<E, F>
+where
+    E: Env + EnvProperties<ObservationSpace = BoxR<<E as EnvProperties>::Observation>>,
+    E::Observation: Clone,
+    F: Fn(E::Observation) -> Vec<f64> + Clone,
+{
+    type Observation = Vec<f64>;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = BoxR<Vec<f64>>;
+
+    forward_env_properties!(
+        E;
+        spec,
+        metadata,
+        rand_random,
+        render_mode,
+        reward_range,
+        episode_length,
+        is_terminated,
+        is_truncated,
+        step_count,
+        action_space
+    );
+
+    fn observation_space(&self) -> &Self::ObservationSpace {
+        &self.observation_space
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TransformObservation;
+    use crate::{
+        core::{Env, EnvProperties},
+        envs::classical_control::mountain_car::{
+            MountainCarAction, MountainCarEnv, MountainCarObservation,
+        },
+        spaces::{BoxR, Space},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_scaled_environment_when_stepping_then_observation_and_bounds_are_multiplied() {
+        let mut env = TransformObservation::scale(MountainCarEnv::new(RenderMode::None), -2.);
+        let (observation, _) = env.reset(Some(0), false, None);
+        assert_eq!(observation, vec![env.env.state.position.0 * -2., 0.]);
+
+        let action_reward = env.step(MountainCarAction::Forward);
+        let state = env.env.state;
+
+        assert_eq!(
+            action_reward.observation,
+            vec![state.position.0 * -2., state.velocity.0 * -2.]
+        );
+        assert_eq!(env.observation_space().low, vec![-1.2, -0.14]);
+        assert_eq!(env.observation_space().high, vec![2.4, 0.14]);
+        assert!(env.observation_space().contains(action_reward.observation));
+    }
+
+    #[test]
+    fn given_non_monotone_function_when_wrapped_then_given_observation_space_is_used() {
+        let env = TransformObservation::new(
+            MountainCarEnv::new(RenderMode::None),
+            |observation: MountainCarObservation| vec![observation.velocity.0.powi(2)],
+            BoxR::from_bounds(vec![0.], vec![0.07_f64.powi(2)]),
+        );
+
+        assert_eq!(env.observation_space().low, vec![0.]);
+        assert_eq!(env.observation_space().high, vec![0.07_f64.powi(2)]);
+    }
+}
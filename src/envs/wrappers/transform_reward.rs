@@ -0,0 +1,155 @@
+use derivative::Derivative;
+use ordered_float::OrderedFloat;
+use serde::Serialize;
+
+use super::forward_env_properties;
+use crate::{
+    core::{ActionReward, Env, EnvProperties, RewardRange},
+    spaces::BoxR,
+    utils::{
+        custom::{structs::Metadata, types::O64},
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// A wrapper which applies a user-provided function to every reward produced by the environment.
+#[derive(Derivative, Clone, Serialize)]
+#[derivative(Debug)]
+#[serde(bound = "E: Serialize")]
+pub struct TransformReward<E, F> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// Indicates whether the reward range of the wrapped environment should also be transformed.
+    pub transform_range: bool,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    f: F,
+    reward_range: RewardRange,
+    metadata: Metadata<Self>,
+}
+
+impl<E: Env, F: Fn(O64) -> O64> TransformReward<E, F> {
+    /// Wraps the given environment, transforming its rewards with `f`.
+    pub fn new(env: E, f: F, transform_range: bool) -> Self {
+        let range = env.reward_range();
+        let (lower_bound, upper_bound) = (f(range.lower_bound()), f(range.upper_bound()));
+        let reward_range =
+            RewardRange::new(lower_bound.min(upper_bound), lower_bound.max(upper_bound));
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            transform_range,
+            f,
+            reward_range,
+            metadata,
+        }
+    }
+}
+
+impl<E: Env> TransformReward<E, fn(O64) -> O64> {
+    /// Wraps the given environment, multiplying its rewards and reward range by `factor`.
+    pub fn scale(env: E, factor: f64) -> TransformReward<E, impl Fn(O64) -> O64 + Clone> {
+        TransformReward::new(env, move |reward| reward * OrderedFloat(factor), true)
+    }
+}
+
+impl<E: Env, F: Fn(O64) -> O64 + Clone> Env for TransformReward<E, F> {
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let action_reward = self.env.step(action);
+
+        ActionReward {
+            reward: (self.f)(action_reward.reward),
+            ..action_reward
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        self.env.reset(seed, return_info, options)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        self.env.reset_to_state(state)
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E: Env, F: Fn(O64) -> O64 + Clone> EnvProperties for TransformReward<E, F> {
+    type Observation = E::Observation;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(
+        E;
+        spec,
+        metadata,
+        rand_random,
+        render_mode,
+        episode_length,
+        is_terminated,
+        is_truncated,
+        step_count,
+        action_space,
+        observation_space
+    );
+
+    fn reward_range(&self) -> &RewardRange {
+        if self.transform_range {
+            &self.reward_range
+        } else {
+            self.env.reward_range()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::TransformReward;
+    use crate::{
+        core::Env,
+        envs::classical_control::mountain_car::{MountainCarAction, MountainCarEnv},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_scaled_environment_when_stepping_then_reward_is_multiplied() {
+        let mut env = TransformReward::scale(MountainCarEnv::new(RenderMode::None), 0.5);
+        env.reset(Some(0), false, None);
+
+        let action_reward = env.step(MountainCarAction::Neutral);
+
+        assert_eq!(action_reward.reward, OrderedFloat(-0.5));
+    }
+
+    #[test]
+    fn given_inverting_transform_when_stepping_then_reward_sign_is_flipped() {
+        let mut env = TransformReward::new(
+            MountainCarEnv::new(RenderMode::None),
+            |reward| -reward,
+            false,
+        );
+        env.reset(Some(0), false, None);
+
+        let action_reward = env.step(MountainCarAction::Neutral);
+
+        assert_eq!(action_reward.reward, OrderedFloat(1.));
+    }
+}
@@ -1,8 +1,13 @@
-use derive_new::new;
+use std::fmt::Debug;
+
+use rand::Rng;
 use serde::Serialize;
 
+use super::Space;
+use crate::utils::custom::{traits::Sample, types::O64};
+
 /// Defines a subspace created between two points.
-#[derive(Debug, Serialize, new, Clone)]
+#[derive(Debug, Serialize, Clone)]
 pub struct BoxR<T> {
     /// Defines the lower bound of the subspace where values less than what
     /// is inputted cannot exist.
@@ -11,3 +16,259 @@ pub struct BoxR<T> {
     /// inputted cannot exist.
     pub high: T,
 }
+
+impl<T: BoundedObservation + Debug> BoxR<T> {
+    /// Creates the subspace between the given bounds.
+    ///
+    /// Panics if any element of `low` is greater than the associated element of `high`, as such
+    /// a subspace holds no values and cannot be sampled from.
+    pub fn new(low: T, high: T) -> Self {
+        assert!(
+            low.is_within(&low, &high),
+            "BoxR::new: low ({:?}) must be <= high ({:?}) in every element",
+            low,
+            high
+        );
+
+        Self { low, high }
+    }
+}
+
+impl<T: Sample + Clone> BoxR<T> {
+    /// Draws a value uniformly between the bounds of the subspace.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> T {
+        T::sample_between(rng, Some(self.clone()))
+    }
+}
+
+impl BoxR<Vec<f64>> {
+    /// Creates a vector-valued subspace from the bounds of each of its elements.
+    ///
+    /// Panics if the bounds differ in length, or if any lower bound exceeds its upper bound.
+    pub fn from_bounds(low: Vec<f64>, high: Vec<f64>) -> Self {
+        assert_eq!(
+            low.len(),
+            high.len(),
+            "The lower and upper bounds must hold the same number of elements."
+        );
+
+        Self::new(low, high)
+    }
+}
+
+impl<T: Clamp> BoxR<T> {
+    /// Projects the value onto the subspace, replacing every element beyond the bounds by the
+    /// nearest bound.
+    pub fn clip(&self, value: T) -> T {
+        value.clamp_to(&self.low, &self.high)
+    }
+}
+
+/// Defines how a value is compared against the bounds of a [`BoxR`].
+///
+/// Structured observations should compare each of their fields against the
+/// associated fields of the bounds, rather than relying on their `PartialOrd`
+/// implementation which compares fields lexicographically.
+pub trait BoundedObservation {
+    /// Checks whether every element of the value lies between the given inclusive bounds.
+    fn is_within(&self, low: &Self, high: &Self) -> bool;
+}
+
+impl BoundedObservation for f64 {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        low <= self && self <= high
+    }
+}
+
+impl BoundedObservation for O64 {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        low <= self && self <= high
+    }
+}
+
+impl BoundedObservation for Vec<f64> {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        self.len() == low.len()
+            && self.len() == high.len()
+            && self
+                .iter()
+                .zip(low.iter().zip(high.iter()))
+                .all(|(value, (low, high))| value.is_within(low, high))
+    }
+}
+
+/// Defines how a value is projected onto the bounds of a [`BoxR`].
+///
+/// As with [`BoundedObservation`], structured observations should clamp each of their fields
+/// against the associated fields of the bounds.
+pub trait Clamp {
+    /// Clamps every element of the value between the given inclusive bounds.
+    fn clamp_to(self, low: &Self, high: &Self) -> Self;
+}
+
+impl Clamp for f64 {
+    fn clamp_to(self, low: &Self, high: &Self) -> Self {
+        self.clamp(*low, *high)
+    }
+}
+
+impl Clamp for O64 {
+    fn clamp_to(self, low: &Self, high: &Self) -> Self {
+        self.clamp(*low, *high)
+    }
+}
+
+impl<T> Space<T> for BoxR<T>
+where
+    T: BoundedObservation + PartialOrd + PartialEq,
+{
+    fn contains(&self, value: T) -> bool {
+        value.is_within(&self.low, &self.high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::BoxR;
+    use crate::{
+        core::EnvProperties,
+        envs::classical_control::{
+            acrobot::AcrobotEnv, cartpole::CartPoleEnv, mountain_car::MountainCarObservation,
+            pendulum::PendulumEnv,
+        },
+        spaces::Space,
+        utils::{renderer::RenderMode, seeding::rand_random},
+    };
+
+    #[test]
+    fn given_value_equal_to_bounds_when_contains_called_then_returns_true() {
+        let obj = BoxR::new(-1., 1.);
+
+        assert!(obj.contains(-1.));
+        assert!(obj.contains(1.));
+    }
+
+    #[test]
+    fn given_value_between_bounds_when_contains_called_then_returns_true() {
+        let obj = BoxR::new(-1., 1.);
+
+        assert!(obj.contains(0.));
+        assert!(obj.contains(0.5));
+    }
+
+    #[test]
+    fn given_value_outside_bounds_when_contains_called_then_returns_false() {
+        let obj = BoxR::new(-1., 1.);
+
+        assert!(!obj.contains(-1.5));
+        assert!(!obj.contains(1.0001));
+    }
+
+    #[test]
+    fn given_observation_with_single_field_outside_bounds_when_contains_called_then_returns_false()
+    {
+        let obj = BoxR::new(
+            MountainCarObservation::new(OrderedFloat(-1.2), OrderedFloat(-0.07)),
+            MountainCarObservation::new(OrderedFloat(0.6), OrderedFloat(0.07)),
+        );
+
+        assert!(obj.contains(MountainCarObservation::new(
+            OrderedFloat(0.),
+            OrderedFloat(0.07)
+        )));
+        assert!(!obj.contains(MountainCarObservation::new(
+            OrderedFloat(0.),
+            OrderedFloat(0.08)
+        )));
+    }
+
+    #[test]
+    fn given_observation_equal_to_env_bounds_when_contains_called_then_returns_true() {
+        let cartpole = CartPoleEnv::new(RenderMode::None);
+        let space = cartpole.observation_space();
+        assert!(space.contains(space.low));
+        assert!(space.contains(space.high));
+
+        let acrobot = AcrobotEnv::new(RenderMode::None);
+        let space = acrobot.observation_space();
+        assert!(space.contains(space.low));
+        assert!(space.contains(space.high));
+
+        let pendulum = PendulumEnv::new(RenderMode::None);
+        let space = pendulum.observation_space();
+        assert!(space.contains(space.low));
+        assert!(space.contains(space.high));
+    }
+
+    #[test]
+    #[should_panic(expected = "must be <= high")]
+    fn given_inverted_bounds_when_new_called_then_panics() {
+        BoxR::new(1., -1.);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be <= high")]
+    fn given_bounds_inverted_in_one_field_when_new_called_then_panics() {
+        BoxR::new(
+            MountainCarObservation::new(OrderedFloat(0.), OrderedFloat(5.)),
+            MountainCarObservation::new(OrderedFloat(1.), OrderedFloat(0.)),
+        );
+    }
+
+    #[test]
+    fn given_ordered_bounds_when_new_called_then_bounds_are_kept() {
+        let obj = BoxR::new(-1., -1.);
+
+        assert_eq!((obj.low, obj.high), (-1., -1.));
+    }
+
+    #[test]
+    fn given_vectors_when_contains_called_then_every_element_is_checked() {
+        let obj = BoxR::from_bounds(vec![-1., 0.], vec![1., 2.]);
+
+        assert!(obj.contains(vec![-1., 2.]));
+        assert!(obj.contains(vec![0.5, 1.]));
+        assert!(!obj.contains(vec![0.5, 2.5]));
+        assert!(!obj.contains(vec![0.5]));
+        assert!(!obj.contains(vec![0.5, 1., 0.]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_bounds_of_different_length_when_from_bounds_called_then_panics() {
+        BoxR::from_bounds(vec![-1., 0.], vec![1.]);
+    }
+
+    #[test]
+    fn given_values_outside_bounds_when_clipped_then_nearest_bound_is_returned() {
+        let obj = BoxR::new(
+            MountainCarObservation::new(OrderedFloat(-1.2), OrderedFloat(-0.07)),
+            MountainCarObservation::new(OrderedFloat(0.6), OrderedFloat(0.07)),
+        );
+
+        assert_eq!(BoxR::new(-1., 1.).clip(1.5), 1.);
+        assert_eq!(BoxR::new(-1., 1.).clip(0.5), 0.5);
+        assert_eq!(
+            obj.clip(MountainCarObservation::new(
+                OrderedFloat(-2.),
+                OrderedFloat(0.)
+            )),
+            MountainCarObservation::new(OrderedFloat(-1.2), OrderedFloat(0.))
+        );
+    }
+
+    #[test]
+    fn given_bounds_when_sample_called_then_value_is_within_bounds() {
+        let (mut rng, _) = rand_random(Some(42));
+        let obj = BoxR::new(
+            MountainCarObservation::new(OrderedFloat(-0.6), OrderedFloat(0.)),
+            MountainCarObservation::new(OrderedFloat(-0.4), OrderedFloat(0.)),
+        );
+
+        for _ in 0..100 {
+            assert!(obj.contains(obj.sample(&mut rng)));
+        }
+    }
+}
@@ -1,16 +1,44 @@
-use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Range, RangeInclusive},
+};
 
-use super::Space;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{BoundedObservation, BoxR, Space};
+use crate::utils::custom::traits::Sample;
 
 /// Defines a set of discrete integers starting at 0.
 ///
-/// The value held by this structure defines the largest inclusive value that
-/// exists within the derived set.  
-///
-/// TODO: Update to support negative values.
+/// The value held by this structure defines the smallest exclusive value that
+/// does not exist within the derived set. Sets holding negative values are
+/// defined by [`DiscreteRange`] instead.
 #[derive(Debug, Serialize, PartialEq, PartialOrd, Eq, Ord, Clone)]
 pub struct Discrete(pub usize);
 
+impl Discrete {
+    /// Draws a value uniformly from the set.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> usize {
+        rng.gen_range(0..self.0)
+    }
+
+    /// Iterates over every value of the set in ascending order.
+    pub fn iter(&self) -> Range<usize> {
+        0..self.0
+    }
+}
+
+impl IntoIterator for &Discrete {
+    type Item = usize;
+    type IntoIter = Range<usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl Space<usize> for Discrete {
     fn contains(&self, value: usize) -> bool {
         match *self {
@@ -19,10 +47,115 @@ impl Space<usize> for Discrete {
     }
 }
 
+/// Defines a set of consecutive integers between two inclusive bounds, which may be negative,
+/// such as `{-1, 0, 1}` for the direction of a force.
+#[derive(Debug, Serialize, Deserialize, PartialEq, PartialOrd, Eq, Ord, Clone, Copy)]
+pub struct DiscreteRange {
+    /// The smallest value within the set.
+    pub low: i64,
+    /// The largest value within the set.
+    pub high: i64,
+}
+
+impl DiscreteRange {
+    /// Creates the set of integers between the given inclusive bounds.
+    ///
+    /// Panics if `low` exceeds `high`, as the set would be empty.
+    pub fn new(low: i64, high: i64) -> Self {
+        assert!(
+            low <= high,
+            "DiscreteRange::new: low ({}) must be <= high ({})",
+            low,
+            high
+        );
+
+        Self { low, high }
+    }
+
+    /// Draws a value uniformly from the set.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> i64 {
+        rng.gen_range(self.low..=self.high)
+    }
+
+    /// Iterates over every value of the set in ascending order.
+    pub fn iter(&self) -> RangeInclusive<i64> {
+        self.low..=self.high
+    }
+}
+
+impl From<Discrete> for DiscreteRange {
+    fn from(discrete: Discrete) -> Self {
+        Self {
+            low: 0,
+            high: discrete.0 as i64 - 1,
+        }
+    }
+}
+
+impl Space<i64> for DiscreteRange {
+    fn contains(&self, value: i64) -> bool {
+        self.low <= value && value <= self.high
+    }
+}
+
+/// An observation holding a single value of a [`Discrete`] set, such as the index of a cell in a
+/// tabular environment.
+#[derive(Debug, Serialize, Deserialize, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Hash)]
+pub struct DiscreteObservation(pub usize);
+
+impl Space<DiscreteObservation> for Discrete {
+    fn contains(&self, value: DiscreteObservation) -> bool {
+        self.contains(value.0)
+    }
+}
+
+impl Sample for DiscreteObservation {
+    /// Draws a value uniformly between the inclusive bounds, which must always be provided.
+    fn sample_between<R: Rng>(rng: &mut R, bounds: Option<BoxR<Self>>) -> Self {
+        let BoxR { low, high } =
+            bounds.expect("Sampling a discrete value requires bounds to be provided.");
+
+        DiscreteObservation(rng.gen_range(low.0..=high.0))
+    }
+}
+
+impl BoundedObservation for DiscreteObservation {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        low <= self && self <= high
+    }
+}
+
+impl From<DiscreteObservation> for Vec<f64> {
+    fn from(observation: DiscreteObservation) -> Self {
+        vec![observation.0 as f64]
+    }
+}
+
+/// The error produced when converting a value which lies outside of a discrete set.
+#[derive(Debug, Serialize, PartialEq, Eq, Clone, Copy)]
+pub struct InvalidDiscreteValue {
+    /// The value which was converted.
+    pub value: usize,
+    /// The number of values within the set.
+    pub n: usize,
+}
+
+impl Display for InvalidDiscreteValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} is not within the discrete set [0, {})",
+            self.value, self.n
+        )
+    }
+}
+
+impl Error for InvalidDiscreteValue {}
+
 #[cfg(test)]
 mod tests {
-    use super::Discrete;
-    use crate::spaces::Space;
+    use super::{Discrete, DiscreteRange};
+    use crate::{spaces::Space, utils::seeding::rand_random};
 
     #[test]
     fn given_value_greater_or_eq_than_upper_bound_when_contains_called_then_returns_false() {
@@ -39,4 +172,53 @@ mod tests {
         assert!(obj.contains(1));
         assert!(obj.contains(2));
     }
+
+    #[test]
+    fn given_upper_bound_when_sample_called_then_every_value_in_set_is_drawn() {
+        let (mut rng, _) = rand_random(Some(42));
+        let obj = Discrete(3);
+
+        let mut drawn = [false; 3];
+        for _ in 0..100 {
+            let value = obj.sample(&mut rng);
+            assert!(obj.contains(value));
+            drawn[value] = true;
+        }
+
+        assert_eq!(drawn, [true; 3]);
+    }
+
+    #[test]
+    fn given_upper_bound_when_iterated_then_every_value_is_yielded_in_order() {
+        assert_eq!(Discrete(3).iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!((&Discrete(2)).into_iter().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(Discrete(0).iter().next(), None);
+    }
+
+    #[test]
+    fn given_negative_range_when_sampled_then_values_are_contained() {
+        let (mut rng, _) = rand_random(Some(42));
+        let obj = DiscreteRange::new(-2, 2);
+
+        assert!(obj.contains(-2));
+        assert!(obj.contains(2));
+        assert!(!obj.contains(-3));
+        assert!(!obj.contains(3));
+
+        let mut seen = [false; 5];
+        for _ in 0..100 {
+            let value = obj.sample(&mut rng);
+            assert!(obj.contains(value));
+            seen[(value + 2) as usize] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+    }
+
+    #[test]
+    fn given_discrete_when_converted_then_range_holds_same_values() {
+        let obj = DiscreteRange::from(Discrete(3));
+
+        assert_eq!(obj, DiscreteRange::new(0, 2));
+        assert_eq!(obj.iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
 }
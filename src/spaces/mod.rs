@@ -1,7 +1,9 @@
 mod box_r;
 mod discrete;
+mod multi_discrete;
 mod space;
 
-pub use box_r::BoxR;
-pub use discrete::Discrete;
+pub use box_r::{BoundedObservation, BoxR, Clamp};
+pub use discrete::{Discrete, DiscreteObservation, DiscreteRange, InvalidDiscreteValue};
+pub use multi_discrete::{MultiDiscrete, UniformMultiDiscrete};
 pub use space::Space;
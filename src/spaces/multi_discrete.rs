@@ -0,0 +1,184 @@
+use rand::{
+    distributions::{
+        uniform::{SampleBorrow, SampleUniform, UniformSampler},
+        Uniform,
+    },
+    prelude::Distribution,
+    Rng,
+};
+use serde::Serialize;
+
+use super::{BoundedObservation, BoxR, Space};
+use crate::utils::custom::traits::Sample;
+
+/// Defines a cartesian product of sets of discrete integers starting at 0.
+///
+/// Each value held by this structure defines the largest exclusive value that
+/// exists within the derived set of the associated dimension.
+#[derive(Debug, Serialize, PartialEq, PartialOrd, Eq, Ord, Clone)]
+pub struct MultiDiscrete(pub Vec<usize>);
+
+impl MultiDiscrete {
+    /// Draws a value for each dimension independently, using uniform probability.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Vec<usize> {
+        self.0
+            .iter()
+            .map(|&upper_bound| rng.gen_range(0..upper_bound))
+            .collect()
+    }
+
+    /// Provides the number of distinct values held by the space, being the product of the sizes
+    /// of every dimension.
+    pub fn num_actions(&self) -> usize {
+        self.0.iter().product()
+    }
+}
+
+impl Space<Vec<usize>> for MultiDiscrete {
+    fn contains(&self, value: Vec<usize>) -> bool {
+        value.len() == self.0.len()
+            && value
+                .iter()
+                .zip(self.0.iter())
+                .all(|(value, upper_bound)| value < upper_bound)
+    }
+}
+
+impl BoundedObservation for MultiDiscrete {
+    fn is_within(&self, low: &Self, high: &Self) -> bool {
+        self.0.len() == low.0.len()
+            && self.0.len() == high.0.len()
+            && self
+                .0
+                .iter()
+                .zip(low.0.iter().zip(high.0.iter()))
+                .all(|(value, (low, high))| low <= value && value <= high)
+    }
+}
+
+/// The sampler responsible for independently drawing a value for each dimension using uniform
+/// probability.
+pub struct UniformMultiDiscrete {
+    samplers: Vec<Uniform<usize>>,
+}
+
+impl SampleUniform for MultiDiscrete {
+    type Sampler = UniformMultiDiscrete;
+}
+
+impl UniformSampler for UniformMultiDiscrete {
+    type X = MultiDiscrete;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformMultiDiscrete {
+            samplers: low
+                .borrow()
+                .0
+                .iter()
+                .zip(high.borrow().0.iter())
+                .map(|(low, high)| Uniform::new(low, high))
+                .collect(),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        UniformMultiDiscrete {
+            samplers: low
+                .borrow()
+                .0
+                .iter()
+                .zip(high.borrow().0.iter())
+                .map(|(low, high)| Uniform::new_inclusive(low, high))
+                .collect(),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        MultiDiscrete(
+            self.samplers
+                .iter()
+                .map(|sampler| sampler.sample(rng))
+                .collect(),
+        )
+    }
+}
+
+impl Sample for MultiDiscrete {
+    /// Draws a value for each dimension between the given bounds.
+    ///
+    /// Since the dimensionality cannot be inferred without them, bounds must always be provided.
+    fn sample_between<R: Rng>(rng: &mut R, bounds: Option<BoxR<Self>>) -> Self {
+        let BoxR { low, high } =
+            bounds.expect("Sampling a multi discrete value requires bounds to be provided.");
+
+        Uniform::new(low, high).sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultiDiscrete;
+    use crate::{
+        spaces::{BoxR, Space},
+        utils::{custom::traits::Sample, seeding::rand_random},
+    };
+
+    #[test]
+    fn given_values_less_than_each_upper_bound_when_contains_called_then_returns_true() {
+        let obj = MultiDiscrete(vec![3, 2, 5]);
+
+        assert!(obj.contains(vec![0, 0, 0]));
+        assert!(obj.contains(vec![2, 1, 4]));
+    }
+
+    #[test]
+    fn given_value_greater_or_eq_than_upper_bound_when_contains_called_then_returns_false() {
+        let obj = MultiDiscrete(vec![3, 2, 5]);
+
+        assert!(!obj.contains(vec![3, 0, 0]));
+        assert!(!obj.contains(vec![0, 2, 0]));
+        assert!(!obj.contains(vec![0, 0, 6]));
+    }
+
+    #[test]
+    fn given_value_of_different_length_when_contains_called_then_returns_false() {
+        let obj = MultiDiscrete(vec![3, 2, 5]);
+
+        assert!(!obj.contains(vec![0, 0]));
+        assert!(!obj.contains(vec![0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn given_space_when_sample_called_then_value_is_contained() {
+        let (mut rng, _) = rand_random(Some(42));
+        let space = MultiDiscrete(vec![3, 3, 3, 3]);
+
+        for _ in 0..100 {
+            assert!(space.contains(space.sample(&mut rng)));
+        }
+        assert_eq!(space.num_actions(), 81);
+    }
+
+    #[test]
+    fn given_bounds_when_sample_between_called_then_each_dimension_is_within_bounds() {
+        let (mut rng, _) = rand_random(Some(42));
+        let space = MultiDiscrete(vec![3, 2, 5]);
+
+        for _ in 0..100 {
+            let MultiDiscrete(value) = MultiDiscrete::sample_between(
+                &mut rng,
+                Some(BoxR::new(MultiDiscrete(vec![0, 0, 0]), space.clone())),
+            );
+
+            assert!(space.contains(value));
+        }
+    }
+}
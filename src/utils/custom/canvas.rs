@@ -0,0 +1,595 @@
+#[cfg(feature = "sdl2")]
+pub use sdl2::{
+    gfx::primitives::DrawRenderer,
+    pixels::Color,
+    rect::{Point, Rect},
+    render::WindowCanvas as Canvas,
+};
+
+#[cfg(not(feature = "sdl2"))]
+pub use self::software::{Canvas, Color, DrawRenderer, Point, Rect};
+
+/// Mirrors the subset of the SDL2 drawing API used by the environments, rasterizing into an
+/// off-screen buffer so frames can be produced without linking against SDL2.
+#[cfg(not(feature = "sdl2"))]
+mod software {
+    use std::mem::swap;
+
+    use crate::utils::renderer::{RenderColor, RenderFrame};
+
+    /// Describes the colour of a single pixel.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Color {
+        /// The red channel.
+        pub r: u8,
+        /// The green channel.
+        pub g: u8,
+        /// The blue channel.
+        pub b: u8,
+    }
+
+    #[allow(non_snake_case)]
+    impl Color {
+        /// Pure white.
+        pub const WHITE: Color = Color::RGB(255, 255, 255);
+        /// Pure black.
+        pub const BLACK: Color = Color::RGB(0, 0, 0);
+
+        /// Constructs a colour from its red, green and blue channels.
+        pub const fn RGB(r: u8, g: u8, b: u8) -> Self {
+            Self { r, g, b }
+        }
+    }
+
+    /// A point on the canvas, measured in pixels from the top-left corner.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl Point {
+        /// Constructs a point from its coordinates.
+        pub fn new(x: i32, y: i32) -> Self {
+            Self { x, y }
+        }
+
+        /// Provides the horizontal coordinate.
+        pub fn x(&self) -> i32 {
+            self.x
+        }
+
+        /// Provides the vertical coordinate.
+        pub fn y(&self) -> i32 {
+            self.y
+        }
+    }
+
+    /// A rectangle on the canvas, described by its top-left corner and its dimensions.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Rect {
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    }
+
+    impl Rect {
+        /// Constructs a rectangle from its top-left corner and its dimensions.
+        pub fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+            Self {
+                x,
+                y,
+                width,
+                height,
+            }
+        }
+
+        /// Provides the horizontal coordinate of the top-left corner.
+        pub fn x(&self) -> i32 {
+            self.x
+        }
+
+        /// Provides the vertical coordinate of the top-left corner.
+        pub fn y(&self) -> i32 {
+            self.y
+        }
+
+        /// Provides the width of the rectangle.
+        pub fn width(&self) -> u32 {
+            self.width
+        }
+
+        /// Provides the height of the rectangle.
+        pub fn height(&self) -> u32 {
+            self.height
+        }
+    }
+
+    /// An off-screen buffer of pixels which can be drawn on.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Canvas {
+        width: u32,
+        height: u32,
+        draw_color: Color,
+        pixels: Vec<Color>,
+    }
+
+    impl Canvas {
+        /// Constructs a black canvas of the given dimensions.
+        pub fn new(width: u32, height: u32) -> Self {
+            Self {
+                width,
+                height,
+                draw_color: Color::BLACK,
+                pixels: vec![Color::BLACK; width as usize * height as usize],
+            }
+        }
+
+        /// Provides the width of the canvas in pixels.
+        pub fn width(&self) -> u32 {
+            self.width
+        }
+
+        /// Provides the height of the canvas in pixels.
+        pub fn height(&self) -> u32 {
+            self.height
+        }
+
+        /// Provides the colour of the pixel at the given coordinates, if it lies on the canvas.
+        pub fn pixel(&self, x: i32, y: i32) -> Option<Color> {
+            self.index(x, y).map(|index| self.pixels[index])
+        }
+
+        /// Sets the colour used by [`Canvas::clear`] and [`Canvas::draw_lines`].
+        pub fn set_draw_color(&mut self, color: Color) {
+            self.draw_color = color;
+        }
+
+        /// Fills the entire canvas with the draw colour.
+        pub fn clear(&mut self) {
+            self.pixels.fill(self.draw_color);
+        }
+
+        /// Draws a series of connected lines using the draw colour.
+        pub fn draw_lines(&mut self, points: &[Point]) -> Result<(), String> {
+            for pair in points.windows(2) {
+                self.line(pair[0].x, pair[0].y, pair[1].x, pair[1].y, self.draw_color);
+            }
+            Ok(())
+        }
+
+        /// Produces a copy of the canvas, optionally mirrored along either axis.
+        pub fn flipped(&self, flip_horizontal: bool, flip_vertical: bool) -> Self {
+            let (width, height) = (self.width as usize, self.height as usize);
+            let mut flipped = self.clone();
+            for y in 0..height {
+                for x in 0..width {
+                    let src_x = if flip_horizontal { width - 1 - x } else { x };
+                    let src_y = if flip_vertical { height - 1 - y } else { y };
+                    flipped.pixels[y * width + x] = self.pixels[src_y * width + src_x];
+                }
+            }
+            flipped
+        }
+
+        /// Writes the text with its top-left corner at the given coordinates, using the built-in
+        /// 8x8 font with each font pixel enlarged to a `scale` by `scale` square.
+        pub fn draw_text(&mut self, x: i32, y: i32, text: &str, color: Color, scale: u32) {
+            let scale = scale.max(1) as i32;
+            for (index, character) in text.chars().enumerate() {
+                let left = x.saturating_add((index as i32).saturating_mul(8 * scale));
+                for (row, bits) in glyph(character).into_iter().enumerate() {
+                    for column in (0..5).filter(|column| bits & (0b10000 >> column) != 0) {
+                        let px = left.saturating_add((column + 1) * scale);
+                        let py = y.saturating_add(row as i32 * scale);
+                        for (dx, dy) in (0..scale).flat_map(|dx| (0..scale).map(move |dy| (dx, dy)))
+                        {
+                            self.put(px.saturating_add(dx), py.saturating_add(dy), color);
+                        }
+                    }
+                }
+            }
+        }
+
+        /// Converts the canvas into a frame, row by row from the top.
+        pub fn to_frame(&self) -> RenderFrame {
+            RenderFrame::new(
+                self.pixels
+                    .chunks(self.width.max(1) as usize)
+                    .map(|row| {
+                        row.iter()
+                            .map(|color| RenderColor::RGB(color.r, color.g, color.b))
+                            .collect()
+                    })
+                    .collect(),
+            )
+        }
+
+        fn index(&self, x: i32, y: i32) -> Option<usize> {
+            let on_canvas =
+                (0..self.width as i32).contains(&x) && (0..self.height as i32).contains(&y);
+            on_canvas.then(|| y as usize * self.width as usize + x as usize)
+        }
+
+        fn put(&mut self, x: i32, y: i32, color: Color) {
+            if let Some(index) = self.index(x, y) {
+                self.pixels[index] = color;
+            }
+        }
+
+        fn span(&mut self, mut x1: i32, mut x2: i32, y: i32, color: Color) {
+            if x1 > x2 {
+                swap(&mut x1, &mut x2);
+            }
+            for x in x1..=x2 {
+                self.put(x, y, color);
+            }
+        }
+
+        /// Rasterizes a line using Bresenham's algorithm.
+        fn line(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, color: Color) {
+            let (dx, dy) = ((x2 - x1).abs(), -(y2 - y1).abs());
+            let (step_x, step_y) = ((x2 - x1).signum(), (y2 - y1).signum());
+            let (mut x, mut y, mut error) = (x1, y1, dx + dy);
+            loop {
+                self.put(x, y, color);
+                if x == x2 && y == y2 {
+                    break;
+                }
+                let doubled = 2 * error;
+                if doubled >= dy {
+                    error += dy;
+                    x += step_x;
+                }
+                if doubled <= dx {
+                    error += dx;
+                    y += step_y;
+                }
+            }
+        }
+    }
+
+    /// Provides the primitive shapes offered by SDL2_gfx.
+    ///
+    /// Shapes are drawn without anti-aliasing, so the `aa_` variants only outline the shape.
+    pub trait DrawRenderer {
+        /// Draws a horizontal line between `x1` and `x2`.
+        fn hline(&mut self, x1: i16, x2: i16, y: i16, color: Color) -> Result<(), String>;
+
+        /// Draws a vertical line between `y1` and `y2`.
+        fn vline(&mut self, x: i16, y1: i16, y2: i16, color: Color) -> Result<(), String>;
+
+        /// Draws a line of the given width between two points.
+        fn thick_line(
+            &mut self,
+            x1: i16,
+            y1: i16,
+            x2: i16,
+            y2: i16,
+            width: u8,
+            color: Color,
+        ) -> Result<(), String>;
+
+        /// Draws the outline of a circle.
+        fn aa_circle(&mut self, x: i16, y: i16, rad: i16, color: Color) -> Result<(), String>;
+
+        /// Draws a filled circle.
+        fn filled_circle(&mut self, x: i16, y: i16, rad: i16, color: Color) -> Result<(), String>;
+
+        /// Draws the outline of the polygon with the given vertices.
+        fn aa_polygon(&mut self, vx: &[i16], vy: &[i16], color: Color) -> Result<(), String>;
+
+        /// Draws a filled polygon with the given vertices.
+        fn filled_polygon(&mut self, vx: &[i16], vy: &[i16], color: Color) -> Result<(), String>;
+
+        /// Writes the text with its top-left corner at the given coordinates, using the built-in
+        /// 8x8 font.
+        fn string(&mut self, x: i16, y: i16, s: &str, color: Color) -> Result<(), String>;
+    }
+
+    /// Provides the rows of the 5x7 glyph drawn for a character, the most significant of the five
+    /// bits being the leftmost pixel.
+    ///
+    /// Lowercase letters are drawn as uppercase, while unsupported characters are drawn as `?`.
+    fn glyph(character: char) -> [u8; 7] {
+        match character.to_ascii_uppercase() {
+            ' ' => [0; 7],
+            '0' => [
+                0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110,
+            ],
+            '1' => [
+                0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110,
+            ],
+            '2' => [
+                0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111,
+            ],
+            '3' => [
+                0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110,
+            ],
+            '4' => [
+                0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010,
+            ],
+            '5' => [
+                0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110,
+            ],
+            '6' => [
+                0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110,
+            ],
+            '7' => [
+                0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000,
+            ],
+            '8' => [
+                0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110,
+            ],
+            '9' => [
+                0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100,
+            ],
+            'A' => [
+                0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001,
+            ],
+            'B' => [
+                0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110,
+            ],
+            'C' => [
+                0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110,
+            ],
+            'D' => [
+                0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100,
+            ],
+            'E' => [
+                0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111,
+            ],
+            'F' => [
+                0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000,
+            ],
+            'G' => [
+                0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111,
+            ],
+            'H' => [
+                0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001,
+            ],
+            'I' => [
+                0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110,
+            ],
+            'J' => [
+                0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100,
+            ],
+            'K' => [
+                0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001,
+            ],
+            'L' => [
+                0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111,
+            ],
+            'M' => [
+                0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001,
+            ],
+            'N' => [
+                0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001,
+            ],
+            'O' => [
+                0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110,
+            ],
+            'P' => [
+                0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000,
+            ],
+            'Q' => [
+                0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101,
+            ],
+            'R' => [
+                0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001,
+            ],
+            'S' => [
+                0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110,
+            ],
+            'T' => [
+                0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100,
+            ],
+            'U' => [
+                0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110,
+            ],
+            'V' => [
+                0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100,
+            ],
+            'W' => [
+                0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010,
+            ],
+            'X' => [
+                0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001,
+            ],
+            'Y' => [
+                0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100,
+            ],
+            'Z' => [
+                0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111,
+            ],
+            '.' => [0, 0, 0, 0, 0, 0b01100, 0b01100],
+            ',' => [0, 0, 0, 0, 0b01100, 0b00100, 0b01000],
+            ':' => [0, 0b01100, 0b01100, 0, 0b01100, 0b01100, 0],
+            '-' => [0, 0, 0, 0b11111, 0, 0, 0],
+            '+' => [0, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0],
+            '=' => [0, 0, 0b11111, 0, 0b11111, 0, 0],
+            '_' => [0, 0, 0, 0, 0, 0, 0b11111],
+            '/' => [0, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0],
+            '(' => [
+                0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010,
+            ],
+            ')' => [
+                0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000,
+            ],
+            '[' => [
+                0b01110, 0b01000, 0b01000, 0b01000, 0b01000, 0b01000, 0b01110,
+            ],
+            ']' => [
+                0b01110, 0b00010, 0b00010, 0b00010, 0b00010, 0b00010, 0b01110,
+            ],
+            '<' => [
+                0b00010, 0b00100, 0b01000, 0b10000, 0b01000, 0b00100, 0b00010,
+            ],
+            '>' => [
+                0b01000, 0b00100, 0b00010, 0b00001, 0b00010, 0b00100, 0b01000,
+            ],
+            '*' => [0, 0b00100, 0b10101, 0b01110, 0b10101, 0b00100, 0],
+            '%' => [
+                0b11000, 0b11001, 0b00010, 0b00100, 0b01000, 0b10011, 0b00011,
+            ],
+            '!' => [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0, 0b00100],
+            _ => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0, 0b00100],
+        }
+    }
+
+    fn check_vertices(vx: &[i16], vy: &[i16]) -> Result<(), String> {
+        if vx.len() != vy.len() {
+            Err("A polygon requires as many x-coordinates as y-coordinates.".to_string())
+        } else if vx.len() < 3 {
+            Err("A polygon requires at least 3 vertices.".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    impl DrawRenderer for Canvas {
+        fn hline(&mut self, x1: i16, x2: i16, y: i16, color: Color) -> Result<(), String> {
+            self.span(x1.into(), x2.into(), y.into(), color);
+            Ok(())
+        }
+
+        fn vline(&mut self, x: i16, y1: i16, y2: i16, color: Color) -> Result<(), String> {
+            self.line(x.into(), y1.into(), x.into(), y2.into(), color);
+            Ok(())
+        }
+
+        fn thick_line(
+            &mut self,
+            x1: i16,
+            y1: i16,
+            x2: i16,
+            y2: i16,
+            width: u8,
+            color: Color,
+        ) -> Result<(), String> {
+            let (dx, dy) = (f64::from(x2 - x1), f64::from(y2 - y1));
+            let length = dx.hypot(dy);
+            if width <= 1 || length == 0. {
+                self.line(x1.into(), y1.into(), x2.into(), y2.into(), color);
+                return Ok(());
+            }
+
+            let half_width = f64::from(width) / 2.;
+            let (offset_x, offset_y) = (-dy / length * half_width, dx / length * half_width);
+            let corners = [
+                (f64::from(x1) + offset_x, f64::from(y1) + offset_y),
+                (f64::from(x2) + offset_x, f64::from(y2) + offset_y),
+                (f64::from(x2) - offset_x, f64::from(y2) - offset_y),
+                (f64::from(x1) - offset_x, f64::from(y1) - offset_y),
+            ];
+            let vx = corners.map(|(x, _)| x.round() as i16);
+            let vy = corners.map(|(_, y)| y.round() as i16);
+
+            self.aa_polygon(&vx, &vy, color)?;
+            self.filled_polygon(&vx, &vy, color)
+        }
+
+        fn aa_circle(&mut self, x: i16, y: i16, rad: i16, color: Color) -> Result<(), String> {
+            let (cx, cy) = (i32::from(x), i32::from(y));
+            let (mut dx, mut dy, mut error) = (i32::from(rad), 0, 1 - i32::from(rad));
+            while dx >= dy {
+                for (px, py) in [(dx, dy), (dy, dx), (-dy, dx), (-dx, dy)] {
+                    self.put(cx + px, cy + py, color);
+                    self.put(cx - px, cy - py, color);
+                }
+                dy += 1;
+                if error < 0 {
+                    error += 2 * dy + 1;
+                } else {
+                    dx -= 1;
+                    error += 2 * (dy - dx) + 1;
+                }
+            }
+            Ok(())
+        }
+
+        fn filled_circle(&mut self, x: i16, y: i16, rad: i16, color: Color) -> Result<(), String> {
+            let (cx, cy, rad) = (i32::from(x), i32::from(y), i32::from(rad));
+            for dy in -rad..=rad {
+                let dx = f64::from(rad * rad - dy * dy).sqrt() as i32;
+                self.span(cx - dx, cx + dx, cy + dy, color);
+            }
+            Ok(())
+        }
+
+        fn aa_polygon(&mut self, vx: &[i16], vy: &[i16], color: Color) -> Result<(), String> {
+            check_vertices(vx, vy)?;
+            let n = vx.len();
+            for i in 0..n {
+                let j = (i + 1) % n;
+                self.line(
+                    vx[i].into(),
+                    vy[i].into(),
+                    vx[j].into(),
+                    vy[j].into(),
+                    color,
+                );
+            }
+            Ok(())
+        }
+
+        fn string(&mut self, x: i16, y: i16, s: &str, color: Color) -> Result<(), String> {
+            self.draw_text(x.into(), y.into(), s, color, 1);
+            Ok(())
+        }
+
+        fn filled_polygon(&mut self, vx: &[i16], vy: &[i16], color: Color) -> Result<(), String> {
+            check_vertices(vx, vy)?;
+            let n = vx.len();
+            let min_y = vy.iter().copied().min().unwrap_or_default();
+            let max_y = vy.iter().copied().max().unwrap_or_default();
+
+            // Scan each row, filling between pairs of edge crossings (even-odd rule).
+            for y in min_y..=max_y {
+                let y = f64::from(y);
+                let mut crossings: Vec<f64> = (0..n)
+                    .filter_map(|i| {
+                        let j = (i + n - 1) % n;
+                        let (x1, y1) = (f64::from(vx[i]), f64::from(vy[i]));
+                        let (x2, y2) = (f64::from(vx[j]), f64::from(vy[j]));
+                        let crosses = (y1 <= y && y < y2) || (y2 <= y && y < y1);
+                        crosses.then(|| x1 + (y - y1) * (x2 - x1) / (y2 - y1))
+                    })
+                    .collect();
+                crossings.sort_by(f64::total_cmp);
+
+                for pair in crossings.chunks_exact(2) {
+                    self.span(
+                        pair[0].round() as i32,
+                        pair[1].round() as i32,
+                        y as i32,
+                        color,
+                    );
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(all(test, not(feature = "sdl2")))]
+mod tests {
+    use super::{Canvas, Color, DrawRenderer};
+
+    #[test]
+    fn given_square_when_filled_polygon_drawn_then_interior_is_coloured() {
+        let mut canvas = Canvas::new(10, 10);
+        canvas.set_draw_color(Color::WHITE);
+        canvas.clear();
+
+        canvas
+            .filled_polygon(&[2, 2, 7, 7], &[2, 7, 7, 2], Color::BLACK)
+            .unwrap();
+
+        assert_eq!(canvas.pixel(4, 4), Some(Color::BLACK));
+        assert_eq!(canvas.pixel(0, 0), Some(Color::WHITE));
+        assert_eq!(canvas.pixel(9, 9), Some(Color::WHITE));
+        assert_eq!(canvas.pixel(10, 0), None);
+    }
+}
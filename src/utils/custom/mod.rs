@@ -1,3 +1,5 @@
+/// Holds the drawing primitives shared by SDL2 and the off-screen fallback.
+pub mod canvas;
 /// Holds the objects associating with rendering.
 pub mod screen;
 /// Holds structures commonly used.
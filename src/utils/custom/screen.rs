@@ -1,20 +1,29 @@
+#[cfg(feature = "png")]
+use std::path::Path;
+
 use derivative::Derivative;
 use derive_new::new;
+#[cfg(not(feature = "sdl2"))]
+use log::warn;
+#[cfg(feature = "sdl2")]
 use sdl2::{
     event::Event,
-    gfx::framerate::FPSManager,
+    gfx::{framerate::FPSManager, primitives::DrawRenderer},
+    keyboard::Keycode,
     pixels::PixelFormatEnum,
-    rect::{Point, Rect},
-    render::WindowCanvas,
     EventPump,
 };
 use serde::Serialize;
 
-use crate::utils::renderer::{RenderColor, RenderFrame, RenderMode, Renders};
+use super::canvas::{Canvas, Color, Point, Rect};
+#[cfg(feature = "sdl2")]
+use crate::utils::renderer::RenderColor;
+use crate::utils::renderer::{RenderFrame, RenderMode, Renders};
 
 /// Defines the structures required from SDL2 to process and render environments.
+#[cfg(feature = "sdl2")]
 struct ScreenGui {
-    pub canvas: WindowCanvas,
+    pub canvas: Canvas,
     pub fps_manager: FPSManager,
     pub event_pump: EventPump,
     // pub event_subsystem: EventSubsystem,
@@ -49,33 +58,61 @@ impl Default for ScreenGuiTransformations {
 
 /// Defines a wrapper over SDL2, similar to PyGame to enable rapid development
 /// of GUI environments.
+///
+/// Without the `sdl2` feature, frames are drawn into an off-screen buffer instead, so only the
+/// array-producing render modes are available.
 #[derive(Serialize, Derivative, new)]
 #[derivative(Debug)]
 pub struct Screen {
-    height: u32,
     width: u32,
+    height: u32,
     title: &'static str,
     render_fps: u32,
     mode: RenderMode,
+    /// Indicates whether each render waits for a key to be pressed before continuing.
+    #[new(default)]
+    pub step_mode: bool,
     #[serde(skip_serializing)]
     #[derivative(Debug = "ignore")]
     #[new(default)]
+    #[cfg(feature = "sdl2")]
     gui: Option<ScreenGui>,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    #[new(default)]
+    #[cfg(not(feature = "sdl2"))]
+    gui: Option<Canvas>,
 }
 
 impl Clone for Screen {
     fn clone(&self) -> Self {
         Self {
-            height: self.height,
             width: self.width,
+            height: self.height,
             title: self.title,
             render_fps: self.render_fps,
             mode: self.mode,
+            step_mode: self.step_mode,
             gui: None,
         }
     }
 }
 impl Screen {
+    /// Constructs a screen which waits for a key to be pressed after each render, allowing the
+    /// animation to be stepped through manually.
+    pub fn new_step_mode(
+        width: u32,
+        height: u32,
+        title: &'static str,
+        render_fps: u32,
+        mode: RenderMode,
+    ) -> Self {
+        Self {
+            step_mode: true,
+            ..Self::new(width, height, title, render_fps, mode)
+        }
+    }
+
     /// Closes the process responsible for rendering the environment.
     pub fn close(&mut self) {
         self.gui.take();
@@ -87,7 +124,8 @@ impl Screen {
     }
 
     /// Transforms the canvas into pixel coordinates for external consumption.
-    fn canvas_to_pixels(canvas: &mut WindowCanvas, screen_width: u32) -> RenderFrame {
+    #[cfg(feature = "sdl2")]
+    fn canvas_to_pixels(canvas: &Canvas, screen_width: u32) -> RenderFrame {
         let pixels = canvas
             .read_pixels(None, PixelFormatEnum::RGB24)
             .expect("pixels");
@@ -106,23 +144,53 @@ impl Screen {
     }
 
     /// Outputs the contents found in the GUI buffer to the display surface.
+    ///
+    /// Without the `sdl2` feature, there is no display surface and the contents are only returned.
     pub fn render(&mut self, mode: RenderMode) -> Renders {
-        match self.gui.as_mut() {
-            Some(ScreenGui {
-                canvas,
-                fps_manager,
-                ..
-            }) => {
-                fps_manager.delay();
-                canvas.present();
-                if [RenderMode::RgbArray, RenderMode::SingleRgbArray].contains(&mode) {
-                    Renders::SingleRgbArray(Self::canvas_to_pixels(canvas, self.width))
-                } else {
-                    Renders::None
-                }
-            }
-            _ => Renders::None,
+        #[cfg(feature = "sdl2")]
+        if let Some(ScreenGui {
+            canvas,
+            fps_manager,
+            ..
+        }) = self.gui.as_mut()
+        {
+            fps_manager.delay();
+            canvas.present();
+        }
+        if self.step_mode {
+            self.pause_on_keypress();
         }
+
+        if [RenderMode::RgbArray, RenderMode::SingleRgbArray].contains(&mode) {
+            self.current_frame()
+                .map_or(Renders::None, Renders::SingleRgbArray)
+        } else {
+            Renders::None
+        }
+    }
+
+    /// Saves the content currently displayed by the screen as a PNG file at the given path.
+    ///
+    /// Unlike the frames returned by [`Screen::render`], this captures the screen at any point,
+    /// including text drawn after the last render.
+    #[cfg(feature = "png")]
+    pub fn screenshot(&self, path: &Path) -> Result<(), String> {
+        self.current_frame()
+            .ok_or("Screen not initialized")?
+            .save_png(path)
+            .map_err(|err| err.to_string())
+    }
+
+    #[cfg(feature = "sdl2")]
+    fn current_frame(&self) -> Option<RenderFrame> {
+        self.gui
+            .as_ref()
+            .map(|gui| Self::canvas_to_pixels(&gui.canvas, self.width))
+    }
+
+    #[cfg(not(feature = "sdl2"))]
+    fn current_frame(&self) -> Option<RenderFrame> {
+        self.gui.as_ref().map(Canvas::to_frame)
     }
 
     /// Outputs the width of the internal screen generated.
@@ -130,10 +198,16 @@ impl Screen {
         self.width
     }
 
+    /// Outputs the height of the internal screen generated.
+    pub fn screen_height(&self) -> u32 {
+        self.height
+    }
+
     /// Draws new content on the canvas using the closure and transformation instructions provided.
+    #[cfg(feature = "sdl2")]
     pub fn draw_on_canvas(
         &mut self,
-        using_fn: impl FnMut(&mut WindowCanvas),
+        using_fn: impl FnMut(&mut Canvas),
         with_transformations: ScreenGuiTransformations,
     ) {
         if let Some(ScreenGui { canvas, .. }) = self.gui.as_mut() {
@@ -160,7 +234,33 @@ impl Screen {
         }
     }
 
+    /// Writes the text with its top-left corner at the given screen coordinates, on top of the
+    /// content last drawn on the canvas.
+    ///
+    /// The text uses the built-in 8x8 font of SDL2_gfx, enlarged by whole multiples of
+    /// `font_size / 8`.
+    #[cfg(feature = "sdl2")]
+    pub fn draw_text(&mut self, text: &str, x: i32, y: i32, color: Color, font_size: u16) {
+        if let Some(ScreenGui { canvas, .. }) = self.gui.as_mut() {
+            let scale = (font_size / 8).max(1);
+            let to_scaled = |value: i32| {
+                (value / i32::from(scale)).clamp(i16::MIN.into(), i16::MAX.into()) as i16
+            };
+
+            canvas
+                .set_scale(scale.into(), scale.into())
+                .expect("Was unable to scale the text.");
+            canvas
+                .string(to_scaled(x), to_scaled(y), text, color)
+                .expect("Was unable to draw the text.");
+            canvas
+                .set_scale(1., 1.)
+                .expect("Was unable to restore the scale.");
+        }
+    }
+
     /// Processes all events found in the queue.
+    #[cfg(feature = "sdl2")]
     pub fn consume_events(&mut self) {
         if let Some(ScreenGui { event_pump, .. }) = self.gui.as_mut() {
             for event in event_pump.poll_iter() {
@@ -171,7 +271,26 @@ impl Screen {
         }
     }
 
+    /// Blocks until a key is pressed, closing the screen if it was the escape key.
+    #[cfg(feature = "sdl2")]
+    pub fn pause_on_keypress(&mut self) {
+        if let Some(ScreenGui { event_pump, .. }) = self.gui.as_mut() {
+            loop {
+                match event_pump.wait_event() {
+                    Event::Quit { .. } => panic!("Animation was forced to exit."),
+                    Event::KeyDown {
+                        keycode: Some(Keycode::Escape),
+                        ..
+                    } => break self.close(),
+                    Event::KeyDown { .. } => break,
+                    _ => {}
+                }
+            }
+        }
+    }
+
     /// Generates a window to begin displaying content on.
+    #[cfg(feature = "sdl2")]
     pub fn load_gui(&mut self) {
         if self.gui.is_none() {
             let title = self.title;
@@ -213,4 +332,174 @@ impl Screen {
             self.gui = Some(gui);
         }
     }
+
+    /// Draws new content on the off-screen buffer using the closure provided.
+    ///
+    /// Only the flips of the transformation instructions are applied to the buffer.
+    #[cfg(not(feature = "sdl2"))]
+    pub fn draw_on_canvas(
+        &mut self,
+        mut using_fn: impl FnMut(&mut Canvas),
+        with_transformations: ScreenGuiTransformations,
+    ) {
+        let ScreenGuiTransformations {
+            src,
+            dst,
+            angle,
+            center,
+            ..
+        } = with_transformations;
+        if src.is_some() || dst.is_some() || angle != 0. || center.is_some() {
+            warn!("Only flips are applied when drawing without the `sdl2` feature.");
+        }
+
+        if let Some(canvas) = self.gui.as_mut() {
+            let mut texture = Canvas::new(self.width, self.height);
+            using_fn(&mut texture);
+            *canvas = texture.flipped(
+                with_transformations.flip_horizontal,
+                with_transformations.flip_vertical,
+            );
+        }
+    }
+
+    /// Writes the text with its top-left corner at the given screen coordinates, on top of the
+    /// content last drawn on the buffer.
+    ///
+    /// The text uses a built-in 8x8 font, enlarged by whole multiples of `font_size / 8`.
+    #[cfg(not(feature = "sdl2"))]
+    pub fn draw_text(&mut self, text: &str, x: i32, y: i32, color: Color, font_size: u16) {
+        if let Some(canvas) = self.gui.as_mut() {
+            canvas.draw_text(x, y, text, color, (font_size / 8).max(1).into());
+        }
+    }
+
+    /// Processes all events found in the queue, of which there are none without a window.
+    #[cfg(not(feature = "sdl2"))]
+    pub fn consume_events(&mut self) {}
+
+    /// Returns immediately, as no keys can be pressed without a window.
+    #[cfg(not(feature = "sdl2"))]
+    pub fn pause_on_keypress(&mut self) {}
+
+    /// Allocates the off-screen buffer to begin drawing content on.
+    ///
+    /// Panics when asked to display a window, as that requires the `sdl2` feature.
+    #[cfg(not(feature = "sdl2"))]
+    pub fn load_gui(&mut self) {
+        assert!(
+            self.mode != RenderMode::Human,
+            "Rendering {} to a window requires SDL2, enable the `sdl2` feature of gym-rs.",
+            self.title
+        );
+
+        if self.gui.is_none() {
+            self.gui = Some(Canvas::new(self.width, self.height));
+        }
+    }
+}
+
+#[cfg(all(test, not(feature = "sdl2")))]
+mod tests {
+    use super::{Screen, ScreenGuiTransformations};
+    use crate::utils::{
+        custom::canvas::{Canvas, Color},
+        renderer::{RenderColor, RenderMode, Renders},
+    };
+
+    #[test]
+    fn given_step_mode_without_window_when_rendering_then_frame_is_returned_without_pausing() {
+        let mut screen = Screen::new_step_mode(4, 3, "Test", 30, RenderMode::RgbArray);
+        screen.load_gui();
+
+        assert!(screen.step_mode);
+        assert!(matches!(
+            screen.render(RenderMode::RgbArray),
+            Renders::SingleRgbArray(_)
+        ));
+        assert!(screen.is_open());
+    }
+
+    #[test]
+    fn given_no_sdl2_when_rendering_rgb_array_then_off_screen_frame_is_returned() {
+        let mut screen = Screen::new(4, 3, "Test", 30, RenderMode::RgbArray);
+        screen.load_gui();
+
+        screen.draw_on_canvas(
+            |canvas: &mut Canvas| {
+                canvas.set_draw_color(Color::WHITE);
+                canvas.clear();
+            },
+            ScreenGuiTransformations::default(),
+        );
+
+        match screen.render(RenderMode::RgbArray) {
+            Renders::SingleRgbArray(frame) => {
+                assert_eq!(frame.0.len(), 3);
+                assert!(frame.0.iter().all(|row| row.len() == 4));
+                assert_eq!(frame.0[0][0], RenderColor::RGB(255, 255, 255));
+            }
+            other => panic!("Expected a single frame, received {:?}.", other),
+        }
+    }
+
+    #[test]
+    fn given_various_coordinates_when_drawing_text_then_only_visible_text_is_drawn() {
+        let mut screen = Screen::new(80, 40, "Test", 30, RenderMode::RgbArray);
+        screen.load_gui();
+        screen.draw_on_canvas(
+            |canvas: &mut Canvas| {
+                canvas.set_draw_color(Color::WHITE);
+                canvas.clear();
+            },
+            ScreenGuiTransformations::default(),
+        );
+
+        for (x, y) in [
+            (-100, -100),
+            (i32::MAX, i32::MIN),
+            (80, 0),
+            (0, 40),
+            (i32::MIN, 0),
+        ] {
+            screen.draw_text("Step: 42", x, y, Color::BLACK, 16);
+        }
+        let is_blank = |screen: &mut Screen| match screen.render(RenderMode::RgbArray) {
+            Renders::SingleRgbArray(frame) => frame
+                .0
+                .iter()
+                .flatten()
+                .all(|pixel| *pixel == RenderColor::RGB(255, 255, 255)),
+            other => panic!("Expected a single frame, received {:?}.", other),
+        };
+        assert!(is_blank(&mut screen));
+
+        screen.draw_text("Step: 42", 0, 0, Color::BLACK, 8);
+        assert!(!is_blank(&mut screen));
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn given_screen_when_screenshot_taken_then_png_is_written_once_loaded() {
+        let path = std::env::temp_dir().join("gym_rs_screen_screenshot.png");
+        let mut screen = Screen::new(4, 3, "Test", 30, RenderMode::RgbArray);
+
+        assert_eq!(
+            screen.screenshot(&path),
+            Err("Screen not initialized".to_string())
+        );
+
+        screen.load_gui();
+        screen.screenshot(&path).unwrap();
+        let image = image::open(&path).unwrap().to_rgb8();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!((image.width(), image.height()), (4, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "enable the `sdl2` feature")]
+    fn given_no_sdl2_when_loading_human_gui_then_panics() {
+        Screen::new(4, 3, "Test", 30, RenderMode::Human).load_gui();
+    }
 }
@@ -9,11 +9,47 @@ use crate::utils::renderer::RenderMode;
 ///
 /// Can be dynamically altered and outputted during a state output to describe
 /// the contents of the state further.
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Ord, PartialOrd, Copy, new)]
+#[derive(Debug, Clone, Serialize, PartialEq, PartialOrd, Copy, new)]
 pub struct Metadata<T> {
     /// Defines the render modes supported by the environment.
     pub render_modes: &'static [RenderMode],
     /// Defines the fps used by the internal renderer.
     pub render_fps: u32,
+    /// The average total reward over which the environment is considered solved, if any.
+    #[new(default)]
+    pub reward_threshold: Option<f64>,
+    /// The number of steps after which an episode is truncated, if any.
+    #[new(default)]
+    pub max_episode_steps: Option<usize>,
     marker: PhantomData<T>,
 }
+
+impl<T> Metadata<T> {
+    /// Produces the same set of properties, describing another environment such as a wrapper
+    /// around the original.
+    pub fn cast<U>(&self) -> Metadata<U> {
+        Metadata {
+            render_modes: self.render_modes,
+            render_fps: self.render_fps,
+            reward_threshold: self.reward_threshold,
+            max_episode_steps: self.max_episode_steps,
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets the average total reward over which the environment is considered solved.
+    pub fn with_reward_threshold(self, reward_threshold: f64) -> Self {
+        Self {
+            reward_threshold: Some(reward_threshold),
+            ..self
+        }
+    }
+
+    /// Sets the number of steps after which an episode is truncated.
+    pub fn with_max_episode_steps(self, max_episode_steps: usize) -> Self {
+        Self {
+            max_episode_steps: Some(max_episode_steps),
+            ..self
+        }
+    }
+}
@@ -1,9 +1,32 @@
-use rand::{distributions::uniform::SampleUniform, Rng};
+use rand::{distributions::Uniform, prelude::Distribution, Rng};
 
 use crate::spaces::BoxR;
 
 /// Defines a set of operations to sample an observation for an environment.
-pub trait Sample: SampleUniform {
+pub trait Sample: Sized {
     /// Retrieves a randomly generated observation between the given bounds.
     fn sample_between<R: Rng>(rng: &mut R, bounds: Option<BoxR<Self>>) -> Self;
 }
+
+impl Sample for f64 {
+    /// Draws a value uniformly between the bounds, which must always be provided.
+    fn sample_between<R: Rng>(rng: &mut R, bounds: Option<BoxR<Self>>) -> Self {
+        let BoxR { low, high } = bounds.expect("Sampling a scalar requires bounds to be provided.");
+
+        Uniform::new(low, high).sample(rng)
+    }
+}
+
+impl Sample for Vec<f64> {
+    /// Draws each element uniformly between the associated elements of the bounds.
+    ///
+    /// Since the dimensionality cannot be inferred without them, bounds must always be provided.
+    fn sample_between<R: Rng>(rng: &mut R, bounds: Option<BoxR<Self>>) -> Self {
+        let BoxR { low, high } = bounds.expect("Sampling a vector requires bounds to be provided.");
+
+        low.iter()
+            .zip(high.iter())
+            .map(|(low, high)| Uniform::new(low, high).sample(rng))
+            .collect()
+    }
+}
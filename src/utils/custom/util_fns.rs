@@ -1,3 +1,15 @@
+use std::f64::consts::PI;
+
+use rand::Rng;
+
+/// Draws a value from the standard normal distribution using the Box-Muller transform.
+pub fn sample_standard_normal<R: Rng>(rng: &mut R) -> f64 {
+    let u1: f64 = 1. - rng.gen::<f64>();
+    let u2: f64 = rng.gen();
+
+    (-2. * u1.ln()).sqrt() * (2. * PI * u2).cos()
+}
+
 /// Clips a value between the left and right bounds.
 pub fn clip<T: PartialEq + PartialOrd>(value: T, left_bound: T, right_bound: T) -> T {
     if left_bound <= value && value <= right_bound {
@@ -9,6 +21,12 @@ pub fn clip<T: PartialEq + PartialOrd>(value: T, left_bound: T, right_bound: T)
     }
 }
 
+/// Linearly maps a value from the range `[from_low, from_high]` onto the range
+/// `[to_low, to_high]`.
+pub fn scale(value: f64, from_low: f64, from_high: f64, to_low: f64, to_high: f64) -> f64 {
+    to_low + (value - from_low) * (to_high - to_low) / (from_high - from_low)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::utils::custom::util_fns::clip;
@@ -0,0 +1,185 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use serde::Serialize;
+
+use super::replay_buffer::Transition;
+
+/// Records the transitions of complete episodes, independently of the environment producing them.
+///
+/// Transitions are appended to the current episode until [`EpisodeRecorder::finish_episode`] is
+/// called, which moves the episode into the list of recorded episodes.
+#[derive(Debug, Clone, Serialize)]
+pub struct EpisodeRecorder<O: Clone, A: Clone> {
+    episodes: Vec<Vec<Transition<O, A>>>,
+    current: Vec<Transition<O, A>>,
+}
+
+impl<O: Clone, A: Clone> Default for EpisodeRecorder<O, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<O: Clone, A: Clone> EpisodeRecorder<O, A> {
+    /// Creates a recorder holding no episodes.
+    pub fn new() -> Self {
+        Self {
+            episodes: Vec::new(),
+            current: Vec::new(),
+        }
+    }
+
+    /// Appends a transition to the current episode.
+    pub fn record(&mut self, obs: O, action: A, reward: f64, next_obs: O, done: bool) {
+        self.current
+            .push(Transition::new(obs, action, reward, next_obs, done));
+    }
+
+    /// Ends the current episode, storing it if it holds any transitions.
+    pub fn finish_episode(&mut self) {
+        if !self.current.is_empty() {
+            self.episodes.push(std::mem::take(&mut self.current));
+        }
+    }
+
+    /// Provides the finished episodes, in the order they were recorded.
+    pub fn episodes(&self) -> &[Vec<Transition<O, A>>] {
+        &self.episodes
+    }
+
+    /// Writes the finished episodes to the file at the given path, as a JSON array holding an
+    /// array of transitions per episode.
+    #[cfg(feature = "json")]
+    pub fn export_json(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>>
+    where
+        O: Serialize,
+        A: Serialize,
+    {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, &self.episodes)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Writes the finished episodes to the file at the given path as CSV, using `obs_to_row` to
+    /// flatten observations into columns.
+    ///
+    /// Each row holds the episode and step indices, the observation, the action, the reward, the
+    /// next observation and whether the episode ended, preceded by a header naming the columns
+    /// after the first observation.
+    pub fn export_csv<F>(&self, path: &Path, obs_to_row: F) -> Result<(), io::Error>
+    where
+        A: Display,
+        F: Fn(&O) -> Vec<f64>,
+    {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        let width = self
+            .episodes
+            .first()
+            .and_then(|episode| episode.first())
+            .map_or(0, |transition| obs_to_row(&transition.observation).len());
+        let columns =
+            |prefix: &'static str| (0..width).map(move |index| format!("{prefix}_{index}"));
+        let header: Vec<String> = ["episode".to_string(), "step".to_string()]
+            .into_iter()
+            .chain(columns("obs"))
+            .chain(["action".to_string(), "reward".to_string()])
+            .chain(columns("next_obs"))
+            .chain(["done".to_string()])
+            .collect();
+        writeln!(writer, "{}", header.join(","))?;
+
+        for (episode, transitions) in self.episodes.iter().enumerate() {
+            for (step, transition) in transitions.iter().enumerate() {
+                let row: Vec<String> = [episode.to_string(), step.to_string()]
+                    .into_iter()
+                    .chain(
+                        obs_to_row(&transition.observation)
+                            .iter()
+                            .map(f64::to_string),
+                    )
+                    .chain([transition.action.to_string(), transition.reward.to_string()])
+                    .chain(
+                        obs_to_row(&transition.next_observation)
+                            .iter()
+                            .map(f64::to_string),
+                    )
+                    .chain([transition.done.to_string()])
+                    .collect();
+                writeln!(writer, "{}", row.join(","))?;
+            }
+        }
+
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::EpisodeRecorder;
+    use crate::{
+        core::Env,
+        envs::classical_control::cartpole::{CartPoleAction, CartPoleEnv, CartPoleObservation},
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_three_cart_pole_episodes_when_recorded_then_episode_boundaries_are_kept() {
+        let mut env = CartPoleEnv::new(RenderMode::None);
+        let mut recorder = EpisodeRecorder::<CartPoleObservation, CartPoleAction>::new();
+
+        for seed in 0..3 {
+            let (mut observation, _) = env.reset(Some(seed), false, None);
+            loop {
+                let action = env.sample_action();
+                let action_reward = env.step(action);
+                recorder.record(
+                    observation,
+                    action,
+                    action_reward.reward.into_inner(),
+                    action_reward.observation,
+                    action_reward.done,
+                );
+                observation = action_reward.observation;
+                if action_reward.done {
+                    break;
+                }
+            }
+            recorder.finish_episode();
+        }
+        recorder.finish_episode();
+
+        assert_eq!(recorder.episodes().len(), 3);
+        for episode in recorder.episodes() {
+            let (last, rest) = episode.split_last().unwrap();
+            assert!(last.done);
+            assert!(rest.iter().all(|transition| !transition.done));
+            for (previous, next) in episode.iter().zip(episode.iter().skip(1)) {
+                assert_eq!(previous.next_observation, next.observation);
+            }
+        }
+
+        let path = std::env::temp_dir().join("gym_rs_episode_recorder.csv");
+        recorder
+            .export_csv(&path, |observation| Vec::from(*observation))
+            .unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let transitions: usize = recorder.episodes().iter().map(Vec::len).sum();
+        assert_eq!(contents.lines().count(), transitions + 1);
+        assert_eq!(
+            contents.lines().next().unwrap().split(',').count(),
+            2 + 4 + 2 + 4 + 1
+        );
+    }
+}
@@ -0,0 +1,91 @@
+/// Computes the generalized advantage estimate of every step of a rollout, which may span several
+/// episodes.
+///
+/// The advantage of step `t` is `delta_t + (gamma * lam) * delta_{t+1} + ...`, where
+/// `delta_t = r_t + gamma * V(s_{t+1}) * (1 - done_t) - V(s_t)`, and the sum stops at the end
+/// of the episode the step belongs to.
+///
+/// `values` holds the value of the observation each step was taken from, optionally followed by
+/// the value of the observation after the last step. Without it, the last step bootstraps from
+/// `0`, as it does whenever the step is done.
+pub fn compute_gae(
+    rewards: &[f64],
+    values: &[f64],
+    dones: &[bool],
+    gamma: f64,
+    lam: f64,
+) -> Vec<f64> {
+    assert_eq!(
+        rewards.len(),
+        dones.len(),
+        "Every reward must be paired with whether its step was done."
+    );
+    assert!(
+        values.len() == rewards.len() || values.len() == rewards.len() + 1,
+        "Expected a value for every step, optionally followed by a bootstrap value."
+    );
+
+    let mut advantages = vec![0.; rewards.len()];
+    let mut advantage = 0.;
+    for t in (0..rewards.len()).rev() {
+        let not_done = if dones[t] { 0. } else { 1. };
+        let next_value = values.get(t + 1).copied().unwrap_or(0.);
+        let delta = rewards[t] + gamma * next_value * not_done - values[t];
+        advantage = delta + gamma * lam * not_done * advantage;
+        advantages[t] = advantage;
+    }
+
+    advantages
+}
+
+/// Computes the discounted Monte Carlo return of every step of a rollout, which may span several
+/// episodes, restarting the sum at the end of each episode.
+pub fn compute_returns(rewards: &[f64], dones: &[bool], gamma: f64) -> Vec<f64> {
+    assert_eq!(
+        rewards.len(),
+        dones.len(),
+        "Every reward must be paired with whether its step was done."
+    );
+
+    let mut returns = vec![0.; rewards.len()];
+    let mut future_return = 0.;
+    for t in (0..rewards.len()).rev() {
+        if dones[t] {
+            future_return = 0.;
+        }
+        future_return = rewards[t] + gamma * future_return;
+        returns[t] = future_return;
+    }
+
+    returns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compute_gae, compute_returns};
+
+    const REWARDS: [f64; 4] = [1., 2., 3., 4.];
+    const VALUES: [f64; 4] = [0.5, 1., 1.5, 2.];
+    const DONES: [bool; 4] = [false, true, false, true];
+
+    #[test]
+    fn given_no_lambda_when_computing_gae_then_advantages_are_td_errors() {
+        let advantages = compute_gae(&REWARDS, &VALUES, &DONES, 0.5, 0.);
+
+        assert_eq!(
+            advantages,
+            vec![1. + 0.5 * 1. - 0.5, 2. - 1., 3. + 0.5 * 2. - 1.5, 4. - 2.]
+        );
+    }
+
+    #[test]
+    fn given_full_lambda_when_computing_gae_then_advantages_are_returns_minus_values() {
+        let advantages = compute_gae(&REWARDS, &VALUES, &DONES, 0.5, 1.);
+        let returns = compute_returns(&REWARDS, &DONES, 0.5);
+
+        assert_eq!(returns, vec![2., 2., 5., 4.]);
+        for ((advantage, value), expected) in advantages.iter().zip(VALUES).zip(returns) {
+            assert_eq!(advantage + value, expected);
+        }
+    }
+}
@@ -1,7 +1,25 @@
 /// Holds custom namespaces, unique to this project, which handle common operations like rendering onto GUIs or
 /// standardize sampling.
 pub mod custom;
+/// Holds structures which record complete episodes for offline analysis.
+pub mod episode_recorder;
+/// Holds functions which estimate the advantage of each step of a rollout.
+pub mod gae;
+/// Holds structures which store experience, sampled by priority, for off-policy learning.
+pub mod priority_replay_buffer;
 /// Holds utilty namespaces to handle different rendering modes.
 pub mod renderer;
+/// Holds structures which store experience for off-policy learning.
+pub mod replay_buffer;
+/// Holds functions which compute the discounted returns of reward sequences.
+pub mod returns;
+/// Holds functions and wrappers which shape rewards using potentials.
+pub mod reward_shaping;
+/// Holds functions which collect episodes by following a policy.
+pub mod rollout;
+/// Holds structures which compute statistics over a stream of values.
+pub mod running_mean_std;
 /// Holds utility namespaces to handle seeding of environments.
 pub mod seeding;
+/// Holds structures which store the data of complete episodes.
+pub mod trajectory;
@@ -0,0 +1,213 @@
+use rand::Rng;
+use serde::Serialize;
+
+use super::replay_buffer::Transition;
+
+/// A fixed-capacity store of transitions which are sampled in proportion to their priority, as
+/// used by prioritized experience replay (Schaul et al., 2015).
+///
+/// The priorities are held in a sum-tree, such that both updating a priority and drawing a
+/// transition take `O(log n)` time. Priorities are used as given, so any exponent controlling how
+/// strongly they are favoured must be applied beforehand. Once full, the oldest transition is
+/// overwritten.
+#[derive(Debug, Clone, Serialize)]
+pub struct PriorityReplayBuffer<O: Clone, A: Clone> {
+    capacity: usize,
+    transitions: Vec<Transition<O, A>>,
+    /// The slot which the next transition is stored in.
+    next: usize,
+    /// The sum-tree, whose root is at index `1` and whose leaves, at indices `capacity` onwards,
+    /// hold the priority of each slot. Every other node holds the sum of its two children.
+    tree: Vec<f64>,
+}
+
+impl<O: Clone, A: Clone> PriorityReplayBuffer<O, A> {
+    /// Creates an empty buffer holding at most `capacity` transitions.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "The capacity of a replay buffer must be positive."
+        );
+
+        Self {
+            capacity,
+            transitions: Vec::with_capacity(capacity),
+            next: 0,
+            tree: vec![0.; 2 * capacity],
+        }
+    }
+
+    /// Stores the transition with the given priority, overwriting the oldest one if the buffer is
+    /// full.
+    pub fn push(&mut self, transition: Transition<O, A>, priority: f64) {
+        if self.is_full() {
+            self.transitions[self.next] = transition;
+        } else {
+            self.transitions.push(transition);
+        }
+        self.set_priority(self.next, priority);
+        self.next = (self.next + 1) % self.capacity;
+    }
+
+    /// Draws `n` transitions, with replacement, in proportion to their priority.
+    ///
+    /// Returns the transitions alongside their importance sampling weights, `(N * P(i))^-beta`
+    /// normalized by the largest weight of the batch, and their indices within the buffer, as
+    /// expected by [`PriorityReplayBuffer::update_priorities`]. The range of priorities is split
+    /// into `n` segments of equal mass, each of which one transition is drawn from.
+    ///
+    /// Panics if the buffer holds no transition with a positive priority.
+    pub fn sample<R: Rng>(
+        &self,
+        rng: &mut R,
+        n: usize,
+        beta: f64,
+    ) -> (Vec<&Transition<O, A>>, Vec<f64>, Vec<usize>) {
+        let total = self.total_priority();
+        assert!(
+            total > 0.,
+            "Cannot sample from a buffer without any positive priority."
+        );
+
+        let segment = total / n as f64;
+        let indices: Vec<usize> = (0..n)
+            .map(|i| self.find(rng.gen_range(segment * i as f64..segment * (i + 1) as f64)))
+            .collect();
+
+        let weights: Vec<f64> = indices
+            .iter()
+            .map(|&i| (self.len() as f64 * self.priority(i) / total).powf(-beta))
+            .collect();
+        let max_weight = weights.iter().copied().fold(f64::MIN, f64::max);
+        let weights = weights.iter().map(|weight| weight / max_weight).collect();
+
+        let transitions = indices.iter().map(|&i| &self.transitions[i]).collect();
+
+        (transitions, weights, indices)
+    }
+
+    /// Replaces the priorities of the transitions at the given indices, such as after their
+    /// temporal difference errors were recomputed.
+    pub fn update_priorities(&mut self, indices: &[usize], priorities: &[f64]) {
+        assert_eq!(
+            indices.len(),
+            priorities.len(),
+            "Every index must be paired with a priority."
+        );
+
+        for (&index, &priority) in indices.iter().zip(priorities) {
+            assert!(
+                index < self.len(),
+                "No transition is stored at index {}.",
+                index
+            );
+            self.set_priority(index, priority);
+        }
+    }
+
+    /// The priority of the transition at the given index.
+    pub fn priority(&self, index: usize) -> f64 {
+        self.tree[self.capacity + index]
+    }
+
+    /// The sum of the priorities of every stored transition.
+    pub fn total_priority(&self) -> f64 {
+        self.tree[1]
+    }
+
+    /// The maximum number of transitions which can be stored.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of transitions currently stored.
+    pub fn len(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// Checks whether no transition is stored.
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+
+    /// Checks whether storing another transition would overwrite the oldest one.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity
+    }
+
+    /// Updates the leaf of the given slot, then the sums of all of its ancestors.
+    fn set_priority(&mut self, index: usize, priority: f64) {
+        assert!(
+            priority >= 0. && priority.is_finite(),
+            "Priorities must be finite and non-negative, got {}.",
+            priority
+        );
+
+        let mut node = self.capacity + index;
+        self.tree[node] = priority;
+        while node > 1 {
+            node /= 2;
+            self.tree[node] = self.tree[2 * node] + self.tree[2 * node + 1];
+        }
+    }
+
+    /// Descends from the root to the slot whose priority covers the given mass.
+    fn find(&self, mut mass: f64) -> usize {
+        let mut node = 1;
+        while node < self.capacity {
+            let left = 2 * node;
+            if mass < self.tree[left] || self.tree[left + 1] == 0. {
+                node = left;
+            } else {
+                mass -= self.tree[left];
+                node = left + 1;
+            }
+        }
+
+        node - self.capacity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PriorityReplayBuffer;
+    use crate::utils::{replay_buffer::Transition, seeding::rand_random};
+
+    fn transition(i: usize) -> Transition<usize, usize> {
+        Transition::new(i, 0, 1., i + 1, false)
+    }
+
+    #[test]
+    fn given_unequal_priorities_when_sampled_then_frequencies_follow_priorities() {
+        let (mut rng, _) = rand_random(Some(0));
+        let mut buffer = PriorityReplayBuffer::new(3);
+        buffer.push(transition(0), 1.);
+        buffer.push(transition(1), 3.);
+
+        let (transitions, weights, indices) = buffer.sample(&mut rng, 4000, 1.);
+        let frequent = transitions.iter().filter(|t| t.observation == 1).count();
+
+        assert_eq!(frequent, 3000);
+        for (weight, index) in weights.iter().zip(indices) {
+            let expected = if index == 1 { 1. / 3. } else { 1. };
+            assert!((weight - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn given_updated_priorities_when_sampled_then_zero_priority_transitions_are_skipped() {
+        let (mut rng, _) = rand_random(Some(0));
+        let mut buffer = PriorityReplayBuffer::new(5);
+        for i in 0..7 {
+            buffer.push(transition(i), 1.);
+        }
+        assert_eq!(buffer.len(), 5);
+        assert_eq!(buffer.total_priority(), 5.);
+
+        buffer.update_priorities(&[0, 1, 2, 3], &[0., 0., 0., 0.]);
+        let (transitions, _, indices) = buffer.sample(&mut rng, 100, 0.4);
+
+        assert!(indices.iter().all(|&i| i == 4));
+        assert!(transitions.iter().all(|t| t.observation == 4));
+    }
+}
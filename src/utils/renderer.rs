@@ -1,3 +1,7 @@
+#[cfg(any(feature = "png", feature = "gif"))]
+use std::{error::Error, path::Path};
+use std::{fmt::Display, str::FromStr};
+
 use derive_new::new;
 use serde::Serialize;
 
@@ -53,9 +57,7 @@ impl Renderer {
         if self.single_render.contains(&self.mode) {
             render(self.mode)
         } else if self.mode != RenderMode::None && !self.no_returns_render.contains(&self.mode) {
-            let renders = self.render_list.clone();
-            self.render_list = Vec::new();
-            Renders::RgbArray(renders)
+            Renders::RgbArray(self.take_frames())
         } else {
             Renders::None
         }
@@ -65,6 +67,53 @@ impl Renderer {
     pub fn reset(&mut self) {
         self.render_list = Vec::new();
     }
+
+    /// The number of frames collected thus far.
+    pub fn frame_count(&self) -> usize {
+        self.render_list.len()
+    }
+
+    /// The most recent frame collected, without consuming it.
+    pub fn last_frame(&self) -> Option<&RenderFrame> {
+        self.render_list.last()
+    }
+
+    /// Removes and returns every frame collected thus far.
+    pub fn take_frames(&mut self) -> Vec<RenderFrame> {
+        std::mem::take(&mut self.render_list)
+    }
+
+    /// Encodes the frames collected thus far into an endlessly looping GIF written to the given
+    /// path, where each frame is displayed for `frame_delay` hundredths of a second, as defined
+    /// by the GIF specification.
+    ///
+    /// The pixels of each frame are quantized onto a palette of at most 256 colours.
+    #[cfg(feature = "gif")]
+    pub fn export_gif(&self, path: &Path, frame_delay: u16) -> Result<(), Box<dyn Error>> {
+        let first = self
+            .render_list
+            .first()
+            .ok_or("No frames have been collected to export.")?;
+        let height = u16::try_from(first.height())?;
+        let width = u16::try_from(first.width())?;
+
+        let file = std::fs::File::create(path)?;
+        let mut encoder = gif::Encoder::new(file, width, height, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        for render_frame in &self.render_list {
+            let mut pixels = render_frame.as_flat_bytes();
+            if pixels.len() != width as usize * height as usize * 4 {
+                return Err("Every frame must share the dimensions of the first frame.".into());
+            }
+
+            let mut frame = gif::Frame::from_rgba(width, height, &mut pixels);
+            frame.delay = frame_delay;
+            encoder.write_frame(&frame)?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Defines various palettes capabling of describing the colour of a pixel.
@@ -72,14 +121,81 @@ impl Renderer {
 pub enum RenderColor {
     /// Holds the red-green-blue values of some pixel.
     RGB(u8, u8, u8),
+    /// Holds the red-green-blue values of some pixel along with its alpha, where `0` is fully
+    /// transparent and `255` fully opaque.
+    RGBA(u8, u8, u8, u8),
 }
 
 /// A surface which holds pixels describing the contents produced during a render.
 #[derive(Debug, new, Clone, PartialEq, PartialOrd, Eq, Ord, Serialize)]
 pub struct RenderFrame(pub Vec<Vec<RenderColor>>);
 
+impl RenderFrame {
+    /// The number of rows of pixels.
+    pub fn height(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The number of pixels in the first row, or zero if the frame is empty.
+    pub fn width(&self) -> usize {
+        self.0.first().map_or(0, Vec::len)
+    }
+
+    /// The pixel at column `x` of row `y`, if it lies within the frame.
+    pub fn pixel(&self, x: usize, y: usize) -> Option<&RenderColor> {
+        self.0.get(y)?.get(x)
+    }
+
+    /// Flattens the pixels, row by row, into RGBA32 bytes.
+    ///
+    /// Pixels without an alpha channel are fully opaque.
+    pub fn as_flat_bytes(&self) -> Vec<u8> {
+        self.0
+            .iter()
+            .flatten()
+            .flat_map(|color| match *color {
+                RenderColor::RGB(r, g, b) => [r, g, b, u8::MAX],
+                RenderColor::RGBA(r, g, b, a) => [r, g, b, a],
+            })
+            .collect()
+    }
+
+    /// Checks whether any pixel carries an alpha channel.
+    pub fn has_alpha(&self) -> bool {
+        self.0
+            .iter()
+            .flatten()
+            .any(|color| matches!(color, RenderColor::RGBA(..)))
+    }
+
+    /// Writes the frame to the given path as a PNG image.
+    ///
+    /// The dimensions are inferred from the number of rows and the length of the first row. The
+    /// image only holds an alpha channel if any pixel carries one.
+    #[cfg(feature = "png")]
+    pub fn save_png(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        const UNEVEN_ROWS: &str = "Every row of the frame must hold the same number of pixels.";
+        let height = self.height() as u32;
+        let width = self.width() as u32;
+        let pixels = self.as_flat_bytes();
+
+        if self.has_alpha() {
+            image::RgbaImage::from_raw(width, height, pixels)
+                .ok_or(UNEVEN_ROWS)?
+                .save(path)?;
+        } else {
+            let pixels = pixels.chunks(4).flat_map(|pixel| &pixel[..3]).copied();
+            image::RgbImage::from_raw(width, height, pixels.collect())
+                .ok_or(UNEVEN_ROWS)?
+                .save(path)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// A collection of various formats describing the type of content produced during a render.
-#[derive(PartialEq, PartialOrd, Debug, Clone, Copy, Serialize, Eq, Ord)]
+#[derive(PartialEq, PartialOrd, Debug, Clone, Copy, Serialize, Eq, Ord, Default)]
 pub enum RenderMode {
     /// Indicates that that renderer should be done through the terminal or an external display.
     Human,
@@ -93,15 +209,10 @@ pub enum RenderMode {
     /// environments current state
     Ansi,
     /// Indicates that renderer should be skipped.
+    #[default]
     None,
 }
 
-impl Default for RenderMode {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl RenderMode {
     /// Defines an empty set of render modes.
     ///
@@ -110,7 +221,54 @@ impl RenderMode {
     pub const DEFAULT: &'static [RenderMode] = &[];
 
     const NO_RETURNS_RENDER: &'static [RenderMode] = &[RenderMode::Human];
-    const SINGLE_RENDER: &'static [RenderMode] = &[RenderMode::SingleRgbArray];
+    const SINGLE_RENDER: &'static [RenderMode] = &[RenderMode::SingleRgbArray, RenderMode::Ansi];
+
+    /// Lists every render mode.
+    pub fn all() -> &'static [RenderMode] {
+        &[
+            RenderMode::Human,
+            RenderMode::SingleRgbArray,
+            RenderMode::RgbArray,
+            RenderMode::Ansi,
+            RenderMode::None,
+        ]
+    }
+}
+
+/// Parses a render mode from its snake case name, as used by gym, or the name of its variant.
+impl TryFrom<&str> for RenderMode {
+    type Error = String;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        match name {
+            "human" | "Human" => Ok(RenderMode::Human),
+            "single_rgb_array" | "SingleRgbArray" => Ok(RenderMode::SingleRgbArray),
+            "rgb_array" | "RgbArray" => Ok(RenderMode::RgbArray),
+            "ansi" | "Ansi" => Ok(RenderMode::Ansi),
+            "none" | "None" => Ok(RenderMode::None),
+            _ => Err(format!("Unknown render mode: {}", name)),
+        }
+    }
+}
+
+impl FromStr for RenderMode {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::try_from(name)
+    }
+}
+
+impl Display for RenderMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenderMode::Human => write!(f, "human"),
+            RenderMode::SingleRgbArray => write!(f, "single_rgb_array"),
+            RenderMode::RgbArray => write!(f, "rgb_array"),
+            RenderMode::Ansi => write!(f, "ansi"),
+            RenderMode::None => write!(f, "none"),
+        }
+    }
 }
 
 /// Defines a collection of common structures produced during rendering.
@@ -128,3 +286,101 @@ pub enum Renders {
     /// Most commonly used during Human or None mode.
     None,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{RenderColor, RenderFrame, RenderMode, Renderer, Renders};
+
+    fn frame() -> RenderFrame {
+        RenderFrame::new(vec![
+            vec![RenderColor::RGB(255, 0, 0), RenderColor::RGB(0, 255, 0)],
+            vec![RenderColor::RGB(0, 0, 255), RenderColor::RGB(1, 2, 3)],
+        ])
+    }
+
+    #[test]
+    fn given_every_render_mode_when_displayed_then_name_parses_back_to_mode() {
+        for &mode in RenderMode::all() {
+            assert_eq!(mode.to_string().parse::<RenderMode>(), Ok(mode));
+        }
+        assert_eq!(RenderMode::try_from("RgbArray"), Ok(RenderMode::RgbArray));
+        assert_eq!(
+            "rgb".parse::<RenderMode>(),
+            Err("Unknown render mode: rgb".to_string())
+        );
+    }
+
+    #[test]
+    fn given_frame_when_flattened_then_bytes_are_rgba_ordered_row_by_row() {
+        assert_eq!(
+            frame().as_flat_bytes(),
+            vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 1, 2, 3, 255]
+        );
+
+        let translucent = RenderFrame::new(vec![vec![RenderColor::RGBA(1, 2, 3, 4)]]);
+        assert!(translucent.has_alpha() && !frame().has_alpha());
+        assert_eq!(translucent.as_flat_bytes(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn given_frame_when_indexed_then_pixel_is_found_by_column_and_row() {
+        let frame = frame();
+
+        assert_eq!((frame.width(), frame.height()), (2, 2));
+        assert_eq!(frame.pixel(1, 0), Some(&RenderColor::RGB(0, 255, 0)));
+        assert_eq!(frame.pixel(0, 1), Some(&RenderColor::RGB(0, 0, 255)));
+        assert_eq!(frame.pixel(2, 0), None);
+        assert_eq!(RenderFrame::new(vec![]).width(), 0);
+    }
+
+    #[cfg(feature = "gif")]
+    #[test]
+    fn given_collected_frames_when_exported_then_gif_file_is_written() {
+        let path = std::env::temp_dir().join("gym_rs_renderer_export.gif");
+        let mut renderer = Renderer::new(RenderMode::RgbArray, None, None);
+        for _ in 0..3 {
+            renderer.render_step(&mut |_| Renders::SingleRgbArray(frame()));
+        }
+
+        assert_eq!(renderer.frame_count(), 3);
+
+        renderer.export_gif(&path, 5).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(bytes.starts_with(b"GIF89a"));
+    }
+
+    #[test]
+    fn given_three_collected_frames_when_peeking_then_last_frame_is_kept() {
+        let mut renderer = Renderer::new(RenderMode::RgbArray, None, None);
+        for value in 0..3 {
+            renderer.render_step(&mut |_| {
+                Renders::SingleRgbArray(RenderFrame::new(vec![vec![RenderColor::RGB(
+                    value, value, value,
+                )]]))
+            });
+        }
+
+        assert_eq!(
+            renderer.last_frame(),
+            Some(&RenderFrame::new(vec![vec![RenderColor::RGB(2, 2, 2)]]))
+        );
+        assert_eq!(renderer.frame_count(), 3);
+        assert_eq!(renderer.take_frames().len(), 3);
+        assert_eq!(renderer.frame_count(), 0);
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn given_frame_when_saved_as_png_then_reloaded_pixels_match() {
+        let path = std::env::temp_dir().join("gym_rs_render_frame_round_trip.png");
+
+        frame().save_png(&path).unwrap();
+        let image = image::open(&path).unwrap().to_rgba8();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!((image.width(), image.height()), (2, 2));
+        assert_eq!(image.into_raw(), frame().as_flat_bytes());
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::VecDeque;
+
+use derive_new::new;
+use rand::{seq::index, Rng};
+use serde::Serialize;
+
+/// A single step of experience collected from an environment.
+#[derive(Debug, Clone, Serialize, PartialEq, new)]
+pub struct Transition<O, A> {
+    /// The observation the action was taken from.
+    pub observation: O,
+    /// The action taken.
+    pub action: A,
+    /// The reward produced by the action.
+    pub reward: f64,
+    /// The observation produced by the action.
+    pub next_observation: O,
+    /// Indicates whether the episode ended after the action.
+    pub done: bool,
+}
+
+/// A fixed-capacity store of transitions, evicting the oldest transition once full.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayBuffer<O: Clone, A: Clone> {
+    capacity: usize,
+    buffer: VecDeque<Transition<O, A>>,
+}
+
+impl<O: Clone, A: Clone> ReplayBuffer<O, A> {
+    /// Creates an empty buffer holding at most `capacity` transitions.
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "The capacity of a replay buffer must be positive."
+        );
+
+        Self {
+            capacity,
+            buffer: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Stores the transition, evicting the oldest one if the buffer is full.
+    pub fn push(&mut self, transition: Transition<O, A>) {
+        if self.is_full() {
+            self.buffer.pop_front();
+        }
+        self.buffer.push_back(transition);
+    }
+
+    /// Draws `batch_size` distinct transitions uniformly at random.
+    ///
+    /// Panics if fewer than `batch_size` transitions are stored.
+    pub fn sample_batch<R: Rng>(&self, rng: &mut R, batch_size: usize) -> Vec<&Transition<O, A>> {
+        assert!(
+            batch_size <= self.len(),
+            "Cannot sample {} transitions from a buffer holding {}.",
+            batch_size,
+            self.len()
+        );
+
+        index::sample(rng, self.len(), batch_size)
+            .into_iter()
+            .map(|i| &self.buffer[i])
+            .collect()
+    }
+
+    /// The maximum number of transitions which can be stored.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of transitions currently stored.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Checks whether no transition is stored.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Checks whether storing another transition would evict the oldest one.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity
+    }
+
+    /// Removes every stored transition.
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    /// Iterates over the stored transitions, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &Transition<O, A>> {
+        self.buffer.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReplayBuffer, Transition};
+    use crate::utils::seeding::rand_random;
+
+    fn transition(i: usize) -> Transition<usize, usize> {
+        Transition::new(i, 0, 1., i + 1, false)
+    }
+
+    #[test]
+    fn given_full_buffer_when_pushed_then_oldest_transition_is_evicted() {
+        let mut buffer = ReplayBuffer::new(3);
+
+        for i in 0..5 {
+            buffer.push(transition(i));
+        }
+
+        assert!(buffer.is_full());
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(
+            buffer.iter().map(|t| t.observation).collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn given_stored_transitions_when_sampled_then_batch_holds_distinct_transitions() {
+        let (mut rng, _) = rand_random(Some(0));
+        let mut buffer = ReplayBuffer::new(10);
+        for i in 0..10 {
+            buffer.push(transition(i));
+        }
+
+        let mut batch: Vec<_> = buffer
+            .sample_batch(&mut rng, 10)
+            .iter()
+            .map(|t| t.observation)
+            .collect();
+        batch.sort();
+
+        assert_eq!(batch, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot sample 3 transitions from a buffer holding 2.")]
+    fn given_batch_size_greater_than_len_when_sampled_then_panics() {
+        let (mut rng, _) = rand_random(Some(0));
+        let mut buffer = ReplayBuffer::new(10);
+        buffer.push(transition(0));
+        buffer.push(transition(1));
+
+        buffer.sample_batch(&mut rng, 3);
+    }
+}
@@ -0,0 +1,74 @@
+/// Computes the discounted return `G_t = r_t + gamma * r_{t+1} + gamma^2 * r_{t+2} + ...` of
+/// every step of a single episode, bootstrapping from `final_value` after the last step.
+///
+/// `final_value` should be `0` if the episode terminated, or the estimated value of the last
+/// observation otherwise.
+pub fn discount_returns(rewards: &[f64], gamma: f64, final_value: f64) -> Vec<f64> {
+    let mut returns = vec![0.; rewards.len()];
+    let mut future_return = final_value;
+    for (t, reward) in rewards.iter().enumerate().rev() {
+        future_return = reward + gamma * future_return;
+        returns[t] = future_return;
+    }
+
+    returns
+}
+
+/// Computes the `n`-step temporal difference target of every step of a single episode, summing
+/// the next `n` discounted rewards and bootstrapping from the value of the observation reached.
+///
+/// `values` holds the value of the observation each step was taken from, optionally followed by
+/// the value of the observation after the last step. Without it, targets reaching past the last
+/// step bootstrap from `0`.
+pub fn n_step_returns(rewards: &[f64], values: &[f64], gamma: f64, n: usize) -> Vec<f64> {
+    assert!(n > 0, "Targets must span at least one step.");
+    assert!(
+        values.len() == rewards.len() || values.len() == rewards.len() + 1,
+        "Expected a value for every step, optionally followed by a bootstrap value."
+    );
+
+    (0..rewards.len())
+        .map(|t| {
+            let end = (t + n).min(rewards.len());
+            let bootstrap = values.get(end).copied().unwrap_or(0.);
+            rewards[t..end]
+                .iter()
+                .rev()
+                .fold(bootstrap, |future_return, reward| {
+                    reward + gamma * future_return
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{discount_returns, n_step_returns};
+
+    #[test]
+    fn given_terminated_episode_when_discounting_then_returns_match_expected_values() {
+        let returns = discount_returns(&[1., 1., 1.], 0.99, 0.);
+
+        let expected = [2.9701, 1.99, 1.];
+        for (value, expected) in returns.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+        assert!(discount_returns(&[], 0.99, 5.).is_empty());
+    }
+
+    #[test]
+    fn given_two_step_targets_when_computed_then_values_bootstrap_after_two_rewards() {
+        let returns = n_step_returns(&[1., 2., 3.], &[10., 20., 30., 40.], 0.5, 2);
+
+        assert_eq!(
+            returns,
+            vec![
+                1. + 0.5 * 2. + 0.25 * 30.,
+                2. + 0.5 * 3. + 0.25 * 40.,
+                3. + 0.5 * 40.
+            ]
+        );
+        assert_eq!(n_step_returns(&[1.], &[10.], 0.5, 3), vec![1.]);
+        assert!(n_step_returns(&[], &[], 0.5, 1).is_empty());
+    }
+}
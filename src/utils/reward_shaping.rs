@@ -0,0 +1,216 @@
+use derivative::Derivative;
+use ordered_float::OrderedFloat;
+use serde::Serialize;
+
+use crate::{
+    core::{ActionReward, Env, EnvProperties, RewardRange},
+    envs::classical_control::mountain_car::{MountainCarEnv, MountainCarObservation},
+    envs::wrappers::forward_env_properties,
+    spaces::BoxR,
+    utils::{
+        custom::structs::Metadata,
+        renderer::{RenderMode, Renders},
+    },
+};
+
+/// Shapes a reward using the difference in potential between two consecutive states, as
+/// `original_reward + gamma * potential_next - potential_current`.
+///
+/// Shaping with a potential preserves the optimal policies of the environment (Ng et al., 1999).
+pub fn shape_reward(
+    original_reward: f64,
+    potential_current: f64,
+    potential_next: f64,
+    gamma: f64,
+) -> f64 {
+    original_reward + gamma * potential_next - potential_current
+}
+
+/// A potential for mountain car, being the mechanical energy of the car in the given
+/// observation, as computed by [`MountainCarEnv::energy`].
+pub fn energy_potential(obs: &MountainCarObservation, env: &MountainCarEnv) -> f64 {
+    env.energy_at(obs.position, obs.velocity)
+}
+
+/// A wrapper which shapes every reward produced by the environment using the potential of the
+/// observations before and after each step.
+///
+/// The potential of a terminal observation is taken to be `0`, so the shaped return of an episode
+/// differs from the original return only by the potential of its initial observation. Since
+/// shaped rewards are unbounded in general, the reward range spans all values.
+///
+/// # Examples
+///
+/// ```rust
+/// use gym_rs::{
+///     core::Env,
+///     envs::classical_control::mountain_car::{MountainCarAction, MountainCarEnv},
+///     utils::{
+///         renderer::RenderMode,
+///         reward_shaping::{energy_potential, RewardShaper},
+///     },
+/// };
+///
+/// let env = MountainCarEnv::new(RenderMode::None);
+/// let parameters = env.clone();
+/// let mut env = RewardShaper::new(env, move |obs| energy_potential(obs, &parameters), 0.99);
+///
+/// env.reset(Some(0), false, None);
+/// env.step(MountainCarAction::Forward);
+/// ```
+#[derive(Derivative, Clone, Serialize)]
+#[derivative(Debug)]
+#[serde(bound = "E: Serialize")]
+pub struct RewardShaper<E, P> {
+    /// The environment being wrapped.
+    pub env: E,
+    /// The discount factor applied to the potential of the next observation.
+    pub gamma: f64,
+    #[serde(skip_serializing)]
+    #[derivative(Debug = "ignore")]
+    potential: P,
+    current_potential: f64,
+    reward_range: RewardRange,
+    metadata: Metadata<Self>,
+}
+
+impl<E: Env, P: Fn(&E::Observation) -> f64> RewardShaper<E, P> {
+    /// Wraps the given environment, shaping its rewards with the given potential.
+    pub fn new(env: E, potential: P, gamma: f64) -> Self {
+        let metadata = env.metadata().cast();
+
+        Self {
+            env,
+            gamma,
+            potential,
+            current_potential: 0.,
+            reward_range: RewardRange::default(),
+            metadata,
+        }
+    }
+}
+
+impl<E: Env, P: Fn(&E::Observation) -> f64 + Clone> Env for RewardShaper<E, P> {
+    type Action = E::Action;
+    type Info = E::Info;
+    type ResetInfo = E::ResetInfo;
+
+    fn step(&mut self, action: Self::Action) -> ActionReward<Self::Observation, Self::Info> {
+        let action_reward = self.env.step(action);
+
+        let potential = (self.potential)(&action_reward.observation);
+        let terminated = action_reward.done && !action_reward.truncated;
+        let potential_next = if terminated { 0. } else { potential };
+        let reward = shape_reward(
+            action_reward.reward.into_inner(),
+            self.current_potential,
+            potential_next,
+            self.gamma,
+        );
+        self.current_potential = potential;
+
+        ActionReward {
+            reward: OrderedFloat(reward),
+            ..action_reward
+        }
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        return_info: bool,
+        options: Option<BoxR<Self::Observation>>,
+    ) -> (Self::Observation, Option<Self::ResetInfo>) {
+        let (observation, info) = self.env.reset(seed, return_info, options);
+        self.current_potential = (self.potential)(&observation);
+
+        (observation, info)
+    }
+
+    fn reset_to_state(&mut self, state: Self::Observation) -> Self::Observation {
+        let observation = self.env.reset_to_state(state);
+        self.current_potential = (self.potential)(&observation);
+
+        observation
+    }
+
+    fn render(&mut self, mode: RenderMode) -> Renders {
+        self.env.render(mode)
+    }
+
+    fn close(&mut self) {
+        self.env.close();
+    }
+}
+
+impl<E: Env, P: Fn(&E::Observation) -> f64 + Clone> EnvProperties for RewardShaper<E, P> {
+    type Observation = E::Observation;
+    type ActionSpace = E::ActionSpace;
+    type ObservationSpace = E::ObservationSpace;
+
+    forward_env_properties!(
+        E;
+        spec,
+        metadata,
+        rand_random,
+        render_mode,
+        episode_length,
+        is_terminated,
+        is_truncated,
+        step_count,
+        action_space,
+        observation_space
+    );
+
+    fn reward_range(&self) -> &RewardRange {
+        &self.reward_range
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+
+    use super::{energy_potential, shape_reward, RewardShaper};
+    use crate::{
+        core::Env,
+        envs::classical_control::mountain_car::{
+            MountainCarAction, MountainCarEnv, MountainCarObservation,
+        },
+        utils::renderer::RenderMode,
+    };
+
+    #[test]
+    fn given_potentials_when_shaping_then_discounted_difference_is_added() {
+        assert_eq!(shape_reward(1., 2., 4., 0.5), 1.);
+        assert_eq!(shape_reward(-1., 0., 0., 0.99), -1.);
+    }
+
+    #[test]
+    fn given_goal_reaching_policy_when_shaped_then_return_only_differs_by_initial_potential() {
+        let env = MountainCarEnv::new(RenderMode::None);
+        let parameters = env.clone();
+        let mut env = RewardShaper::new(env, move |obs| energy_potential(obs, &parameters), 1.);
+        let start = MountainCarObservation::new(OrderedFloat(-0.5), OrderedFloat(0.));
+        env.reset_to_state(start);
+
+        let (mut original_return, mut shaped_return) = (0., 0.);
+        loop {
+            let action = if env.env.state.velocity >= OrderedFloat(0.) {
+                MountainCarAction::Forward
+            } else {
+                MountainCarAction::Backward
+            };
+            let action_reward = env.step(action);
+            original_return -= 1.;
+            shaped_return += action_reward.reward.into_inner();
+            if action_reward.done {
+                assert!(!action_reward.truncated);
+                break;
+            }
+        }
+
+        let initial_potential = energy_potential(&start, &env.env);
+        assert!((shaped_return - (original_return - initial_potential)).abs() < 1e-9);
+    }
+}
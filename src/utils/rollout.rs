@@ -0,0 +1,167 @@
+use ordered_float::OrderedFloat;
+
+use crate::{
+    core::{ActionReward, Env},
+    envs::wrappers::record_episode_statistics::EpisodeStats,
+};
+
+/// Resets the environment and steps through a single episode using the given policy.
+///
+/// Collects every [`ActionReward`] produced until the episode is done or `max_steps` steps have
+/// been taken.
+pub fn rollout<E, P>(
+    env: &mut E,
+    policy: &mut P,
+    max_steps: usize,
+) -> Vec<ActionReward<E::Observation, E::Info>>
+where
+    E: Env,
+    E::Observation: Clone,
+    P: FnMut(&E::Observation) -> E::Action,
+{
+    let (mut observation, _) = env.reset(None, false, None);
+    let mut trajectory = Vec::new();
+
+    for _ in 0..max_steps {
+        let action_reward = env.step(policy(&observation));
+        observation = action_reward.observation.clone();
+
+        let done = action_reward.done;
+        trajectory.push(action_reward);
+        if done {
+            break;
+        }
+    }
+
+    trajectory
+}
+
+/// Resets the environment and steps through a single episode using the given policy, until the
+/// episode is done.
+///
+/// Environments whose episodes never end should be wrapped by a
+/// [`TimeLimit`](crate::envs::wrappers::time_limit::TimeLimit) beforehand.
+pub fn run_episode<E, P>(env: &mut E, policy: &mut P) -> EpisodeStats
+where
+    E: Env,
+    P: FnMut(&E::Observation) -> E::Action,
+{
+    let (mut observation, _) = env.reset(None, false, None);
+    let mut total_reward = OrderedFloat(0.);
+    let mut length = 0;
+
+    loop {
+        let action_reward = env.step(policy(&observation));
+        total_reward += action_reward.reward;
+        length += 1;
+
+        if action_reward.done {
+            return EpisodeStats {
+                total_reward,
+                length,
+                terminated: !action_reward.truncated,
+                truncated: action_reward.truncated,
+            };
+        }
+        observation = action_reward.observation;
+    }
+}
+
+/// Runs `n_episodes` complete episodes using the given policy, returning the statistics of each.
+pub fn run_episodes<E, P>(env: &mut E, policy: &mut P, n_episodes: usize) -> Vec<EpisodeStats>
+where
+    E: Env,
+    P: FnMut(&E::Observation) -> E::Action,
+{
+    (0..n_episodes).map(|_| run_episode(env, policy)).collect()
+}
+
+/// Runs the policy for `n_episodes` episodes, returning the mean and standard deviation of the
+/// total reward collected in each.
+pub fn evaluate_policy<E, P>(
+    env: &mut E,
+    policy: &mut P,
+    n_episodes: usize,
+    max_steps: usize,
+) -> (f64, f64)
+where
+    E: Env,
+    E::Observation: Clone,
+    P: FnMut(&E::Observation) -> E::Action,
+{
+    assert!(n_episodes > 0, "At least one episode must be evaluated.");
+
+    let returns: Vec<f64> = (0..n_episodes)
+        .map(|_| {
+            rollout(env, policy, max_steps)
+                .iter()
+                .map(|action_reward| action_reward.reward.into_inner())
+                .sum()
+        })
+        .collect();
+
+    let mean = returns.iter().sum::<f64>() / n_episodes as f64;
+    let var = returns
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / n_episodes as f64;
+
+    (mean, var.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{evaluate_policy, rollout, run_episodes};
+    use crate::{
+        envs::{
+            classical_control::cartpole::{CartPoleAction, CartPoleEnv, CartPoleObservation},
+            wrappers::time_limit::TimeLimit,
+        },
+        utils::renderer::RenderMode,
+    };
+
+    fn balancing_policy(observation: &CartPoleObservation) -> CartPoleAction {
+        if observation.theta() + observation.theta_dot() > 0. {
+            CartPoleAction::Right
+        } else {
+            CartPoleAction::Left
+        }
+    }
+
+    #[test]
+    fn given_balancing_policy_when_rolled_out_then_each_step_is_rewarded() {
+        let mut env = CartPoleEnv::new(RenderMode::None);
+
+        let trajectory = rollout(&mut env, &mut balancing_policy, 50);
+
+        assert_eq!(trajectory.len(), 50);
+        assert!(trajectory
+            .iter()
+            .all(|action_reward| action_reward.reward.into_inner() == 1.));
+    }
+
+    #[test]
+    fn given_balancing_policy_when_evaluated_then_every_episode_reaches_max_steps() {
+        let mut env = CartPoleEnv::new(RenderMode::None);
+
+        let (mean, std) = evaluate_policy(&mut env, &mut balancing_policy, 5, 50);
+
+        assert_eq!(mean, 50.);
+        assert_eq!(std, 0.);
+    }
+
+    #[test]
+    fn given_balancing_policy_when_episodes_run_then_each_is_truncated_at_time_limit() {
+        let mut env = TimeLimit::new(CartPoleEnv::new(RenderMode::None), 50);
+
+        let stats = run_episodes(&mut env, &mut balancing_policy, 2);
+
+        assert_eq!(stats.len(), 2);
+        for episode in stats {
+            assert_eq!(episode.length, 50);
+            assert_eq!(episode.total_reward.into_inner(), 50.);
+            assert!(episode.truncated && !episode.terminated);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+/// Tracks the element-wise mean and variance of a stream of samples using Welford's online
+/// algorithm.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RunningMeanStd {
+    /// The number of samples observed.
+    count: u64,
+    /// The running mean of each element.
+    mean: Vec<f64>,
+    /// The running sum of squared differences from the mean of each element.
+    m2: Vec<f64>,
+}
+
+impl RunningMeanStd {
+    /// Creates an accumulator for samples holding `shape` elements.
+    ///
+    /// Panics if `shape` is `0`, as samples without elements cannot be told apart.
+    pub fn new(shape: usize) -> Self {
+        assert!(shape > 0, "Samples must hold at least one element.");
+
+        Self {
+            count: 0,
+            mean: vec![0.; shape],
+            m2: vec![0.; shape],
+        }
+    }
+
+    /// Incorporates one or more samples into the statistics.
+    ///
+    /// The given values are split into consecutive samples of `shape` elements each.
+    pub fn update(&mut self, x: &[f64]) {
+        assert_eq!(
+            x.len() % self.mean.len(),
+            0,
+            "Expected a multiple of {} values.",
+            self.mean.len()
+        );
+
+        for sample in x.chunks(self.mean.len()) {
+            self.count += 1;
+            for ((value, mean), m2) in sample.iter().zip(&mut self.mean).zip(&mut self.m2) {
+                let delta = value - *mean;
+                *mean += delta / self.count as f64;
+                *m2 += delta * (value - *mean);
+            }
+        }
+    }
+
+    /// Provides the number of samples observed.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Provides the running mean of each element.
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+
+    /// Provides the running population variance of each element.
+    ///
+    /// Before any sample has been observed, a variance of `1` is reported.
+    pub fn var(&self) -> Vec<f64> {
+        if self.count == 0 {
+            vec![1.; self.m2.len()]
+        } else {
+            self.m2.iter().map(|m2| m2 / self.count as f64).collect()
+        }
+    }
+
+    /// Provides the running standard deviation of each element.
+    pub fn std(&self) -> Vec<f64> {
+        self.var().into_iter().map(f64::sqrt).collect()
+    }
+}
+
+/// Tracks the mean and variance of a stream of scalar samples, such as rewards, using Welford's
+/// online algorithm.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+pub struct RunningMeanStdScalar {
+    /// The number of samples observed.
+    count: u64,
+    /// The running mean.
+    mean: f64,
+    /// The running sum of squared differences from the mean.
+    m2: f64,
+}
+
+impl RunningMeanStdScalar {
+    /// Creates an accumulator which has not observed any sample.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Incorporates a single sample into the statistics.
+    pub fn update(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    /// Provides the number of samples observed.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Provides the running mean.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Provides the running population variance.
+    ///
+    /// Before any sample has been observed, a variance of `1` is reported.
+    pub fn var(&self) -> f64 {
+        if self.count == 0 {
+            1.
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+
+    /// Provides the running standard deviation.
+    pub fn std(&self) -> f64 {
+        self.var().sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::{RunningMeanStd, RunningMeanStdScalar};
+    use crate::utils::{custom::util_fns::sample_standard_normal, seeding::rand_random};
+
+    #[test]
+    fn given_standard_normal_samples_when_updated_then_mean_and_std_are_estimated() {
+        let (mut rng, _) = rand_random(Some(0));
+        let mut rms = RunningMeanStd::new(1);
+        let mut scalar = RunningMeanStdScalar::new();
+
+        for _ in 0..1000 {
+            let x = sample_standard_normal(&mut rng);
+            rms.update(&[x]);
+            scalar.update(x);
+        }
+
+        assert!(rms.mean()[0].abs() < 0.05);
+        assert!((rms.std()[0] - 1.).abs() < 0.05);
+        assert!((scalar.mean() - rms.mean()[0]).abs() < 1e-12);
+        assert!((scalar.std() - rms.std()[0]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn given_iid_uniform_samples_when_updated_then_statistics_converge() {
+        let (mut rng, _) = rand_random(Some(42));
+        let mut rms = RunningMeanStd::new(2);
+
+        for _ in 0..10_000 {
+            rms.update(&[rng.gen_range(0. ..1.), rng.gen_range(-2. ..2.)]);
+        }
+
+        assert_eq!(rms.count(), 10_000);
+        assert!((rms.mean()[0] - 0.5).abs() < 0.02);
+        assert!(rms.mean()[1].abs() < 0.05);
+        assert!((rms.var()[0] - 1. / 12.).abs() < 0.01);
+        assert!((rms.var()[1] - 16. / 12.).abs() < 0.05);
+    }
+
+    #[test]
+    fn given_batch_of_samples_when_updated_then_each_sample_is_counted() {
+        let mut rms = RunningMeanStd::new(2);
+
+        rms.update(&[1., 2., 3., 4.]);
+
+        assert_eq!(rms.count(), 2);
+        assert_eq!(rms.mean(), &[2., 3.]);
+        assert_eq!(rms.var(), vec![1., 1.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one element")]
+    fn given_empty_shape_when_created_then_panics() {
+        RunningMeanStd::new(0);
+    }
+}
@@ -25,9 +25,42 @@ pub fn rand_random(seed: Option<u64>) -> (Pcg64, u64) {
     (generator, seed_no)
 }
 
+/// Generates a PRNG using a seed derived from the given string, such as the name of a run.
+///
+/// Returns the PRNG instance along with the derived seed number.
+///
+/// # Examples
+///
+/// ```rust
+/// use gym_rs::utils::seeding::{rand_random, rand_random_from_str};
+///
+/// let (_, seed_no) = rand_random_from_str("experiment_01");
+/// assert_eq!(rand_random(Some(seed_no)).1, seed_no);
+/// ```
+pub fn rand_random_from_str(s: &str) -> (Pcg64, u64) {
+    rand_random_from_bytes(s.as_bytes())
+}
+
+/// Generates a PRNG using a seed derived from the given bytes.
+///
+/// The seed is the 64 bit FNV-1a hash of the bytes, which unlike the hasher of the standard
+/// library is stable across platforms and compiler versions.
+pub fn rand_random_from_bytes(b: &[u8]) -> (Pcg64, u64) {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+    let hash = b.iter().fold(FNV_OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    });
+
+    rand_random(Some(hash))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::rand_random;
+    use rand::Rng;
+
+    use super::{rand_random, rand_random_from_str};
 
     // NOTE: The negative case cannot be tested.
     #[test]
@@ -37,4 +70,20 @@ mod tests {
 
         assert_eq!(seed_no, generator_seed);
     }
+
+    #[test]
+    fn given_strings_when_rand_random_from_str_then_equal_strings_produce_equal_sequences() {
+        let draw = |s: &str| {
+            let (mut generator, seed_no) = rand_random_from_str(s);
+            (
+                seed_no,
+                (0..8).map(|_| generator.gen()).collect::<Vec<u64>>(),
+            )
+        };
+
+        assert_eq!(draw("experiment_01"), draw("experiment_01"));
+        assert_ne!(draw("experiment_01").1, draw("experiment_02").1);
+        // The FNV-1a hash of the empty input is its offset basis.
+        assert_eq!(draw("").0, 0xcbf2_9ce4_8422_2325);
+    }
 }
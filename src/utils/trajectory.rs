@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+use super::returns;
+
+/// The data of a single episode, stored as one sequence per quantity so each can be processed in
+/// bulk.
+///
+/// The element at index `i` of every sequence belongs to step `i` of the episode, where the
+/// observation is the one the action was taken from.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Trajectory<O: Clone, A: Clone> {
+    /// The observations the actions were taken from.
+    pub observations: Vec<O>,
+    /// The actions taken.
+    pub actions: Vec<A>,
+    /// The rewards produced by the actions.
+    pub rewards: Vec<f64>,
+    /// Indicates whether the episode ended after each action.
+    pub dones: Vec<bool>,
+    /// Indicates whether the episode was cut short after each action.
+    pub truncateds: Vec<bool>,
+}
+
+impl<O: Clone, A: Clone> Default for Trajectory<O, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<O: Clone, A: Clone> Trajectory<O, A> {
+    /// Creates a trajectory holding no steps.
+    pub fn new() -> Self {
+        Self {
+            observations: Vec::new(),
+            actions: Vec::new(),
+            rewards: Vec::new(),
+            dones: Vec::new(),
+            truncateds: Vec::new(),
+        }
+    }
+
+    /// Appends a step to the end of the trajectory.
+    pub fn push(&mut self, obs: O, action: A, reward: f64, done: bool, truncated: bool) {
+        self.observations.push(obs);
+        self.actions.push(action);
+        self.rewards.push(reward);
+        self.dones.push(done);
+        self.truncateds.push(truncated);
+    }
+
+    /// The number of steps held.
+    pub fn len(&self) -> usize {
+        self.rewards.len()
+    }
+
+    /// Checks whether no steps are held.
+    pub fn is_empty(&self) -> bool {
+        self.rewards.is_empty()
+    }
+
+    /// The sum of the rewards of every step.
+    pub fn total_reward(&self) -> f64 {
+        self.rewards.iter().sum()
+    }
+
+    /// The return following each step, discounting each later reward by `gamma` per step.
+    pub fn discount_returns(&self, gamma: f64) -> Vec<f64> {
+        returns::discount_returns(&self.rewards, gamma, 0.)
+    }
+
+    /// Copies the steps from `start` up to, but excluding, `end` into a new trajectory.
+    pub fn slice(&self, start: usize, end: usize) -> Trajectory<O, A> {
+        Trajectory {
+            observations: self.observations[start..end].to_vec(),
+            actions: self.actions[start..end].to_vec(),
+            rewards: self.rewards[start..end].to_vec(),
+            dones: self.dones[start..end].to_vec(),
+            truncateds: self.truncateds[start..end].to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Trajectory;
+
+    fn trajectory() -> Trajectory<usize, usize> {
+        let mut trajectory = Trajectory::new();
+        for (step, reward) in [1., 2., 3., 4.].into_iter().enumerate() {
+            trajectory.push(step, step % 2, reward, step == 3, false);
+        }
+
+        trajectory
+    }
+
+    #[test]
+    fn given_undiscounted_returns_when_computed_then_each_is_the_sum_of_remaining_rewards() {
+        let trajectory = trajectory();
+
+        assert_eq!(trajectory.discount_returns(1.), vec![10., 9., 7., 4.]);
+        assert_eq!(trajectory.discount_returns(0.5), vec![3.25, 4.5, 5., 4.]);
+        assert_eq!(trajectory.total_reward(), 10.);
+    }
+
+    #[test]
+    fn given_trajectory_when_sliced_then_only_selected_steps_are_kept() {
+        let slice = trajectory().slice(1, 3);
+
+        assert_eq!(slice.len(), 2);
+        assert_eq!(slice.observations, vec![1, 2]);
+        assert_eq!(slice.rewards, vec![2., 3.]);
+        assert!(trajectory().slice(2, 2).is_empty());
+    }
+}
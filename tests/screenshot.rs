@@ -0,0 +1,34 @@
+//! Captures the content of an SDL2 window, which requires a video device.
+#![allow(unused_crate_dependencies)]
+#![cfg(all(feature = "sdl2", feature = "png"))]
+
+use gym_rs::utils::{
+    custom::{
+        canvas::{Canvas, Color},
+        screen::{Screen, ScreenGuiTransformations},
+    },
+    renderer::RenderMode,
+};
+
+#[test]
+#[ignore = "requires a video device"]
+fn given_loaded_sdl2_screen_when_screenshot_taken_then_png_matches_window_size() {
+    let path = std::env::temp_dir().join("gym_rs_sdl2_screenshot.png");
+    let mut screen = Screen::new(30, 40, "Screenshot", 30, RenderMode::RgbArray);
+
+    assert!(screen.screenshot(&path).is_err());
+
+    screen.load_gui();
+    screen.draw_on_canvas(
+        |canvas: &mut Canvas| {
+            canvas.set_draw_color(Color::WHITE);
+            canvas.clear();
+        },
+        ScreenGuiTransformations::default(),
+    );
+    screen.screenshot(&path).unwrap();
+    let image = image::open(&path).unwrap().to_rgb8();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!((image.width(), image.height()), (30, 40));
+}